@@ -0,0 +1,483 @@
+use std::collections::{HashMap, HashSet};
+
+use cairo_lang_defs::ids::GenericParamId;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextPositionSpan, TextSpan, TextWidth};
+use cairo_lang_semantic::items::constant::ConstValue;
+use cairo_lang_semantic::items::imp::ImplLongId;
+use cairo_lang_semantic::lsp_helpers::LspHelpers;
+use cairo_lang_semantic::resolve::ResolvedConcreteItem;
+use cairo_lang_semantic::types::TypesSemantic;
+use cairo_lang_semantic::{GenericArgumentId, GenericParam, TypeId, TypeLongId};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedSyntaxNode, ast};
+use cairo_language_common::CommonGroup;
+use itertools::Itertools;
+use lsp_types::{CodeAction, CodeActionKind, TextEdit, Url, WorkspaceEdit};
+
+use super::helpers::{INDENT, generates_trait, is_mutated, line_indentation};
+use crate::ide::format::types::format_type;
+use crate::lang::db::{AnalysisDatabase, LsSemanticGroup};
+use crate::lang::defs::{ResolvedItem, SymbolDef, SymbolSearch, VariableDef};
+use crate::lang::lsp::ToLsp;
+use crate::lang::types::find_expr_type;
+
+/// Name of the generated function, meant to be renamed by the user right away.
+const FUNCTION_NAME: &str = "extracted";
+
+/// A local variable referred to by the extracted statements.
+struct Variable<'db> {
+    def: VariableDef<'db>,
+    /// The variable is declared by the extracted statements.
+    declared_inside: bool,
+    /// The variable is assigned to or borrowed mutably by the extracted statements.
+    mutated: bool,
+    /// The variable is used after the extracted statements.
+    used_after: bool,
+}
+
+/// Code action moving the selected statements into a new function.
+///
+/// Variables read by the statements become parameters, where the ones which are mutated, or which
+/// cannot be copied but are still needed by the caller, are passed by `ref`.
+/// Variables declared by the statements and used afterwards are returned, as a tuple if needed.
+/// If the statements use `self` of a method in a `#[generate_trait]` impl, a new method is created
+/// in the same impl instead.
+pub fn extract_function<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    range: TextPositionSpan,
+    uri: Url,
+) -> Option<CodeAction> {
+    let content = db.file_content(file)?;
+    let span = trimmed_span(
+        content,
+        TextSpan::new(range.start.offset_in_file(db, file)?, range.end.offset_in_file(db, file)?),
+    )?;
+    let statements = selected_statements(db, file, span)?;
+    if !can_be_extracted(db, &statements) {
+        return None;
+    }
+
+    let function = statements[0].ancestors(db).find(|node| {
+        matches!(node.kind(db), SyntaxKind::FunctionWithBody | SyntaxKind::ItemFreeFunction)
+    })?;
+    let declaration = match function.kind(db) {
+        SyntaxKind::FunctionWithBody => {
+            ast::FunctionWithBody::from_syntax_node(db, function).declaration(db)
+        }
+        _ => ast::ItemFreeFunction::from_syntax_node(db, function).declaration(db),
+    };
+
+    let (variables, uses_self) = collect_variables(db, file, span, &statements)?;
+
+    // Methods can be added only to impls of traits generated from them.
+    let item_impl = function.ancestor_of_type::<ast::ItemImpl>(db);
+    let self_param = if uses_self {
+        let item_impl = item_impl.as_ref()?;
+        if !generates_trait(db, item_impl) {
+            return None;
+        }
+        let self_param = declaration.signature(db).parameters(db).elements(db).find(|param| {
+            param.name(db).as_syntax_node().get_text_without_trivia(db).to_string(db) == "self"
+        })?;
+        Some(self_param.as_syntax_node().get_text_without_trivia(db).to_string(db))
+    } else {
+        None
+    };
+
+    let module_id = db.find_module_containing_node(function)?;
+    let importables = db.visible_importables_from_module(module_id)?;
+    let format = |ty: TypeId<'db>| format_type(db, ty, &importables, None);
+
+    let params: Vec<_> = variables.iter().filter(|var| !var.declared_inside).collect();
+    let returned: Vec<_> =
+        variables.iter().filter(|var| var.declared_inside && var.used_after).collect();
+
+    let mut param_decls = vec![];
+    let mut args = vec![];
+    for var in &params {
+        let ty = var.def.ty(db)?;
+        let by_ref = var.mutated || (var.used_after && db.copyable(ty).is_err());
+        let modifier = if by_ref { "ref " } else { "" };
+        param_decls.push(format!("{modifier}{}: {}", var.def.name(db), format(ty)));
+        args.push(format!("{modifier}{}", var.def.name(db)));
+    }
+
+    let return_types =
+        returned.iter().map(|var| var.def.ty(db).map(format)).collect::<Option<Vec<_>>>()?;
+    let (return_type, return_expr, binding) = match returned.as_slice() {
+        [] => (String::new(), None, String::new()),
+        [var] => (
+            format!(" -> {}", return_types[0]),
+            Some(var.def.name(db)),
+            format!("let {} = ", binding_pattern(db, var)),
+        ),
+        vars => (
+            format!(" -> ({})", return_types.join(", ")),
+            Some(format!("({})", vars.iter().map(|var| var.def.name(db)).join(", "))),
+            format!("let ({}) = ", vars.iter().map(|var| binding_pattern(db, var)).join(", ")),
+        ),
+    };
+
+    // Generic parameters of the enclosing impl are in scope of its methods, but not of a new
+    // free function.
+    let mut generic_params = vec![];
+    if self_param.is_none()
+        && let Some(item_impl) = &item_impl
+    {
+        generic_params.extend(generic_param_list(db, item_impl.generic_params(db)));
+    }
+    generic_params.extend(generic_param_list(db, declaration.generic_params(db)));
+    let types = variables
+        .iter()
+        .filter_map(|var| var.def.ty(db))
+        .chain(
+            statements
+                .iter()
+                .flat_map(|statement| statement.descendants(db))
+                .filter(|node| ast::Expr::is_variant(node.kind(db)))
+                .filter_map(|node| find_expr_type(db, node)),
+        )
+        .collect_vec();
+    let generics = required_generic_params(db, &generic_params, &types, &statements);
+    let selected_text = span.take(content);
+
+    // Place a method right after the one it is extracted from,
+    // and a free function after the module item containing the statements.
+    let anchor = if self_param.is_some() {
+        function
+    } else {
+        function.ancestors_with_self(db).find(|node| {
+            node.parent(db).is_some_and(|parent| parent.kind(db) == SyntaxKind::ModuleItemList)
+        })?
+    };
+    let anchor_span = anchor.span_without_trivia(db);
+    let indent = line_indentation(content, anchor_span.start);
+    let body_indent = format!("{indent}{INDENT}");
+
+    let statements_indent = line_indentation(content, span.start);
+    let mut body = selected_text
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let line = if i == 0 {
+                line
+            } else {
+                line.strip_prefix(statements_indent).unwrap_or(line.trim_start())
+            };
+            if line.trim().is_empty() { String::new() } else { format!("{body_indent}{line}") }
+        })
+        .join("\n");
+    if let Some(return_expr) = return_expr {
+        body.push_str(&format!("\n{body_indent}{return_expr}"));
+    }
+
+    let all_params = self_param.iter().cloned().chain(param_decls).join(", ");
+    let function_text = format!(
+        "\n\n{indent}fn {FUNCTION_NAME}{generics}({all_params}){return_type} {{\n{body}\n{indent}}}"
+    );
+    let receiver = if uses_self { "self." } else { "" };
+    let call_text = format!("{binding}{receiver}{FUNCTION_NAME}({});", args.join(", "));
+
+    let insertion = anchor_span.end.position_in_file(db, file)?.to_lsp();
+    let edits = vec![
+        TextEdit { range: span.position_in_file(db, file)?.to_lsp(), new_text: call_text },
+        TextEdit {
+            range: lsp_types::Range { start: insertion, end: insertion },
+            new_text: function_text,
+        },
+    ];
+
+    Some(CodeAction {
+        title: if uses_self { "Extract into method" } else { "Extract into function" }.to_string(),
+        kind: Some(CodeActionKind::REFACTOR_EXTRACT),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri, edits)])),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        ..Default::default()
+    })
+}
+
+/// Shrinks the span so that it does not start or end with whitespace.
+fn trimmed_span(content: &str, span: TextSpan) -> Option<TextSpan> {
+    let text = span.take(content);
+    let leading = text.len() - text.trim_start().len();
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    let start = span.start.add_width(TextWidth::from_str(&text[..leading]));
+    Some(TextSpan::new(start, start.add_width(TextWidth::from_str(trimmed))))
+}
+
+/// Finds statements of a single block which are exactly covered by the span.
+fn selected_statements<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    span: TextSpan,
+) -> Option<Vec<SyntaxNode<'db>>> {
+    let first = db
+        .find_syntax_node_at_offset(file, span.start)?
+        .ancestors_with_self(db)
+        .filter(|node| {
+            ast::Statement::is_variant(node.kind(db))
+                && node
+                    .parent(db)
+                    .is_some_and(|parent| parent.kind(db) == SyntaxKind::StatementList)
+        })
+        .find(|node| node.span_without_trivia(db).start == span.start)?;
+
+    let statements: Vec<_> = first
+        .parent(db)?
+        .get_children(db)
+        .iter()
+        .copied()
+        .skip_while(|node| *node != first)
+        .take_while(|node| span.contains(node.span_without_trivia(db)))
+        .collect();
+
+    (statements.last()?.span_without_trivia(db).end == span.end).then_some(statements)
+}
+
+/// Checks that the statements do not alter control flow of the function they are extracted from
+/// and do not produce its resulting value.
+fn can_be_extracted<'db>(db: &'db AnalysisDatabase, statements: &[SyntaxNode<'db>]) -> bool {
+    let Some(last) = statements.last() else {
+        return false;
+    };
+
+    let escapes = statements.iter().flat_map(|statement| statement.descendants(db)).any(|node| {
+        match node.kind(db) {
+            SyntaxKind::StatementReturn | SyntaxKind::ExprErrorPropagate => true,
+            // Loops nested in the statements may still be broken out of.
+            SyntaxKind::StatementBreak | SyntaxKind::StatementContinue => !node
+                .ancestors(db)
+                .take_while(|ancestor| !statements.contains(ancestor))
+                .any(|ancestor| {
+                    matches!(
+                        ancestor.kind(db),
+                        SyntaxKind::ExprLoop | SyntaxKind::ExprWhile | SyntaxKind::ExprFor
+                    )
+                }),
+            _ => false,
+        }
+    });
+
+    let tail_expr = ast::StatementExpr::cast(db, *last).filter(|statement| {
+        matches!(statement.semicolon(db), ast::OptionTerminalSemicolon::Empty(_))
+    });
+    let produces_value = tail_expr.is_some_and(|statement| {
+        find_expr_type(db, statement.expr(db).as_syntax_node())
+            .is_none_or(|ty| !matches!(ty.long(db), TypeLongId::Tuple(types) if types.is_empty()))
+    });
+
+    !escapes && !produces_value
+}
+
+/// Finds local variables referred to by the statements, in order of their first appearance.
+///
+/// Returns them along with information whether `self` is used.
+fn collect_variables<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    span: TextSpan,
+    statements: &[SyntaxNode<'db>],
+) -> Option<(Vec<Variable<'db>>, bool)> {
+    let mut variables: Vec<Variable<'db>> = vec![];
+    let mut uses_self = false;
+
+    let identifiers = statements
+        .iter()
+        .flat_map(|statement| statement.descendants(db))
+        .filter_map(|node| ast::TerminalIdentifier::cast(db, node));
+    for identifier in identifiers {
+        let Some(search) = SymbolSearch::find_definition(db, &identifier) else {
+            continue;
+        };
+        let SymbolDef::Variable(def) = search.def.clone() else {
+            continue;
+        };
+        if def.is_constant(db) {
+            continue;
+        }
+
+        let definition = def.definition_node();
+        let declared_inside = definition.stable_ptr(db).file_id(db) == file
+            && span.contains(definition.span_without_trivia(db));
+        if !declared_inside && def.name(db) == "self" {
+            uses_self = true;
+            continue;
+        }
+
+        let mutated = def.is_mutable(db) && is_mutated(db, &identifier);
+        if let Some(var) = variables.iter_mut().find(|var| var.def == def) {
+            var.mutated |= mutated;
+            continue;
+        }
+
+        let used_after = search.usages(db).collect().into_iter().any(|usage| {
+            let location = usage.location();
+            location.file_id == file && location.span.start >= span.end
+        });
+        variables.push(Variable { def, declared_inside, mutated, used_after });
+    }
+
+    Some((variables, uses_self))
+}
+
+/// Pattern binding the returned variable at the call site, keeping its mutability.
+fn binding_pattern<'db>(db: &'db AnalysisDatabase, var: &Variable<'db>) -> String {
+    let mutability = if var.def.is_mutable(db) { "mut " } else { "" };
+    format!("{mutability}{}", var.def.name(db))
+}
+
+fn generic_param_list<'db>(
+    db: &'db AnalysisDatabase,
+    list: ast::OptionWrappedGenericParamList<'db>,
+) -> Vec<ast::GenericParam<'db>> {
+    match list {
+        ast::OptionWrappedGenericParamList::WrappedGenericParamList(list) => {
+            list.generic_params(db).elements(db).collect()
+        }
+        ast::OptionWrappedGenericParamList::Empty(_) => vec![],
+    }
+}
+
+/// Formats the generic parameters which the new function needs to compile.
+///
+/// These are the parameters which the types of the extracted code, or the paths in it, refer to.
+/// Impl parameters constraining any of them, like `+Drop<T>` needed to drop values of `T`,
+/// are kept too, along with the parameters they refer to in turn.
+fn required_generic_params<'db>(
+    db: &'db AnalysisDatabase,
+    params: &[ast::GenericParam<'db>],
+    types: &[TypeId<'db>],
+    statements: &[SyntaxNode<'db>],
+) -> String {
+    let Some(lookup_items) = db.collect_lookup_items_with_parent_files(statements[0]) else {
+        return String::new();
+    };
+    let params = params
+        .iter()
+        .filter_map(|param| {
+            let stable_ptr = param.stable_ptr(db);
+            let semantic = lookup_items
+                .iter()
+                .flat_map(|&item| db.item_generic_params(item))
+                .find(|semantic| semantic.stable_ptr(db) == stable_ptr)?
+                .clone();
+            Some((param, semantic))
+        })
+        .collect_vec();
+
+    let mut used = HashSet::new();
+    for &ty in types {
+        collect_type_generic_params(db, ty, &mut used);
+    }
+    let identifiers = statements
+        .iter()
+        .flat_map(|statement| statement.descendants(db))
+        .filter_map(|node| ast::TerminalIdentifier::cast(db, node));
+    for identifier in identifiers {
+        match SymbolSearch::find_definition(db, &identifier).map(|search| search.resolved_item) {
+            Some(ResolvedItem::GenericParam(param)) => {
+                used.insert(param.id());
+            }
+            Some(ResolvedItem::Concrete(ResolvedConcreteItem::Type(ty))) => {
+                collect_type_generic_params(db, ty, &mut used)
+            }
+            Some(ResolvedItem::Concrete(ResolvedConcreteItem::Impl(imp))) => {
+                collect_argument_generic_params(db, GenericArgumentId::Impl(imp), &mut used)
+            }
+            _ => {}
+        }
+    }
+
+    loop {
+        let mut changed = false;
+        for (_, semantic) in &params {
+            let (GenericParam::Impl(param) | GenericParam::NegImpl(param)) = semantic else {
+                continue;
+            };
+            let mut mentioned = HashSet::new();
+            for arg in param
+                .concrete_trait
+                .iter()
+                .flat_map(|concrete_trait| concrete_trait.generic_args(db))
+            {
+                collect_argument_generic_params(db, arg, &mut mentioned);
+            }
+            if used.contains(&param.id) || mentioned.iter().any(|id| used.contains(id)) {
+                for id in mentioned.into_iter().chain([param.id]) {
+                    changed |= used.insert(id);
+                }
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let required = params
+        .iter()
+        .filter(|(_, semantic)| used.contains(&semantic.id()))
+        .map(|(param, _)| param.as_syntax_node().get_text_without_trivia(db).to_string(db))
+        .collect_vec();
+
+    if required.is_empty() { String::new() } else { format!("<{}>", required.join(", ")) }
+}
+
+/// Collects the generic parameters the type is built of.
+fn collect_type_generic_params<'db>(
+    db: &'db AnalysisDatabase,
+    ty: TypeId<'db>,
+    params: &mut HashSet<GenericParamId<'db>>,
+) {
+    match ty.long(db) {
+        TypeLongId::GenericParameter(param) => {
+            params.insert(*param);
+        }
+        TypeLongId::Concrete(concrete_type) => {
+            for arg in concrete_type.generic_args(db) {
+                collect_argument_generic_params(db, arg, params);
+            }
+        }
+        TypeLongId::Snapshot(ty) => collect_type_generic_params(db, *ty, params),
+        TypeLongId::FixedSizeArray { type_id, size } => {
+            collect_type_generic_params(db, *type_id, params);
+            collect_argument_generic_params(db, GenericArgumentId::Constant(*size), params);
+        }
+        TypeLongId::Tuple(types) => {
+            for ty in types {
+                collect_type_generic_params(db, *ty, params);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_argument_generic_params<'db>(
+    db: &'db AnalysisDatabase,
+    arg: GenericArgumentId<'db>,
+    params: &mut HashSet<GenericParamId<'db>>,
+) {
+    match arg {
+        GenericArgumentId::Type(ty) => collect_type_generic_params(db, ty, params),
+        GenericArgumentId::Constant(value) => {
+            if let ConstValue::Generic(param) = value.long(db) {
+                params.insert(*param);
+            }
+        }
+        GenericArgumentId::Impl(imp) => {
+            if let ImplLongId::GenericParameter(param) = imp.long(db) {
+                params.insert(*param);
+            }
+        }
+        GenericArgumentId::NegImpl(_) => {}
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::{HashMap, HashSet};
+
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextPositionSpan, TextSpan};
+use cairo_lang_semantic::types::TypesSemantic;
+use cairo_lang_semantic::{ConcreteTypeId, TypeId, TypeLongId};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode, ast};
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+use lsp_types::{CodeAction, CodeActionKind, TextEdit, Url, WorkspaceEdit};
+
+use super::helpers::{has_no_side_effects, unique_name, words};
+use crate::lang::db::{AnalysisDatabase, LsSyntaxGroup};
+use crate::lang::defs::{SymbolDef, SymbolSearch};
+use crate::lang::lsp::ToLsp;
+use crate::lang::types::find_expr_type;
+
+/// Name used for the extracted variable when nothing better can be derived from the expression.
+const DEFAULT_NAME: &str = "var";
+
+/// Code actions introducing a `let` binding for the selected expression.
+///
+/// If the expression is free of side effects, its value is `Copy` and it occurs more than once
+/// in the enclosing function, an additional action replaces all the occurrences with the new
+/// variable.
+pub fn extract_variable<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    range: TextPositionSpan,
+    uri: Url,
+) -> Vec<CodeAction> {
+    extract_variable_ex(db, file, range, uri).unwrap_or_default()
+}
+
+fn extract_variable_ex<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    range: TextPositionSpan,
+    uri: Url,
+) -> Option<Vec<CodeAction>> {
+    let span =
+        TextSpan::new(range.start.offset_in_file(db, file)?, range.end.offset_in_file(db, file)?);
+    if span.start == span.end {
+        return None;
+    }
+
+    let content = db.file_content(file)?;
+    let selected_text = span.take(content).trim();
+
+    let expr = db
+        .widest_node_within_span_without_trivia(file, span)?
+        .ancestors_with_self(db)
+        .filter(|node| node.get_text_without_trivia(db).to_string(db) == selected_text)
+        .find_map(|node| ast::Expr::cast(db, node))?;
+    if matches!(expr, ast::Expr::Path(_) | ast::Expr::Block(_)) {
+        return None;
+    }
+
+    let statement = enclosing_statement(db, expr.as_syntax_node())?;
+    let ty = find_expr_type(db, expr.as_syntax_node())?;
+    if ty.is_missing(db) {
+        return None;
+    }
+
+    let name = unique_name(&variable_name(db, &expr, ty), &names_in_function(db, &expr)?);
+    let expr_text = expr.as_syntax_node().get_text_without_trivia(db).to_string(db);
+
+    let declaration = |statement: SyntaxNode<'db>| -> Option<TextEdit> {
+        let position = statement.span_without_trivia(db).position_in_file(db, file)?.start;
+        let indent: String =
+            content.lines().nth(position.line)?.chars().take_while(|c| c.is_whitespace()).collect();
+        let position = position.to_lsp();
+
+        Some(TextEdit {
+            range: lsp_types::Range { start: position, end: position },
+            new_text: format!("let {name} = {expr_text};\n{indent}"),
+        })
+    };
+    let replacement = |node: SyntaxNode<'db>| -> Option<TextEdit> {
+        Some(TextEdit {
+            range: node.span_without_trivia(db).position_in_file(db, file)?.to_lsp(),
+            new_text: name.clone(),
+        })
+    };
+
+    let mut actions = vec![action(
+        "Extract into variable".to_string(),
+        uri.clone(),
+        vec![declaration(statement)?, replacement(expr.as_syntax_node())?],
+    )];
+
+    if has_no_side_effects(db, &expr)
+        && db.copyable(ty).is_ok()
+        && let Some((statement, occurrences)) = occurrences(db, expr.as_syntax_node())
+        && occurrences.len() > 1
+    {
+        let edits =
+            occurrences.iter().map(|&node| replacement(node)).collect::<Option<Vec<_>>>()?;
+
+        actions.push(action(
+            format!("Extract into variable, replacing all {} occurrences", occurrences.len()),
+            uri,
+            [vec![declaration(statement)?], edits].concat(),
+        ));
+    }
+
+    Some(actions)
+}
+
+fn action(title: String, uri: Url, edits: Vec<TextEdit>) -> CodeAction {
+    CodeAction {
+        title,
+        kind: Some(CodeActionKind::REFACTOR_EXTRACT),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri, edits)])),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        ..Default::default()
+    }
+}
+
+/// Finds the statement before which the variable can be declared.
+///
+/// Expressions which may refer to variables bound between them and the statement,
+/// like closure bodies or match arms, cannot be extracted.
+/// Neither can expressions evaluated repeatedly or conditionally, like `while` conditions,
+/// `else if` conditions or right-hand sides of `&&` and `||`, as the variable is evaluated once
+/// and unconditionally.
+fn enclosing_statement<'db>(
+    db: &'db AnalysisDatabase,
+    node: SyntaxNode<'db>,
+) -> Option<SyntaxNode<'db>> {
+    let mut child = node;
+    for ancestor in node.ancestors(db) {
+        match ancestor.kind(db) {
+            kind if ast::Statement::is_variant(kind) => {
+                return (ancestor.parent(db)?.kind(db) == SyntaxKind::StatementList)
+                    .then_some(ancestor);
+            }
+            SyntaxKind::ExprClosure | SyntaxKind::MatchArm => return None,
+            SyntaxKind::ExprWhile => {
+                let body = ast::ExprWhile::from_syntax_node(db, ancestor).body(db);
+                if body.as_syntax_node() != child {
+                    return None;
+                }
+            }
+            SyntaxKind::ExprIf if ancestor.parent(db)?.kind(db) == SyntaxKind::ElseClause => {
+                let expr_if = ast::ExprIf::from_syntax_node(db, ancestor);
+                if expr_if.if_block(db).as_syntax_node() != child
+                    && expr_if.else_clause(db).as_syntax_node() != child
+                {
+                    return None;
+                }
+            }
+            SyntaxKind::ExprBinary => {
+                let binary = ast::ExprBinary::from_syntax_node(db, ancestor);
+                if matches!(
+                    binary.op(db),
+                    ast::BinaryOperator::AndAnd(_) | ast::BinaryOperator::OrOr(_)
+                ) && binary.rhs(db).as_syntax_node() == child
+                {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+        child = ancestor;
+    }
+    None
+}
+
+/// Finds expressions identical to the extracted one in the enclosing function body, along with
+/// the statement before which the variable has to be declared to be in scope for all of them.
+///
+/// Only expressions referring to the same symbols are considered identical, so the ones using
+/// shadowed variables are skipped.
+fn occurrences<'db>(
+    db: &'db AnalysisDatabase,
+    expr: SyntaxNode<'db>,
+) -> Option<(SyntaxNode<'db>, Vec<SyntaxNode<'db>>)> {
+    let body = expr.ancestors(db).filter(|node| node.kind(db) == SyntaxKind::ExprBlock).last()?;
+    let kind = expr.kind(db);
+    let text = expr.get_text_without_trivia(db);
+    let symbols = referred_symbols(db, expr)?;
+
+    let occurrences = body
+        .descendants(db)
+        .filter(|node| node.kind(db) == kind && node.get_text_without_trivia(db) == text)
+        .filter(|&node| enclosing_statement(db, node).is_some())
+        .filter(|&node| referred_symbols(db, node).is_some_and(|found| found == symbols))
+        .collect_vec();
+
+    // Declare the variable in the innermost block containing all the occurrences,
+    // right before the first of them.
+    let first = *occurrences.first()?;
+    let statements = first.ancestors(db).find(|node| {
+        node.kind(db) == SyntaxKind::StatementList
+            && occurrences.iter().all(|occurrence| occurrence.is_descendant_or_self(db, node))
+    })?;
+    let statement =
+        first.ancestors_with_self(db).find(|node| node.parent(db) == Some(statements))?;
+
+    Some((statement, occurrences))
+}
+
+/// Resolves the symbols referred to by the identifiers in the expression.
+///
+/// Returns `None` if the expression refers to a mutable variable,
+/// as its value may differ between the occurrences.
+fn referred_symbols<'db>(
+    db: &'db AnalysisDatabase,
+    expr: SyntaxNode<'db>,
+) -> Option<Vec<Option<SymbolDef<'db>>>> {
+    expr.descendants(db)
+        .filter_map(|node| ast::TerminalIdentifier::cast(db, node))
+        .map(|identifier| {
+            let def = SymbolSearch::find_definition(db, &identifier).map(|search| search.def);
+            match &def {
+                Some(SymbolDef::Variable(variable)) if variable.is_mutable(db) => None,
+                _ => Some(def),
+            }
+        })
+        .collect()
+}
+
+/// Collects the names written in the function enclosing the expression.
+///
+/// These include all the bindings and items the function refers to, so a variable named
+/// differently does not shadow anything used in its scope.
+fn names_in_function<'db>(
+    db: &'db AnalysisDatabase,
+    expr: &ast::Expr<'db>,
+) -> Option<HashSet<String>> {
+    let body = expr
+        .as_syntax_node()
+        .ancestors(db)
+        .filter(|node| node.kind(db) == SyntaxKind::ExprBlock)
+        .last()?;
+    let function = body.parent(db).unwrap_or(body);
+    Some(words(&function.get_text_without_trivia(db).to_string(db)).into_iter().collect())
+}
+
+/// Names the variable after the called function or method, or after the type of the value.
+fn variable_name<'db>(db: &'db AnalysisDatabase, expr: &ast::Expr<'db>, ty: TypeId<'db>) -> String {
+    let call = match expr {
+        ast::Expr::FunctionCall(call) => Some(call.clone()),
+        ast::Expr::Binary(binary) => match (binary.op(db), binary.rhs(db)) {
+            (ast::BinaryOperator::Dot(_), ast::Expr::FunctionCall(call)) => Some(call),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    let callee_name = call.and_then(|call| {
+        let path = call.path(db).as_syntax_node().get_text_without_trivia(db).to_string(db);
+        let name = path.split("::").rfind(|segment| !segment.starts_with('<'))?;
+        let name = name.split('<').next()?.trim();
+        let name = name.strip_prefix("get_").unwrap_or(name);
+        (!["new", "default", "into", "unwrap", "clone"].contains(&name) && !name.is_empty())
+            .then(|| name.to_case(Case::Snake))
+    });
+
+    callee_name.or_else(|| type_name(db, ty)).unwrap_or_else(|| DEFAULT_NAME.to_string())
+}
+
+fn type_name<'db>(db: &'db AnalysisDatabase, ty: TypeId<'db>) -> Option<String> {
+    let TypeLongId::Concrete(ConcreteTypeId::Struct(_) | ConcreteTypeId::Enum(_)) = ty.long(db)
+    else {
+        return None;
+    };
+
+    let formatted = ty.format(db);
+    let path = formatted.split('<').next()?.trim_end_matches("::");
+    Some(path.rsplit("::").next()?.to_case(Case::Snake))
+}
@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode, ast};
+use lsp_types::{Position, Range, TextEdit};
+
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::lsp::ToLsp;
+
+/// Indentation added for each nesting level of the generated code.
+pub const INDENT: &str = "    ";
+
+/// Returns whitespace preceding the first non-whitespace character of the line containing
+/// the offset.
+pub fn line_indentation(content: &str, offset: TextOffset) -> &str {
+    let offset = offset.as_u32() as usize;
+    let line_start = content[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line = &content[line_start..];
+    &line[..line.len() - line.trim_start().len()]
+}
+
+/// Removes the node, together with its lines if nothing else is written on them.
+pub fn node_removal<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    node: SyntaxNode<'db>,
+) -> Option<TextEdit> {
+    let content = db.file_content(file)?;
+    let span = node.span_without_trivia(db);
+    let position = span.position_in_file(db, file)?;
+
+    let (start, end) = (span.start.as_u32() as usize, span.end.as_u32() as usize);
+    let line_start = content[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = content[end..].find('\n').map_or(content.len(), |i| end + i);
+    let alone_on_lines =
+        content[line_start..start].trim().is_empty() && content[end..line_end].trim().is_empty();
+
+    let range = if alone_on_lines {
+        Range {
+            start: Position { line: position.start.line as u32, character: 0 },
+            end: Position { line: position.end.line as u32 + 1, character: 0 },
+        }
+    } else {
+        position.to_lsp()
+    };
+
+    Some(TextEdit { range, new_text: String::new() })
+}
+
+/// Splits the text into identifier-like words, to collect the names a piece of code refers to.
+pub fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .filter(|word| !word.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
+
+/// Returns the base name, suffixed with `_1`, `_2`, ... if needed to differ from the taken names.
+pub fn unique_name(base: &str, taken: &HashSet<String>) -> String {
+    (0..)
+        .map(|i| if i == 0 { base.to_string() } else { format!("{base}_{i}") })
+        .find(|name| !taken.contains(name))
+        .unwrap()
+}
+
+/// Checks if the impl is marked with `#[generate_trait]`, so that its trait follows its methods.
+pub fn generates_trait<'db>(db: &'db AnalysisDatabase, item_impl: &ast::ItemImpl<'db>) -> bool {
+    item_impl.attributes(db).elements(db).any(|attribute| {
+        attribute.attr(db).as_syntax_node().get_text_without_trivia(db).to_string(db)
+            == "generate_trait"
+    })
+}
+
+/// Checks if the variable referred to by the identifier is assigned to, passed as a `ref`
+/// argument, or is a receiver of a method call, which may take `ref self`.
+pub fn is_mutated<'db>(
+    db: &'db AnalysisDatabase,
+    identifier: &ast::TerminalIdentifier<'db>,
+) -> bool {
+    let Some(path) = identifier.as_syntax_node().ancestor_of_type::<ast::ExprPath>(db) else {
+        return false;
+    };
+
+    let mut node = path.as_syntax_node();
+    while let Some(binary) = node.parent(db).and_then(|parent| ast::ExprBinary::cast(db, parent)) {
+        if binary.lhs(db).as_syntax_node() != node {
+            break;
+        }
+        match binary.op(db) {
+            ast::BinaryOperator::Dot(_) if matches!(binary.rhs(db), ast::Expr::FunctionCall(_)) => {
+                return true;
+            }
+            ast::BinaryOperator::Dot(_) => node = binary.as_syntax_node(),
+            ast::BinaryOperator::Eq(_)
+            | ast::BinaryOperator::PlusEq(_)
+            | ast::BinaryOperator::MinusEq(_)
+            | ast::BinaryOperator::MulEq(_)
+            | ast::BinaryOperator::DivEq(_)
+            | ast::BinaryOperator::ModEq(_) => return true,
+            _ => break,
+        }
+    }
+
+    path.as_syntax_node()
+        .parent(db)
+        .filter(|clause| clause.kind(db) == SyntaxKind::ArgClauseUnnamed)
+        .and_then(|clause| clause.parent(db))
+        .and_then(|arg| ast::Arg::cast(db, arg))
+        .is_some_and(|arg| {
+            arg.modifiers(db).elements(db).any(|modifier| matches!(modifier, ast::Modifier::Ref(_)))
+        })
+}
+
+/// Checks if evaluating the expression has no side effects,
+/// so that evaluating it once or many times does not change the behaviour of the code.
+///
+/// Function and method calls, including the ones behind indexing or macros,
+/// are conservatively assumed to have side effects.
+pub fn has_no_side_effects<'db>(db: &'db AnalysisDatabase, expr: &ast::Expr<'db>) -> bool {
+    match expr {
+        ast::Expr::Literal(_)
+        | ast::Expr::ShortString(_)
+        | ast::Expr::String(_)
+        | ast::Expr::True(_)
+        | ast::Expr::False(_)
+        | ast::Expr::Path(_) => true,
+        ast::Expr::Parenthesized(expr) => has_no_side_effects(db, &expr.expr(db)),
+        ast::Expr::Unary(unary) => has_no_side_effects(db, &unary.expr(db)),
+        ast::Expr::Binary(binary) => match binary.op(db) {
+            // Member access.
+            ast::BinaryOperator::Dot(_) => {
+                matches!(binary.rhs(db), ast::Expr::Path(_))
+                    && has_no_side_effects(db, &binary.lhs(db))
+            }
+            ast::BinaryOperator::Eq(_)
+            | ast::BinaryOperator::PlusEq(_)
+            | ast::BinaryOperator::MinusEq(_)
+            | ast::BinaryOperator::MulEq(_)
+            | ast::BinaryOperator::DivEq(_)
+            | ast::BinaryOperator::ModEq(_) => false,
+            _ => {
+                has_no_side_effects(db, &binary.lhs(db)) && has_no_side_effects(db, &binary.rhs(db))
+            }
+        },
+        ast::Expr::Tuple(tuple) => {
+            tuple.expressions(db).elements(db).all(|expr| has_no_side_effects(db, &expr))
+        }
+        ast::Expr::FixedSizeArray(array) => {
+            array.exprs(db).elements(db).all(|expr| has_no_side_effects(db, &expr))
+        }
+        ast::Expr::StructCtorCall(ctor) => {
+            ctor.arguments(db).arguments(db).elements(db).all(|arg| match arg {
+                ast::StructArg::StructArgSingle(arg) => match arg.arg_expr(db) {
+                    ast::OptionStructArgExpr::StructArgExpr(arg) => {
+                        has_no_side_effects(db, &arg.expr(db))
+                    }
+                    ast::OptionStructArgExpr::Empty(_) => true,
+                },
+                ast::StructArg::StructArgTail(tail) => {
+                    has_no_side_effects(db, &tail.expression(db))
+                }
+            })
+        }
+        _ => false,
+    }
+}
@@ -0,0 +1,586 @@
+use std::collections::{HashMap, HashSet};
+
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextPosition, TextSpan};
+use cairo_lang_semantic::TypeLongId;
+use cairo_lang_syntax::node::ast::ArgClause;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedSyntaxNode, ast};
+use cairo_language_common::CommonGroup;
+use itertools::Itertools;
+use lsp_types::{CodeAction, CodeActionKind, Position, Range, TextEdit, Url, WorkspaceEdit};
+
+use super::helpers::{INDENT, generates_trait, line_indentation, unique_name, words};
+use crate::lang::db::{AnalysisDatabase, LsSyntaxGroup};
+use crate::lang::defs::{SymbolDef, SymbolSearch};
+use crate::lang::lsp::{LsProtoGroup, ToLsp};
+use crate::lang::types::find_expr_type;
+
+/// Code action replacing a call of a function or method with the body of the callee.
+///
+/// When invoked on the name of the function in its definition, all calls are inlined instead,
+/// and the function is removed.
+///
+/// Arguments which are variables or literals are substituted for the parameters, other ones are
+/// bound to the parameters by `let` statements in a block wrapping the body.
+/// Locals of the body which would shadow variables used by the arguments are renamed.
+/// A body returning early is wrapped in a `loop`, with each `return` turned into a `break`.
+pub fn inline_function<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    position: TextPosition,
+) -> Option<CodeAction> {
+    let identifier = db.find_identifier_at_position(file, position)?;
+    let search = SymbolSearch::find_definition(db, &identifier)?;
+    let SymbolDef::Item(item) = &search.def else {
+        return None;
+    };
+    let function = Function::new(db, item.definition_stable_ptr().lookup(db))?;
+
+    let (title, changes) = if function.name_node == identifier.as_syntax_node() {
+        if !function.is_removable(db) {
+            return None;
+        }
+        let usages = search.usages(db).collect();
+        if usages.is_empty() {
+            return None;
+        }
+
+        let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+        changes.entry(db.url_for_file(function.file)?).or_default().push(function.removal(db)?);
+        for usage in usages {
+            let location = usage.location();
+            let identifier = db
+                .find_syntax_node_at_offset(location.file_id, location.span.start)?
+                .ancestors_with_self(db)
+                .find_map(|node| ast::TerminalIdentifier::cast(db, node))?;
+            // Every usage has to be a call, otherwise the function cannot be removed.
+            let call = CallSite::new(db, identifier)?;
+            changes
+                .entry(db.url_for_file(location.file_id)?)
+                .or_default()
+                .push(function.inline(db, &call)?);
+        }
+
+        (format!("Inline all calls of `{}`", function.name), changes)
+    } else {
+        let call = CallSite::new(db, identifier)?;
+        let edit = function.inline(db, &call)?;
+
+        (
+            format!("Inline function `{}`", function.name),
+            HashMap::from([(db.url_for_file(file)?, vec![edit])]),
+        )
+    };
+
+    Some(CodeAction {
+        title,
+        kind: Some(CodeActionKind::REFACTOR_INLINE),
+        edit: Some(WorkspaceEdit {
+            changes: Some(changes),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        ..Default::default()
+    })
+}
+
+/// A free function or an impl method which can be inlined.
+struct Function<'db> {
+    name: String,
+    name_node: SyntaxNode<'db>,
+    /// The whole item, including its attributes.
+    node: SyntaxNode<'db>,
+    file: FileId<'db>,
+    params: Vec<ast::Param<'db>>,
+    statements: Vec<ast::Statement<'db>>,
+    /// The body contains a `return` other than the trailing one.
+    returns_early: bool,
+}
+
+impl<'db> Function<'db> {
+    /// Finds the function defined by the given name node.
+    ///
+    /// Recursive functions, functions marked with `#[inline(never)]` and functions referring to
+    /// their generic parameters in the body cannot be inlined.
+    /// Neither can functions using `?` or returning from within a loop, since the inlined body
+    /// would exit the caller or only the loop instead.
+    fn new(db: &'db AnalysisDatabase, name_node: SyntaxNode<'db>) -> Option<Self> {
+        let node = name_node.ancestors(db).find(|node| {
+            matches!(node.kind(db), SyntaxKind::FunctionWithBody | SyntaxKind::ItemFreeFunction)
+        })?;
+        let (attributes, declaration, body) = match node.kind(db) {
+            SyntaxKind::FunctionWithBody => {
+                let function = ast::FunctionWithBody::from_syntax_node(db, node);
+                (function.attributes(db), function.declaration(db), function.body(db))
+            }
+            _ => {
+                let function = ast::ItemFreeFunction::from_syntax_node(db, node);
+                (function.attributes(db), function.declaration(db), function.body(db))
+            }
+        };
+        let name = declaration.name(db).text(db).to_string(db);
+
+        let never_inlined = attributes.elements(db).any(|attribute| {
+            let text = attribute.as_syntax_node().get_text_without_trivia(db).to_string(db);
+            text.split_whitespace().join("") == "#[inline(never)]"
+        });
+        if never_inlined {
+            return None;
+        }
+
+        let statements: Vec<_> = body.statements(db).elements(db).collect();
+        let body_node = body.as_syntax_node();
+
+        let generic_params = [
+            Some(declaration.generic_params(db)),
+            node.ancestor_of_type::<ast::ItemImpl>(db)
+                .map(|item_impl| item_impl.generic_params(db)),
+        ];
+        let body_words = words(&body_node.get_text_without_trivia(db).to_string(db));
+        let uses_generic_params = generic_params
+            .into_iter()
+            .flatten()
+            .flat_map(|params| match params {
+                ast::OptionWrappedGenericParamList::WrappedGenericParamList(list) => {
+                    list.generic_params(db).elements(db).collect()
+                }
+                ast::OptionWrappedGenericParamList::Empty(_) => vec![],
+            })
+            .filter_map(|param| match param {
+                ast::GenericParam::Type(param) => Some(param.name(db).text(db).to_string(db)),
+                ast::GenericParam::Const(param) => Some(param.name(db).text(db).to_string(db)),
+                _ => None,
+            })
+            .any(|name| body_words.contains(&name));
+        if uses_generic_params {
+            return None;
+        }
+
+        if body_node.descendants(db).any(|node| node.kind(db) == SyntaxKind::ExprErrorPropagate) {
+            return None;
+        }
+        let returns = returns(db, body_node);
+        let returns_from_loop = returns.iter().any(|statement| {
+            statement.as_syntax_node().ancestors(db).take_while(|node| *node != body_node).any(
+                |node| {
+                    matches!(
+                        node.kind(db),
+                        SyntaxKind::ExprLoop | SyntaxKind::ExprWhile | SyntaxKind::ExprFor
+                    )
+                },
+            )
+        });
+        if returns_from_loop {
+            return None;
+        }
+        let trailing_return = statements.last().map(|statement| statement.as_syntax_node());
+        let returns_early =
+            returns.iter().any(|statement| Some(statement.as_syntax_node()) != trailing_return);
+
+        let recursive = body_node
+            .descendants(db)
+            .filter_map(|node| ast::TerminalIdentifier::cast(db, node))
+            .filter(|identifier| identifier.text(db).to_string(db) == name)
+            .any(|identifier| {
+                let Some(SymbolSearch { def: SymbolDef::Item(item), .. }) =
+                    SymbolSearch::find_definition(db, &identifier)
+                else {
+                    return false;
+                };
+                item.definition_stable_ptr().lookup(db) == name_node
+            });
+        if recursive {
+            return None;
+        }
+
+        Some(Self {
+            name,
+            name_node,
+            node,
+            file: node.stable_ptr(db).file_id(db),
+            params: declaration.signature(db).parameters(db).elements(db).collect(),
+            statements,
+            returns_early,
+        })
+    }
+
+    /// Checks if the function can be removed without breaking the item defining it.
+    ///
+    /// Methods of trait impls are required by the trait, unlike the ones of generated traits.
+    fn is_removable(&self, db: &'db AnalysisDatabase) -> bool {
+        let Some(item_impl) = self.node.ancestor_of_type::<ast::ItemImpl>(db) else {
+            return true;
+        };
+        generates_trait(db, &item_impl)
+    }
+
+    /// Removes the whole lines of the function together with its doc comments
+    /// and a blank line separating it from other items.
+    fn removal(&self, db: &'db AnalysisDatabase) -> Option<TextEdit> {
+        let content = db.file_content(self.file)?;
+        let position = self.node.span_without_trivia(db).position_in_file(db, self.file)?;
+        let lines: Vec<_> = content.lines().collect();
+
+        let mut start = position.start.line;
+        while start > 0 && lines[start - 1].trim_start().starts_with("///") {
+            start -= 1;
+        }
+        let mut end = position.end.line + 1;
+        if lines.get(end).is_some_and(|line| line.trim().is_empty()) {
+            end += 1;
+        } else if start > 0 && lines[start - 1].trim().is_empty() {
+            start -= 1;
+        }
+
+        Some(TextEdit {
+            range: Range {
+                start: Position { line: start as u32, character: 0 },
+                end: Position { line: end as u32, character: 0 },
+            },
+            new_text: String::new(),
+        })
+    }
+
+    /// Replaces the call with the body of the function.
+    fn inline(&self, db: &'db AnalysisDatabase, call: &CallSite<'db>) -> Option<TextEdit> {
+        // Paths in the body are relative to the module of the function,
+        // and may refer to items private to it.
+        if db.find_module_containing_node(self.node)?
+            != db.find_module_containing_node(call.expr)?
+        {
+            return None;
+        }
+
+        let args = call.arguments(db, self.params.first())?;
+        if args.len() != self.params.len() {
+            return None;
+        }
+
+        let caller_words: HashSet<String> = args.iter().flat_map(|arg| words(&arg.text)).collect();
+        let mut taken: HashSet<String> = caller_words.clone();
+        taken.extend(self.statements.iter().flat_map(|statement| {
+            words(&statement.as_syntax_node().get_text_without_trivia(db).to_string(db))
+        }));
+        let mut unique_name = |base: &str| {
+            let name = unique_name(base, &taken);
+            taken.insert(name.clone());
+            name
+        };
+
+        // Maps definitions of parameters and renamed locals to the text replacing their usages.
+        let mut renames: HashMap<SyntaxNode<'db>, String> = HashMap::new();
+        let mut bindings = vec![];
+        for (param, arg) in self.params.iter().zip(args) {
+            let name = param.name(db).text(db).to_string(db);
+            let modifiers: Vec<_> = param.modifiers(db).elements(db).collect();
+            let is_ref = modifiers.iter().any(|modifier| matches!(modifier, ast::Modifier::Ref(_)));
+            let is_mut = modifiers.iter().any(|modifier| matches!(modifier, ast::Modifier::Mut(_)));
+
+            let replacement = if is_ref || (arg.simple && !is_mut) {
+                arg.text
+            } else {
+                let binding_name = if name == "self" {
+                    unique_name("this")
+                } else if caller_words.contains(&name) {
+                    unique_name(&name)
+                } else {
+                    name.clone()
+                };
+                let mutability = if is_mut { "mut " } else { "" };
+                bindings.push(format!("let {mutability}{binding_name} = {};", arg.text));
+                binding_name
+            };
+            renames.insert(param.name(db).as_syntax_node(), replacement);
+        }
+
+        let body_span = TextSpan::new(
+            self.statements.first()?.as_syntax_node().span_without_trivia(db).start,
+            self.statements.last()?.as_syntax_node().span_without_trivia(db).end,
+        );
+        let mut replacements = vec![];
+        let identifiers = self
+            .statements
+            .iter()
+            .flat_map(|statement| statement.as_syntax_node().descendants(db))
+            .filter_map(|node| ast::TerminalIdentifier::cast(db, node));
+        for identifier in identifiers {
+            let Some(SymbolSearch { def: SymbolDef::Variable(var), .. }) =
+                SymbolSearch::find_definition(db, &identifier)
+            else {
+                continue;
+            };
+            let definition = var.definition_node();
+            let declared_in_body = definition.stable_ptr(db).file_id(db) == self.file
+                && body_span.contains(definition.span_without_trivia(db));
+            if declared_in_body
+                && !renames.contains_key(&definition)
+                && caller_words.contains(&var.name(db))
+            {
+                renames.insert(definition, unique_name(&var.name(db)));
+            }
+            let Some(new_name) = renames.get(&definition) else {
+                continue;
+            };
+
+            let old_name = identifier.text(db).to_string(db);
+            if *new_name == old_name {
+                continue;
+            }
+            // Shorthands like `Struct { x }` need the field name to stay.
+            let is_shorthand = identifier
+                .as_syntax_node()
+                .parent(db)
+                .is_some_and(|parent| parent.kind(db) == SyntaxKind::StructArgSingle);
+            let new_text =
+                if is_shorthand { format!("{old_name}: {new_name}") } else { new_name.clone() };
+            replacements.push((identifier.as_syntax_node().span_without_trivia(db), new_text));
+        }
+
+        let mut value = None;
+        let mut trailing_break = false;
+        if self.returns_early {
+            // The `loop` wrapping the body is exited with the value of each `return`.
+            for statement in
+                self.statements.iter().flat_map(|statement| returns(db, statement.as_syntax_node()))
+            {
+                let keyword = statement.return_kw(db).as_syntax_node().span_without_trivia(db);
+                replacements.push((keyword, "break".to_string()));
+            }
+            match self.statements.last()? {
+                ast::Statement::Return(_) => {}
+                ast::Statement::Expr(statement)
+                    if matches!(
+                        statement.semicolon(db),
+                        ast::OptionTerminalSemicolon::Empty(_)
+                    ) =>
+                {
+                    let span = statement.expr(db).as_syntax_node().span_without_trivia(db);
+                    replacements
+                        .push((TextSpan::new(span.start, span.start), "break ".to_string()));
+                    replacements.push((TextSpan::new(span.end, span.end), ";".to_string()));
+                }
+                _ => trailing_break = true,
+            }
+        } else {
+            // The trailing `return` becomes the value of the inlined body.
+            match self.statements.last()? {
+                ast::Statement::Return(statement) => {
+                    let statement_span = statement.as_syntax_node().span_without_trivia(db);
+                    match statement.expr_clause(db) {
+                        ast::OptionExprClause::ExprClause(clause) => {
+                            let expr = clause.expr(db);
+                            let expr_span = expr.as_syntax_node().span_without_trivia(db);
+                            replacements.push((
+                                TextSpan::new(statement_span.start, expr_span.start),
+                                String::new(),
+                            ));
+                            replacements.push((
+                                TextSpan::new(expr_span.end, statement_span.end),
+                                String::new(),
+                            ));
+                            value = Some(expr);
+                        }
+                        ast::OptionExprClause::Empty(_) => {
+                            replacements.push((statement_span, String::new()));
+                        }
+                    }
+                }
+                ast::Statement::Expr(statement)
+                    if matches!(
+                        statement.semicolon(db),
+                        ast::OptionTerminalSemicolon::Empty(_)
+                    ) =>
+                {
+                    value = Some(statement.expr(db));
+                }
+                _ => {}
+            }
+        }
+
+        let content = db.file_content(self.file)?;
+        let mut body = String::new();
+        let mut offset = body_span.start;
+        // Insertions go before replacements starting at the same offset.
+        for (span, text) in
+            replacements.into_iter().sorted_by_key(|(span, _)| (span.start, span.end))
+        {
+            body.push_str(TextSpan::new(offset, span.start).take(content));
+            body.push_str(&text);
+            offset = span.end;
+        }
+        body.push_str(TextSpan::new(offset, body_span.end).take(content));
+        let body = body.trim_end();
+
+        let call_content = db.file_content(call.file)?;
+        let call_span = call.expr.span_without_trivia(db);
+        let new_text = match value {
+            Some(value) if bindings.is_empty() && self.statements.len() == 1 => {
+                let needs_parens = matches!(value, ast::Expr::Binary(_) | ast::Expr::Unary(_))
+                    && call.expr.parent(db).is_some_and(|parent| {
+                        matches!(
+                            parent.kind(db),
+                            SyntaxKind::ExprBinary
+                                | SyntaxKind::ExprUnary
+                                | SyntaxKind::ExprErrorPropagate
+                        )
+                    });
+                if needs_parens { format!("({body})") } else { body.to_string() }
+            }
+            _ => {
+                let indent = line_indentation(call_content, call_span.start);
+                let body_indent = line_indentation(content, body_span.start);
+                let mut body_lines = body
+                    .lines()
+                    .map(|line| line.strip_prefix(body_indent).unwrap_or(line.trim_start()))
+                    .map(ToString::to_string)
+                    .collect_vec();
+                if trailing_break {
+                    body_lines.push("break;".to_string());
+                }
+
+                let (opening, lines) = match (self.returns_early, bindings.is_empty()) {
+                    (true, true) => ("loop {", body_lines),
+                    (true, false) => {
+                        let looped = body_lines.iter().map(|line| format!("{INDENT}{line}"));
+                        let lines = bindings
+                            .into_iter()
+                            .chain(["loop {".to_string()])
+                            .chain(looped)
+                            .chain(["}".to_string()])
+                            .collect();
+                        ("{", lines)
+                    }
+                    (false, _) => ("{", bindings.into_iter().chain(body_lines).collect()),
+                };
+
+                let inner_indent = format!("{indent}{INDENT}");
+                let lines = lines.iter().map(|line| {
+                    if line.trim().is_empty() {
+                        String::new()
+                    } else {
+                        format!("{inner_indent}{line}")
+                    }
+                });
+                format!("{opening}\n{}\n{indent}}}", lines.collect::<Vec<_>>().join("\n"))
+            }
+        };
+
+        Some(TextEdit { range: call_span.position_in_file(db, call.file)?.to_lsp(), new_text })
+    }
+}
+
+/// A call of a function, either by path or using the method syntax.
+struct CallSite<'db> {
+    file: FileId<'db>,
+    /// The expression replaced by the inlined body, including the receiver of a method call.
+    expr: SyntaxNode<'db>,
+    receiver: Option<ast::Expr<'db>>,
+    call: ast::ExprFunctionCall<'db>,
+}
+
+/// An argument passed to a parameter of the inlined function.
+struct Argument {
+    text: String,
+    /// The argument can be substituted for the parameter without changing evaluation semantics.
+    simple: bool,
+}
+
+impl<'db> CallSite<'db> {
+    /// Finds the call of the function whose name is the given identifier.
+    fn new(db: &'db AnalysisDatabase, identifier: ast::TerminalIdentifier<'db>) -> Option<Self> {
+        let path = identifier.as_syntax_node().ancestor_of_type::<ast::ExprPath>(db)?;
+        let call = ast::ExprFunctionCall::cast(db, path.as_syntax_node().parent(db)?)?;
+        let method_call = call
+            .as_syntax_node()
+            .parent(db)
+            .and_then(|parent| ast::ExprBinary::cast(db, parent))
+            .filter(|binary| {
+                matches!(binary.op(db), ast::BinaryOperator::Dot(_))
+                    && binary.rhs(db).as_syntax_node() == call.as_syntax_node()
+            });
+
+        Some(Self {
+            file: call.as_syntax_node().stable_ptr(db).file_id(db),
+            expr: method_call
+                .as_ref()
+                .map_or(call.as_syntax_node(), |binary| binary.as_syntax_node()),
+            receiver: method_call.map(|binary| binary.lhs(db)),
+            call,
+        })
+    }
+
+    /// Collects the arguments in order of parameters, starting with the receiver, if any.
+    ///
+    /// The receiver is snapshotted if the method takes `self` by snapshot.
+    fn arguments(
+        &self,
+        db: &'db AnalysisDatabase,
+        self_param: Option<&ast::Param<'db>>,
+    ) -> Option<Vec<Argument>> {
+        let mut arguments = vec![];
+        if let Some(receiver) = &self.receiver {
+            let takes_snapshot = self_param?
+                .type_clause(db)
+                .as_syntax_node()
+                .get_text_without_trivia(db)
+                .to_string(db)
+                .trim_start_matches(':')
+                .trim_start()
+                .starts_with('@');
+            let is_snapshot = find_expr_type(db, receiver.as_syntax_node())
+                .is_some_and(|ty| matches!(ty.long(db), TypeLongId::Snapshot(_)));
+            let text = receiver.as_syntax_node().get_text_without_trivia(db).to_string(db);
+
+            arguments.push(if takes_snapshot && !is_snapshot {
+                Argument { text: format!("@{text}"), simple: false }
+            } else {
+                Argument { text, simple: is_simple(receiver) }
+            });
+        }
+
+        for arg in self.call.arguments(db).arguments(db).elements(db) {
+            let value = match arg.arg_clause(db) {
+                ArgClause::Unnamed(unnamed) => unnamed.value(db),
+                ArgClause::Named(named) => named.value(db),
+                ArgClause::FieldInitShorthand(shorthand) => {
+                    let text = shorthand.name(db).name(db).text(db).to_string(db);
+                    arguments.push(Argument { text, simple: true });
+                    continue;
+                }
+            };
+            let text = value.as_syntax_node().get_text_without_trivia(db).to_string(db);
+            arguments.push(Argument { text, simple: is_simple(&value) });
+        }
+
+        Some(arguments)
+    }
+}
+
+/// Finds `return` statements in the function body, skipping the ones of closures.
+fn returns<'db>(
+    db: &'db AnalysisDatabase,
+    node: SyntaxNode<'db>,
+) -> Vec<ast::StatementReturn<'db>> {
+    node.descendants(db)
+        .filter_map(|node| ast::StatementReturn::cast(db, node))
+        .filter(|statement| {
+            statement
+                .as_syntax_node()
+                .ancestor_of_kind(db, SyntaxKind::ExprClosure)
+                .is_none_or(|closure| !closure.is_descendant_or_self(db, &node))
+        })
+        .collect()
+}
+
+fn is_simple(expr: &ast::Expr<'_>) -> bool {
+    matches!(
+        expr,
+        ast::Expr::Path(_)
+            | ast::Expr::Literal(_)
+            | ast::Expr::ShortString(_)
+            | ast::Expr::True(_)
+            | ast::Expr::False(_)
+    )
+}
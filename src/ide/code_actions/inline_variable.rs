@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextPosition};
+use cairo_lang_semantic::types::TypesSemantic;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedSyntaxNode, ast};
+use cairo_language_common::CommonGroup;
+use itertools::Itertools;
+use lsp_types::{CodeAction, CodeActionKind, TextEdit, Url, WorkspaceEdit};
+
+use super::helpers::{has_no_side_effects, is_mutated, node_removal};
+use crate::lang::db::{AnalysisDatabase, LsSyntaxGroup};
+use crate::lang::defs::{SymbolDef, SymbolSearch};
+use crate::lang::lsp::ToLsp;
+use crate::lang::types::find_expr_type;
+
+/// Code action replacing all usages of a variable bound by a `let` statement with its initializer
+/// and removing the statement.
+///
+/// The initializer is evaluated at the usages instead of at the binding, so it must mean the same
+/// there: variables it refers to cannot be shadowed before the usages or mutated after the binding.
+/// Initializers with side effects, and values which are not `Copy` and can be moved only once, are
+/// inlined only if the variable is used exactly once, outside of loops and closures entered after
+/// the binding.
+pub fn inline_variable<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    position: TextPosition,
+    uri: Url,
+) -> Option<CodeAction> {
+    let identifier = db.find_identifier_at_position(file, position)?;
+    let pattern = ast::PatternIdentifier::cast(db, identifier.as_syntax_node().parent(db)?)?;
+    // Mutable variables can be assigned more than once.
+    let modifiers = pattern.modifiers(db).as_syntax_node().get_text_without_trivia(db);
+    if !modifiers.to_string(db).is_empty() {
+        return None;
+    }
+    let statement = ast::StatementLet::cast(db, pattern.as_syntax_node().parent(db)?)?;
+    let initializer = statement.rhs(db);
+
+    let usages = SymbolSearch::find_definition(db, &identifier)?.usages(db).collect();
+    if usages.is_empty() {
+        return None;
+    }
+
+    let ty = find_expr_type(db, initializer.as_syntax_node())?;
+    let is_pure = has_no_side_effects(db, &initializer);
+    if ty.is_missing(db) || (usages.len() > 1 && (!is_pure || db.copyable(ty).is_err())) {
+        return None;
+    }
+
+    let statement_end = statement.as_syntax_node().span_without_trivia(db).end;
+    let referred = initializer
+        .as_syntax_node()
+        .descendants(db)
+        .filter_map(|node| ast::TerminalIdentifier::cast(db, node))
+        .collect_vec();
+    if referred.iter().any(|identifier| is_mutated_after(db, file, identifier, statement_end)) {
+        return None;
+    }
+    let referred_names =
+        referred.iter().map(|identifier| identifier.text(db).to_string(db)).collect_vec();
+
+    let initializer_text = initializer.as_syntax_node().get_text_without_trivia(db).to_string(db);
+    let needs_parens = matches!(initializer, ast::Expr::Binary(_) | ast::Expr::Unary(_));
+
+    let mut edits = vec![node_removal(db, file, statement.as_syntax_node())?];
+    for usage in usages {
+        let location = usage.location();
+        if location.file_id != file {
+            return None;
+        }
+
+        // Shorthands like `Struct { x }` are usages too, but the initializer cannot replace them.
+        let path = db
+            .find_syntax_node_at_offset(file, location.span.start)?
+            .ancestor_of_type::<ast::ExprPath>(db)
+            .filter(|path| path.as_syntax_node().span_without_trivia(db) == location.span)?;
+        if (!is_pure && is_evaluated_repeatedly(db, path.as_syntax_node(), statement_end))
+            || is_shadowed(db, path.as_syntax_node(), &referred_names, statement_end)
+        {
+            return None;
+        }
+
+        let in_operator = path.as_syntax_node().parent(db).is_some_and(|parent| {
+            matches!(
+                parent.kind(db),
+                SyntaxKind::ExprBinary | SyntaxKind::ExprUnary | SyntaxKind::ExprErrorPropagate
+            )
+        });
+        let new_text = if needs_parens && in_operator {
+            format!("({initializer_text})")
+        } else {
+            initializer_text.clone()
+        };
+
+        edits
+            .push(TextEdit { range: location.span.position_in_file(db, file)?.to_lsp(), new_text });
+    }
+
+    Some(CodeAction {
+        title: format!("Inline variable `{}`", identifier.text(db).to_string(db)),
+        kind: Some(CodeActionKind::REFACTOR_INLINE),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri, edits)])),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        ..Default::default()
+    })
+}
+
+/// Checks if the variable referred to by the identifier is mutated after the offset.
+///
+/// Mutations in a loop may precede a usage in the next iteration, so all the later ones count.
+fn is_mutated_after<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    identifier: &ast::TerminalIdentifier<'db>,
+    offset: TextOffset,
+) -> bool {
+    let Some(search) = SymbolSearch::find_definition(db, identifier) else {
+        return false;
+    };
+    let SymbolDef::Variable(variable) = &search.def else {
+        return false;
+    };
+    if !variable.is_mutable(db) {
+        return false;
+    }
+
+    search.usages(db).collect().into_iter().any(|usage| {
+        let location = usage.location();
+        location.file_id == file
+            && location.span.start >= offset
+            && db
+                .find_syntax_node_at_offset(file, location.span.start)
+                .and_then(|node| node.ancestor_of_type::<ast::TerminalIdentifier>(db))
+                .is_some_and(|identifier| is_mutated(db, &identifier))
+    })
+}
+
+/// Checks if any of the names is bound between the offset and the usage,
+/// so that it may refer to a different symbol at the usage than in the initializer.
+fn is_shadowed<'db>(
+    db: &'db AnalysisDatabase,
+    usage: SyntaxNode<'db>,
+    names: &[String],
+    offset: TextOffset,
+) -> bool {
+    let Some(body) =
+        usage.ancestors(db).filter(|node| node.kind(db) == SyntaxKind::ExprBlock).last()
+    else {
+        return true;
+    };
+    let usage_start = usage.span_without_trivia(db).start;
+
+    body.descendants(db)
+        .filter_map(|node| match node.kind(db) {
+            SyntaxKind::PatternIdentifier => {
+                Some(ast::PatternIdentifier::from_syntax_node(db, node).name(db))
+            }
+            SyntaxKind::Param => Some(ast::Param::from_syntax_node(db, node).name(db)),
+            _ => None,
+        })
+        .filter(|name| {
+            let start = name.as_syntax_node().span_without_trivia(db).start;
+            offset <= start && start < usage_start
+        })
+        .any(|name| names.contains(&name.text(db).to_string(db)))
+}
+
+/// Checks if the usage is inside a loop or closure which starts after the offset,
+/// so that the inlined initializer would be evaluated more than once.
+fn is_evaluated_repeatedly<'db>(
+    db: &'db AnalysisDatabase,
+    usage: SyntaxNode<'db>,
+    offset: TextOffset,
+) -> bool {
+    usage.ancestors(db).any(|node| {
+        matches!(
+            node.kind(db),
+            SyntaxKind::ExprLoop
+                | SyntaxKind::ExprWhile
+                | SyntaxKind::ExprFor
+                | SyntaxKind::ExprClosure
+        ) && node.span_without_trivia(db).start >= offset
+    })
+}
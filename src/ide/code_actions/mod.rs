@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 use std::ops::Not;
 
-use cairo_lang_syntax::node::SyntaxNode;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode, ast};
 use itertools::Itertools;
 use lsp_types::{
     CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
@@ -18,10 +19,17 @@ mod add_missing_trait;
 mod cairo_lint;
 mod create_module_file;
 mod expand_macro;
+mod extract_function;
+mod extract_variable;
 mod fill_struct_fields;
 mod fill_trait_members;
+mod helpers;
+mod inline_function;
+mod inline_variable;
 mod make_variable_mutable;
 mod missing_import;
+mod organize_imports;
+mod remove_unused;
 mod rename_unused_variable;
 mod scarb_manifest;
 mod suggest_similar_identifier;
@@ -59,9 +67,81 @@ pub fn code_actions(
         );
     }
 
+    actions.extend(
+        get_code_actions_for_selection(db, &params).into_iter().map(CodeActionOrCommand::from),
+    );
+
     Some(actions)
 }
 
+/// Generate refactoring code actions for the selection in context of [`CodeActionParams`].
+///
+/// Unlike fixes, these do not depend on diagnostics, so they are only computed if the client
+/// asked for refactorings or did not restrict kinds of code actions at all.
+fn get_code_actions_for_selection(
+    db: &AnalysisDatabase,
+    params: &CodeActionParams,
+) -> Vec<CodeAction> {
+    let uri = &params.text_document.uri;
+    let Some(file) = db.file_for_url(uri) else {
+        return vec![];
+    };
+    let range = params.range.to_cairo();
+
+    // Cheap syntactic checks go first, so that no semantic analysis is run for refactorings which
+    // cannot apply to the selection, e.g. on every cursor move when `only` is not given.
+    let mut result = vec![];
+    if params.range.start != params.range.end
+        && is_kind_requested(params, &CodeActionKind::REFACTOR_EXTRACT)
+    {
+        result.extend(extract_variable::extract_variable(db, file, range, uri.clone()));
+        result.extend(extract_function::extract_function(db, file, range, uri.clone()));
+    }
+    if is_kind_requested(params, &CodeActionKind::REFACTOR_INLINE)
+        && let Some(identifier) = db.find_identifier_at_position(file, range.start)
+    {
+        let parent = identifier.as_syntax_node().parent(db);
+        match parent.map(|parent| parent.kind(db)) {
+            Some(SyntaxKind::PatternIdentifier) => {
+                result.extend(inline_variable::inline_variable(db, file, range.start, uri.clone()));
+            }
+            kind if kind == Some(SyntaxKind::FunctionDeclaration) || is_callee(db, &identifier) => {
+                result.extend(inline_function::inline_function(db, file, range.start));
+            }
+            _ => {}
+        }
+    }
+    // Organizing imports touches the whole file, so it is offered only on explicit request,
+    // e.g. as an on-save action.
+    if params.context.only.is_some()
+        && is_kind_requested(params, &CodeActionKind::SOURCE_ORGANIZE_IMPORTS)
+    {
+        result.extend(organize_imports::organize_imports(db, file, uri.clone()));
+    }
+    result
+}
+
+/// Checks if the identifier is a part of the path of a called function or method.
+fn is_callee<'db>(db: &'db AnalysisDatabase, identifier: &ast::TerminalIdentifier<'db>) -> bool {
+    identifier
+        .as_syntax_node()
+        .ancestor_of_type::<ast::ExprPath>(db)
+        .and_then(|path| path.as_syntax_node().parent(db))
+        .is_some_and(|parent| parent.kind(db) == SyntaxKind::ExprFunctionCall)
+}
+
+/// Checks if the client is interested in code actions of the given kind.
+///
+/// Kinds are hierarchical, so e.g. requesting `refactor` includes `refactor.extract`.
+fn is_kind_requested(params: &CodeActionParams, kind: &CodeActionKind) -> bool {
+    params.context.only.as_ref().is_none_or(|only| {
+        only.iter().any(|requested| {
+            kind.as_str() == requested.as_str()
+                || kind.as_str().starts_with(&format!("{}.", requested.as_str()))
+        })
+    })
+}
+
 /// Generate code actions for a given diagnostics in context of [`CodeActionParams`].
 ///
 /// # Arguments
@@ -103,13 +183,23 @@ fn get_code_actions_for_diagnostics(
         match code {
             Some("E2200") => vec![],
 
-            Some("E0001") => rename_unused_variable::rename_unused_variable(
-                db,
-                &ctx.node,
-                (*diagnostic).clone(),
-                uri.clone(),
-            )
-            .to_vec(),
+            Some("E0001") => {
+                let removal = remove_unused::remove_unused_variable(
+                    db,
+                    &ctx.node,
+                    (*diagnostic).clone(),
+                    uri.clone(),
+                );
+                // Both fixes cannot be applied together, so only one of them goes to "Fix All".
+                let rename = rename_unused_variable::rename_unused_variable(
+                    db,
+                    &ctx.node,
+                    (*diagnostic).clone(),
+                    uri.clone(),
+                )
+                .map(|action| CodeAction { is_preferred: Some(removal.is_none()), ..action });
+                rename.into_iter().chain(removal).collect()
+            }
             Some("E0002") => {
                 let fixes = add_missing_trait::add_missing_trait(db, ctx, uri.clone());
                 if let Some(fixes) = fixes
@@ -149,7 +239,14 @@ fn get_code_actions_for_diagnostics(
                 debug!("no code actions for diagnostic code: {code}");
                 vec![]
             }
-            None => Default::default(),
+            // Unused imports have no error code, so they are resolved from semantic diagnostics.
+            None => remove_unused::remove_unused_import(
+                db,
+                &ctx.node,
+                (*diagnostic).clone(),
+                uri.clone(),
+            )
+            .to_vec(),
         }
     }));
 
@@ -167,7 +264,12 @@ fn with_fix_all(mut result: Vec<CodeAction>) -> Vec<CodeAction> {
             for (url, edits) in changes {
                 let entry = acc.entry(url).or_default();
 
-                entry.extend(edits);
+                // Fixes of sibling unused imports share the same edit.
+                for edit in edits {
+                    if !entry.contains(&edit) {
+                        entry.push(edit);
+                    }
+                }
             }
             acc
         });
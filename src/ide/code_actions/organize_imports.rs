@@ -0,0 +1,267 @@
+use std::collections::{BTreeMap, HashMap};
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedStablePtr, TypedSyntaxNode, ast};
+use itertools::Itertools;
+use lsp_types::{CodeAction, CodeActionKind, TextEdit, Url, WorkspaceEdit};
+
+use super::helpers::{line_indentation, node_removal};
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::importer::use_position;
+use crate::lang::lsp::ToLsp;
+
+/// Code action removing unused `use` items of all modules in the file, inline ones included,
+/// and merging, sorting and deduplicating the remaining ones.
+///
+/// Imports sharing the same parent path are merged into a single `use` item, placed where the
+/// first import of the module is.
+/// Items with attributes or comments are left untouched.
+pub fn organize_imports<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    uri: Url,
+) -> Option<CodeAction> {
+    let mut modules = vec![];
+    for module_id in db.file_modules(file).ok()?.iter().copied() {
+        collect_inline_modules(db, module_id, &mut modules);
+    }
+
+    let edits: Vec<_> = modules
+        .into_iter()
+        .filter_map(|module_id| module_edits(db, file, module_id))
+        .flatten()
+        .collect();
+    if edits.is_empty() {
+        return None;
+    }
+
+    Some(CodeAction {
+        title: "Organize imports".to_string(),
+        kind: Some(CodeActionKind::SOURCE_ORGANIZE_IMPORTS),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri, edits)])),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        ..Default::default()
+    })
+}
+
+fn collect_inline_modules<'db>(
+    db: &'db AnalysisDatabase,
+    module_id: ModuleId<'db>,
+    modules: &mut Vec<ModuleId<'db>>,
+) {
+    modules.push(module_id);
+
+    let Ok(submodules) = db.module_submodules_ids(module_id) else { return };
+    for submodule in submodules.iter().copied() {
+        if db.is_submodule_inline(submodule) {
+            collect_inline_modules(db, ModuleId::Submodule(submodule), modules);
+        }
+    }
+}
+
+/// A single imported name, with `use` trees flattened.
+struct Import {
+    visibility: String,
+    parent: Vec<String>,
+    /// The imported name with its alias, `self` or `*`.
+    name: String,
+}
+
+fn module_edits<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    module_id: ModuleId<'db>,
+) -> Option<Vec<TextEdit>> {
+    let items: Vec<ast::ItemUse<'db>> = db
+        .module_uses_ids(module_id)
+        .ok()?
+        .iter()
+        .map(|use_id| use_id.stable_ptr(db).untyped())
+        .filter(|stable_ptr| stable_ptr.file_id(db) == file)
+        .filter_map(|stable_ptr| stable_ptr.lookup(db).ancestor_of_type::<ast::ItemUse>(db))
+        .filter(|item| is_organizable(db, item))
+        .unique()
+        .sorted_by_key(|item| item.as_syntax_node().span_without_trivia(db).start)
+        .collect();
+    if items.is_empty() {
+        return None;
+    }
+
+    let unused: Vec<SyntaxNode<'db>> = db
+        .module_semantic_diagnostics(module_id)
+        .ok()?
+        .get_diagnostics_without_duplicates(db)
+        .into_iter()
+        .filter(|diagnostic| matches!(diagnostic.kind, SemanticDiagnosticKind::UnusedImport(_)))
+        .filter_map(|diagnostic| {
+            diagnostic.stable_location.syntax_node(db).ancestors_with_self(db).find(|node| {
+                matches!(node.kind(db), SyntaxKind::UsePathLeaf | SyntaxKind::UsePathStar)
+            })
+        })
+        .collect();
+
+    let mut imports = vec![];
+    for item in &items {
+        let visibility =
+            item.visibility(db).as_syntax_node().get_text_without_trivia(db).to_string(db);
+        flatten(db, item.use_path(db), &visibility, &mut vec![], &unused, &mut imports);
+    }
+
+    let content = db.file_content(file)?;
+    let first_span = items.first()?.as_syntax_node().span_without_trivia(db);
+    let last_span = items.last()?.as_syntax_node().span_without_trivia(db);
+    let indent = line_indentation(content, first_span.start);
+    let separator = format!("\n{indent}");
+
+    let organized = render(imports).join(&separator);
+    let original = items
+        .iter()
+        .map(|item| item.as_syntax_node().get_text_without_trivia(db).to_string(db))
+        .join(&separator);
+    let span_text = TextSpan::new(first_span.start, last_span.end).take(content);
+    if organized == original && span_text == original {
+        return None;
+    }
+
+    // Keep the imports where the ones added by code actions would go.
+    let anchor_position = use_position(db, module_id).map(|use_position| use_position.position);
+    let anchor = items
+        .iter()
+        .position(|item| {
+            item.as_syntax_node().span_without_trivia(db).position_in_file(db, file).is_some_and(
+                |position| {
+                    let range = position.to_lsp();
+                    anchor_position
+                        .is_some_and(|anchor| range.start <= anchor && anchor <= range.end)
+                },
+            )
+        })
+        .unwrap_or(0);
+
+    let mut edits = vec![];
+    for (i, item) in items.iter().enumerate() {
+        if i == anchor && !organized.is_empty() {
+            edits.push(TextEdit {
+                range: item
+                    .as_syntax_node()
+                    .span_without_trivia(db)
+                    .position_in_file(db, file)?
+                    .to_lsp(),
+                new_text: organized.clone(),
+            });
+        } else {
+            edits.push(node_removal(db, file, item.as_syntax_node())?);
+        }
+    }
+
+    // Do not leave a blank line behind if all the imports were removed.
+    if organized.is_empty()
+        && let Some(last) = edits.last_mut()
+        && last.range.end.character == 0
+        && content
+            .lines()
+            .nth(last.range.end.line as usize)
+            .is_some_and(|line| line.trim().is_empty())
+    {
+        last.range.end.line += 1;
+    }
+
+    Some(edits)
+}
+
+/// Checks if the item can be reformatted without losing attributes or comments attached to it.
+fn is_organizable<'db>(db: &'db AnalysisDatabase, item: &ast::ItemUse<'db>) -> bool {
+    let node = item.as_syntax_node();
+    item.attributes(db).elements(db).next().is_none()
+        && !node.get_text(db).to_string(db).contains("//")
+}
+
+fn flatten<'db>(
+    db: &'db AnalysisDatabase,
+    use_path: ast::UsePath<'db>,
+    visibility: &str,
+    parent: &mut Vec<String>,
+    unused: &[SyntaxNode<'db>],
+    imports: &mut Vec<Import>,
+) {
+    let text = |node: SyntaxNode<'db>| node.get_text_without_trivia(db).to_string(db);
+    let mut import = |name: String, parent: &[String]| {
+        imports.push(Import { visibility: visibility.to_string(), parent: parent.to_vec(), name })
+    };
+
+    match use_path {
+        ast::UsePath::Leaf(leaf) => {
+            if unused.contains(&leaf.as_syntax_node()) {
+                return;
+            }
+            let ident = text(leaf.ident(db).as_syntax_node());
+            let name = match leaf.alias_clause(db) {
+                ast::OptionAliasClause::AliasClause(clause) => {
+                    format!("{ident} as {}", clause.alias(db).text(db).to_string(db))
+                }
+                ast::OptionAliasClause::Empty(_) => ident,
+            };
+            import(name, parent);
+        }
+        ast::UsePath::Star(star) => {
+            if !unused.contains(&star.as_syntax_node()) {
+                import("*".to_string(), parent);
+            }
+        }
+        ast::UsePath::Single(single) => {
+            parent.push(text(single.ident(db).as_syntax_node()));
+            flatten(db, single.use_path(db), visibility, parent, unused, imports);
+            parent.pop();
+        }
+        ast::UsePath::Multi(multi) => {
+            for use_path in multi.use_paths(db).elements(db) {
+                flatten(db, use_path, visibility, parent, unused, imports);
+            }
+        }
+    }
+}
+
+/// Renders `use` items, each importing all names with the same parent path, sorted by the path.
+fn render(imports: Vec<Import>) -> Vec<String> {
+    let mut groups: BTreeMap<(String, Vec<String>), Vec<String>> = BTreeMap::new();
+    for Import { visibility, parent, name } in imports {
+        groups.entry((visibility, parent)).or_default().push(name);
+    }
+
+    let mut lines = vec![];
+    for ((visibility, parent), mut names) in groups {
+        let use_kw =
+            if visibility.is_empty() { "use".to_string() } else { format!("{visibility} use") };
+        names.sort_by_key(|name| (!is_self(name), name == "*", name.clone()));
+        names.dedup();
+
+        let path = parent.join("::");
+        match names.as_slice() {
+            // Top-level names, like crates, cannot be grouped.
+            names if parent.is_empty() => {
+                lines.extend(names.iter().map(|name| format!("{use_kw} {name};")));
+            }
+            [name] if is_self(name) => {
+                lines.push(format!("{use_kw} {path}{};", name.trim_start_matches("self")))
+            }
+            [name] => lines.push(format!("{use_kw} {path}::{name};")),
+            names => lines.push(format!("{use_kw} {path}::{{{}}};", names.join(", "))),
+        }
+    }
+    lines
+}
+
+/// Checks if the name imports the parent module itself, possibly under an alias.
+fn is_self(name: &str) -> bool {
+    name == "self" || name.starts_with("self as ")
+}
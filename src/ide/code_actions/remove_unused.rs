@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+use std::iter;
+
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::lsp_helpers::LspHelpers;
+use cairo_lang_semantic::types::TypesSemantic;
+use cairo_lang_syntax::node::ast::PatternIdentifier;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode, ast};
+use itertools::Itertools;
+use lsp_types::{CodeAction, CodeActionKind, Diagnostic, TextEdit, Url, WorkspaceEdit};
+
+use super::helpers::node_removal;
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::lsp::ToLsp;
+use crate::lang::types::find_expr_type;
+
+/// Create a code action that removes an unused leaf of a `use` tree.
+///
+/// Unused siblings adjacent to the leaf are removed along with it, so that fixes of all of them
+/// share a single edit. Groups left empty are removed as well, up to the whole `use` item.
+pub fn remove_unused_import<'db>(
+    db: &'db AnalysisDatabase,
+    node: &SyntaxNode<'db>,
+    diagnostic: Diagnostic,
+    uri: Url,
+) -> Option<CodeAction> {
+    let leaf = use_leaf(db, *node)?;
+    let unused = unused_leaves(db, leaf)?;
+    if !unused.contains(&leaf) {
+        return None;
+    }
+    let file = leaf.stable_ptr(db).file_id(db);
+
+    let mut removed = leaf;
+    let (edit, removed_nodes) = loop {
+        let parent = removed.parent(db)?;
+        match parent.kind(db) {
+            SyntaxKind::UsePathSingle | SyntaxKind::UsePathMulti => removed = parent,
+            SyntaxKind::UsePathList => {
+                let paths = parent
+                    .get_children(db)
+                    .iter()
+                    .copied()
+                    .filter(|child| child.kind(db) != SyntaxKind::TerminalComma)
+                    .collect_vec();
+                let is_unused = |path: &SyntaxNode<'db>| is_unused_path(db, *path, &unused);
+                if paths.iter().all(is_unused) {
+                    removed = parent;
+                    continue;
+                }
+
+                // Remove the run of unused paths containing this one.
+                let index = paths.iter().position(|path| *path == removed)?;
+                let first =
+                    paths[..index].iter().rposition(|path| !is_unused(path)).map_or(0, |i| i + 1);
+                let end = paths[index..]
+                    .iter()
+                    .position(|path| !is_unused(path))
+                    .map_or(paths.len(), |i| index + i);
+                let run = &paths[first..end];
+
+                // Remove the separator following the run, or preceding it if it ends the list.
+                let span = match paths.get(end) {
+                    Some(next) => TextSpan::new(
+                        run.first()?.span_without_trivia(db).start,
+                        next.span_without_trivia(db).start,
+                    ),
+                    None => TextSpan::new(
+                        paths.get(first.checked_sub(1)?)?.span_without_trivia(db).end,
+                        run.last()?.span_without_trivia(db).end,
+                    ),
+                };
+
+                let edit = TextEdit {
+                    range: span.position_in_file(db, file)?.to_lsp(),
+                    new_text: String::new(),
+                };
+                break (edit, run.to_vec());
+            }
+            SyntaxKind::ItemUse => break (node_removal(db, file, parent)?, vec![parent]),
+            _ => return None,
+        }
+    };
+
+    let names = unused
+        .iter()
+        .filter(|leaf| removed_nodes.iter().any(|node| leaf.is_descendant_or_self(db, node)))
+        .sorted_by_key(|leaf| leaf.offset(db))
+        .map(|leaf| format!("`{}`", leaf.get_text_without_trivia(db).to_string(db)))
+        .collect_vec();
+    let title = match names.as_slice() {
+        [name] => format!("Remove unused import {name}"),
+        names => format!("Remove unused imports {}", names.join(", ")),
+    };
+
+    Some(unused_fix(title, edit, diagnostic, uri))
+}
+
+/// Create a code action that removes a `let` statement binding an unused variable.
+///
+/// The statement is removed only if its initializer is a literal or a copyable value, so that
+/// removing it can neither skip side effects nor drop a moved value.
+pub fn remove_unused_variable<'db>(
+    db: &'db AnalysisDatabase,
+    node: &SyntaxNode<'db>,
+    diagnostic: Diagnostic,
+    uri: Url,
+) -> Option<CodeAction> {
+    let pattern = if let Some(path) = node.ancestor_of_kind(db, SyntaxKind::ExprPath) {
+        path
+    } else {
+        node.ancestor_of_type::<PatternIdentifier>(db)?.as_syntax_node()
+    };
+    let statement = ast::StatementLet::cast(db, pattern.parent(db)?)?;
+    if statement.pattern(db).as_syntax_node() != pattern
+        || !matches!(statement.let_else_clause(db), ast::OptionLetElseClause::Empty(_))
+        || !can_be_dropped(db, &statement.rhs(db))
+    {
+        return None;
+    }
+
+    let name = pattern.get_text_without_trivia(db).to_string(db);
+    let name = name.trim_start_matches("mut ").trim();
+    let file = statement.as_syntax_node().stable_ptr(db).file_id(db);
+    let edit = node_removal(db, file, statement.as_syntax_node())?;
+
+    Some(unused_fix(format!("Remove unused variable `{name}`"), edit, diagnostic, uri))
+}
+
+fn unused_fix(title: String, edit: TextEdit, diagnostic: Diagnostic, uri: Url) -> CodeAction {
+    CodeAction {
+        title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        is_preferred: Some(true),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri, vec![edit])])),
+            document_changes: None,
+            change_annotations: None,
+        }),
+        diagnostics: Some(vec![diagnostic]),
+        ..Default::default()
+    }
+}
+
+fn use_leaf<'db>(db: &'db AnalysisDatabase, node: SyntaxNode<'db>) -> Option<SyntaxNode<'db>> {
+    node.ancestors_with_self(db)
+        .find(|node| matches!(node.kind(db), SyntaxKind::UsePathLeaf | SyntaxKind::UsePathStar))
+}
+
+/// Finds leaves of `use` trees reported as unused imports in the module containing the node.
+fn unused_leaves<'db>(
+    db: &'db AnalysisDatabase,
+    node: SyntaxNode<'db>,
+) -> Option<Vec<SyntaxNode<'db>>> {
+    let module_id = db.find_module_containing_node(node)?;
+    let unused = db
+        .module_semantic_diagnostics(module_id)
+        .ok()?
+        .get_diagnostics_without_duplicates(db)
+        .into_iter()
+        .filter(|diagnostic| matches!(diagnostic.kind, SemanticDiagnosticKind::UnusedImport(_)))
+        .filter_map(|diagnostic| use_leaf(db, diagnostic.stable_location.syntax_node(db)))
+        .collect();
+    Some(unused)
+}
+
+/// Checks if all the leaves of a `use` path are unused.
+fn is_unused_path<'db>(
+    db: &'db AnalysisDatabase,
+    path: SyntaxNode<'db>,
+    unused: &[SyntaxNode<'db>],
+) -> bool {
+    let mut leaves = iter::once(path)
+        .chain(path.descendants(db))
+        .filter(|node| matches!(node.kind(db), SyntaxKind::UsePathLeaf | SyntaxKind::UsePathStar));
+    leaves.all(|leaf| unused.contains(&leaf))
+}
+
+/// Checks if dropping the expression cannot change the behaviour of the program.
+///
+/// Only literals and paths to copyable values qualify, since operators and function calls may
+/// have side effects or panic, and any other use of a non-copyable value moves it.
+fn can_be_dropped<'db>(db: &'db AnalysisDatabase, expr: &ast::Expr<'db>) -> bool {
+    match expr {
+        ast::Expr::Literal(_)
+        | ast::Expr::ShortString(_)
+        | ast::Expr::String(_)
+        | ast::Expr::True(_)
+        | ast::Expr::False(_) => true,
+        ast::Expr::Path(path) => {
+            find_expr_type(db, path.as_syntax_node()).is_some_and(|ty| db.copyable(ty).is_ok())
+        }
+        ast::Expr::Parenthesized(expr) => can_be_dropped(db, &expr.expr(db)),
+        ast::Expr::Tuple(tuple) => {
+            tuple.expressions(db).elements(db).all(|expr| can_be_dropped(db, &expr))
+        }
+        _ => false,
+    }
+}
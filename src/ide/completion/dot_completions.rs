@@ -19,11 +19,12 @@ use tracing::debug;
 
 use crate::ide::completion::helpers::binary_expr::dot_rhs::dot_expr_rhs;
 use crate::ide::completion::helpers::snippets::TypedSnippet;
+use crate::ide::completion::resolve::CompletionItemData;
 use crate::ide::completion::{CompletionItemOrderable, CompletionRelevance};
 use crate::ide::format::types::format_type;
 use crate::lang::analysis_context::AnalysisContext;
 use crate::lang::db::AnalysisDatabase;
-use crate::lang::importer::import_edit_for_trait_if_needed;
+use crate::lang::importer::trait_import_path_if_needed;
 use crate::lang::methods::find_methods_for_type;
 use crate::lang::text_matching::text_matches;
 
@@ -147,12 +148,8 @@ fn completion_for_method<'db>(
     let name = trait_function.name(db).to_string(db);
     let signature = db.trait_function_signature(trait_function).ok()?;
 
-    let mut additional_text_edits = vec![];
-
-    // If the trait is not in scope, add a use statement.
-    if let Some(edit) = import_edit_for_trait_if_needed(db, ctx, trait_id) {
-        additional_text_edits.push(edit);
-    }
+    // If the trait is not in scope, a use statement is added when the item gets resolved.
+    let import_path = trait_import_path_if_needed(db, ctx, trait_id);
     let function_call_snippet = TypedSnippet::function_call(db, &name, signature, Some(trait_id));
 
     let completion = CompletionItemOrderable {
@@ -165,7 +162,7 @@ fn completion_for_method<'db>(
                 detail: None,
             }),
             kind: Some(CompletionItemKind::METHOD),
-            additional_text_edits: Some(additional_text_edits),
+            data: Some(CompletionItemData { import_path, ..Default::default() }.into_value()),
             ..CompletionItem::default()
         },
         // We set the relevance to medium as we want methods to be shown after the members of the struct.
@@ -13,10 +13,10 @@ use serde::Serialize;
 
 use crate::ide::completion::helpers::completion_kind::importable_completion_kind;
 use crate::ide::completion::helpers::snippets::TypedSnippet;
+use crate::ide::completion::resolve::CompletionItemData;
 use crate::lang::analysis_context::AnalysisContext;
 use crate::lang::db::AnalysisDatabase;
 use crate::lang::importable::{importable_crate_id, importable_syntax_node};
-use crate::lang::importer::new_import_edit;
 use crate::lang::text_matching::text_matches;
 
 pub struct FirstSegmentCompletionCandidate<'a> {
@@ -207,16 +207,11 @@ impl ImportableCompletionItem<'_> {
             return None;
         }
 
-        let additional_text_edits = if is_not_in_scope
-            && !path_segments.is_empty()
-            && let Some(import_edit) = new_import_edit(db, ctx, path_segments.join("::"))
-        {
-            Some(vec![import_edit])
-        } else {
-            None
-        };
+        // The import edit is computed when the item gets resolved.
+        let import_path =
+            (is_not_in_scope && !path_segments.is_empty()).then(|| path_segments.join("::"));
 
-        let does_require_import = additional_text_edits.is_some();
+        let does_require_import = import_path.is_some();
         let importable_crate = importable_crate_id(db, *importable);
         let is_current_crate = importable_crate == current_crate;
         let is_core = *importable_crate.long(db) == CrateLongId::core(db);
@@ -237,7 +232,14 @@ impl ImportableCompletionItem<'_> {
                             .map(|typed_snippet| typed_snippet.type_hint)
                             .unwrap_or_default(),
                     }),
-                    additional_text_edits,
+                    data: Some(
+                        CompletionItemData {
+                            item_path: Some(path_str.to_string()),
+                            import_path,
+                            ..Default::default()
+                        }
+                        .into_value(),
+                    ),
                     ..CompletionItem::default()
                 },
                 relevance: get_item_relevance(!does_require_import, is_current_crate, is_core),
@@ -15,13 +15,15 @@ use cairo_language_common::CommonGroup;
 use doc_links::doc_link_completions;
 use function::params::params_completions;
 use function::variables::variables_completions;
-use lsp_types::{CompletionParams, CompletionResponse, CompletionTriggerKind};
+use lsp_types::{ClientCapabilities, CompletionParams, CompletionResponse, CompletionTriggerKind};
 use path::path_suffix_completions;
 use pattern::{enum_pattern_completions, struct_pattern_completions};
 use self_completions::self_completions;
 use struct_constructor::struct_constructor_completions;
 
 use self::dot_completions::dot_completions;
+pub use self::resolve::resolve_completion_item;
+use self::resolve::{attach_position, context_at, resolve_import_edit};
 use crate::ide::completion::expr::macro_call::{
     expr_inline_macro_completions, top_level_inline_macro_completions,
 };
@@ -33,6 +35,7 @@ use crate::ide::completion::use_statement::use_completions;
 use crate::lang::analysis_context::AnalysisContext;
 use crate::lang::db::{AnalysisDatabase, LsSyntaxGroup};
 use crate::lang::lsp::{LsProtoGroup, ToCairo};
+use crate::lsp::capabilities::client::ClientCapabilitiesExt;
 
 mod attribute;
 mod doc_links;
@@ -43,12 +46,20 @@ mod helpers;
 mod mod_item;
 mod path;
 mod pattern;
+mod resolve;
 mod self_completions;
 mod struct_constructor;
 mod use_statement;
 
 /// Compute completion items at a given cursor position.
-pub fn complete(params: CompletionParams, db: &AnalysisDatabase) -> Option<CompletionResponse> {
+///
+/// Documentation of the items is left to [`resolve_completion_item`], as well as import edits if
+/// the client can resolve them.
+pub fn complete(
+    params: CompletionParams,
+    db: &AnalysisDatabase,
+    client_capabilities: &ClientCapabilities,
+) -> Option<CompletionResponse> {
     let text_document_position = params.text_document_position;
     let file_id = db.file_for_url(&text_document_position.text_document.uri)?;
     let mut position = text_document_position.position;
@@ -117,12 +128,23 @@ pub fn complete(params: CompletionParams, db: &AnalysisDatabase) -> Option<Compl
         b.relevance.cmp(&a.relevance).then_with(|| compare_items_by_label_and_detail(a, b))
     });
 
+    let import_ctx = if client_capabilities.completion_resolve_additional_text_edits_support() {
+        None
+    } else {
+        context_at(db, &text_document_position)
+    };
+
     // Set the sort text as it's used to sort the items on the client side.
     // We want to keep the order the same way we have it here.
     for (index, item) in result.iter_mut().enumerate() {
         // Pad length is here to ensure we return `sort_text` in such format "1____", "10___" etc.
         // This ensures correct lexicographical ordering on the client side.
         item.item.sort_text = Some(format!("{:0width$}_", index + 1, width = 5));
+
+        attach_position(&mut item.item, &text_document_position);
+        if let Some(ctx) = &import_ctx {
+            resolve_import_edit(db, ctx, &mut item.item);
+        }
     }
 
     Some(CompletionResponse::Array(result.into_iter().map(|item| item.item).collect()))
@@ -0,0 +1,134 @@
+use cairo_lang_defs::ids::{ImportableId, LookupItemId, ModuleItemId};
+use cairo_lang_doc::db::DocGroup;
+use cairo_lang_doc::documentable_item::DocumentableItemId;
+use cairo_lang_semantic::lsp_helpers::LspHelpers;
+use lsp_types::{
+    CompletionItem, Documentation, MarkupContent, MarkupKind, TextDocumentPositionParams,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::ide::markdown::{RULE, fenced_code_block};
+use crate::lang::analysis_context::AnalysisContext;
+use crate::lang::db::{AnalysisDatabase, LsSyntaxGroup};
+use crate::lang::importer::new_import_edit;
+use crate::lang::lsp::{LsProtoGroup, ToCairo};
+
+/// Payload attached to completion items, used to compute their details when they get resolved.
+#[derive(Serialize, Deserialize, Default)]
+pub struct CompletionItemData {
+    /// Document and position at which the completion was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<TextDocumentPositionParams>,
+    /// Path of the completed item as visible from the current module.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_path: Option<String>,
+    /// Path which has to be imported for the completed item to be in scope.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import_path: Option<String>,
+}
+
+impl CompletionItemData {
+    pub fn into_value(self) -> serde_json::Value {
+        serde_json::to_value(self).expect("serialization should not fail")
+    }
+
+    fn from_item(item: &CompletionItem) -> Option<Self> {
+        serde_json::from_value(item.data.clone()?).ok()
+    }
+}
+
+/// Computes documentation and the import edit of the completion item highlighted by the user.
+pub fn resolve_completion_item(mut item: CompletionItem, db: &AnalysisDatabase) -> CompletionItem {
+    let Some(data) = CompletionItemData::from_item(&item) else {
+        return item;
+    };
+    let Some(ctx) = data.position.as_ref().and_then(|position| context_at(db, position)) else {
+        return item;
+    };
+
+    resolve_import_edit(db, &ctx, &mut item);
+
+    if item.documentation.is_none()
+        && let Some(value) =
+            data.item_path.and_then(|item_path| documentation(db, &ctx, &item_path))
+    {
+        item.documentation =
+            Some(Documentation::MarkupContent(MarkupContent { kind: MarkupKind::Markdown, value }));
+    }
+
+    item
+}
+
+/// Remembers where the completion was requested, so that the item can be resolved later.
+pub fn attach_position(item: &mut CompletionItem, position: &TextDocumentPositionParams) {
+    if let Some(mut data) = CompletionItemData::from_item(item) {
+        data.position = Some(position.clone());
+        item.data = Some(data.into_value());
+    }
+}
+
+/// Computes the edit importing the completed item if it is not in scope.
+pub fn resolve_import_edit<'db>(
+    db: &'db AnalysisDatabase,
+    ctx: &AnalysisContext<'db>,
+    item: &mut CompletionItem,
+) {
+    if item.additional_text_edits.is_none()
+        && let Some(import_path) = CompletionItemData::from_item(item).and_then(|d| d.import_path)
+    {
+        item.additional_text_edits = new_import_edit(db, ctx, import_path).map(|edit| vec![edit]);
+    }
+}
+
+/// Builds the analysis context for the position at which the completion was requested.
+pub fn context_at<'db>(
+    db: &'db AnalysisDatabase,
+    position: &TextDocumentPositionParams,
+) -> Option<AnalysisContext<'db>> {
+    let file = db.file_for_url(&position.text_document.uri)?;
+    let node = db.find_syntax_node_at_position(file, position.position.to_cairo())?;
+    AnalysisContext::from_node(db, node)
+}
+
+fn documentation<'db>(
+    db: &'db AnalysisDatabase,
+    ctx: &AnalysisContext<'db>,
+    item_path: &str,
+) -> Option<String> {
+    let importables = db.visible_importables_from_module(ctx.module_id)?;
+    let (importable, _) = importables.iter().find(|(_, path)| path.as_str() == item_path)?;
+    let item = documentable_item(*importable);
+
+    let mut md = String::new();
+    if let Some(signature) = db.get_item_signature(item) {
+        md += &fenced_code_block(&signature);
+    }
+    if let Some(doc) = db.get_item_documentation(item) {
+        if !md.is_empty() {
+            md += RULE;
+        }
+        md += &doc;
+    }
+
+    (!md.is_empty()).then_some(md)
+}
+
+fn documentable_item(importable: ImportableId<'_>) -> DocumentableItemId<'_> {
+    let module_item = match importable {
+        ImportableId::Crate(id) => return DocumentableItemId::Crate(id),
+        ImportableId::Variant(id) => return DocumentableItemId::Variant(id),
+        ImportableId::Constant(id) => ModuleItemId::Constant(id),
+        ImportableId::Submodule(id) => ModuleItemId::Submodule(id),
+        ImportableId::FreeFunction(id) => ModuleItemId::FreeFunction(id),
+        ImportableId::Struct(id) => ModuleItemId::Struct(id),
+        ImportableId::Enum(id) => ModuleItemId::Enum(id),
+        ImportableId::TypeAlias(id) => ModuleItemId::TypeAlias(id),
+        ImportableId::ImplAlias(id) => ModuleItemId::ImplAlias(id),
+        ImportableId::Trait(id) => ModuleItemId::Trait(id),
+        ImportableId::Impl(id) => ModuleItemId::Impl(id),
+        ImportableId::ExternType(id) => ModuleItemId::ExternType(id),
+        ImportableId::ExternFunction(id) => ModuleItemId::ExternFunction(id),
+        ImportableId::MacroDeclaration(id) => ModuleItemId::MacroDeclaration(id),
+    };
+    DocumentableItemId::LookupItem(LookupItemId::ModuleItem(module_item))
+}
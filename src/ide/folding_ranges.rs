@@ -0,0 +1,172 @@
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_syntax::node::SyntaxNode;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use lsp_types::{FoldingRange, FoldingRangeKind, FoldingRangeParams};
+
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::lsp::{LsProtoGroup, ToLsp};
+
+/// Computes folding ranges for a given text document.
+pub fn folding_ranges(
+    params: FoldingRangeParams,
+    db: &AnalysisDatabase,
+) -> Option<Vec<FoldingRange>> {
+    let file = db.file_for_url(&params.text_document.uri)?;
+    let root = db.file_syntax(file).ok()?;
+
+    let mut collector = FoldingRangeCollector { db, file, ranges: vec![], comments: None };
+    for node in root.descendants(db) {
+        collector.visit(node);
+    }
+    collector.flush_comments();
+
+    Some(collector.ranges)
+}
+
+struct FoldingRangeCollector<'db> {
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    ranges: Vec<FoldingRange>,
+    /// The first and last line of the run of consecutive line comments being collected.
+    comments: Option<(u32, u32)>,
+}
+
+impl<'db> FoldingRangeCollector<'db> {
+    fn visit(&mut self, node: SyntaxNode<'db>) {
+        let db = self.db;
+        match node.kind(db) {
+            SyntaxKind::ExprBlock
+            | SyntaxKind::ImplBody
+            | SyntaxKind::TraitBody
+            | SyntaxKind::ModuleBody
+            | SyntaxKind::ItemStruct
+            | SyntaxKind::ItemEnum
+            | SyntaxKind::ExprMatch => self.push_braced(node),
+            SyntaxKind::MatchArm => {
+                // Arms with block bodies are folded by the block itself.
+                let is_block_arm = node
+                    .get_children(db)
+                    .last()
+                    .is_some_and(|expr| expr.kind(db) == SyntaxKind::ExprBlock);
+                if !is_block_arm {
+                    self.push(node.span_without_trivia(db), None);
+                }
+            }
+            SyntaxKind::ModuleItemList => self.push_use_groups(node),
+            SyntaxKind::AttributeList if node.get_children(db).len() > 1 => {
+                self.push(node.span_without_trivia(db), None);
+            }
+            SyntaxKind::TokenSingleLineComment
+            | SyntaxKind::TokenSingleLineDocComment
+            | SyntaxKind::TokenSingleLineInnerComment => self.visit_comment(node),
+            _ => {}
+        }
+    }
+
+    /// Folds the content between braces of the node, leaving the closing brace visible.
+    fn push_braced(&mut self, node: SyntaxNode<'db>) {
+        let db = self.db;
+        let children = node.get_children(db);
+        let lbrace = children.iter().find(|child| child.kind(db) == SyntaxKind::TerminalLBrace);
+        let rbrace = children.iter().find(|child| child.kind(db) == SyntaxKind::TerminalRBrace);
+        let (Some(lbrace), Some(rbrace)) = (lbrace, rbrace) else {
+            return;
+        };
+
+        let (Some(start_line), Some(rbrace_line)) = (
+            self.line_of(lbrace.span_without_trivia(db).start),
+            self.line_of(rbrace.span_without_trivia(db).start),
+        ) else {
+            return;
+        };
+
+        self.push_lines(start_line, rbrace_line.saturating_sub(1), None);
+    }
+
+    /// Folds runs of consecutive `use` items in the module item list.
+    fn push_use_groups(&mut self, item_list: SyntaxNode<'db>) {
+        let db = self.db;
+        let mut group: Option<TextSpan> = None;
+
+        for item in item_list.get_children(db).iter() {
+            if item.kind(db) == SyntaxKind::ItemUse {
+                let span = item.span_without_trivia(db);
+                group = Some(match group {
+                    Some(group) => TextSpan { start: group.start, end: span.end },
+                    None => span,
+                });
+            } else if let Some(group) = group.take() {
+                self.push(group, Some(FoldingRangeKind::Imports));
+            }
+        }
+
+        if let Some(group) = group {
+            self.push(group, Some(FoldingRangeKind::Imports));
+        }
+    }
+
+    fn visit_comment(&mut self, comment: SyntaxNode<'db>) {
+        let start = comment.span(self.db).start;
+        // A comment following code on the same line belongs to that code.
+        if !self.starts_line(start) {
+            return;
+        }
+        let Some(line) = self.line_of(start) else {
+            return;
+        };
+
+        match self.comments {
+            Some((start, end)) if end + 1 == line => self.comments = Some((start, line)),
+            _ => {
+                self.flush_comments();
+                self.comments = Some((line, line));
+            }
+        }
+    }
+
+    fn flush_comments(&mut self) {
+        if let Some((start, end)) = self.comments.take() {
+            self.push_lines(start, end, Some(FoldingRangeKind::Comment));
+        }
+    }
+
+    fn push(&mut self, span: TextSpan, kind: Option<FoldingRangeKind>) {
+        if let Some(range) = span.position_in_file(self.db, self.file).map(|span| span.to_lsp()) {
+            self.push_lines(range.start.line, range.end.line, kind);
+        }
+    }
+
+    fn push_lines(&mut self, start_line: u32, end_line: u32, kind: Option<FoldingRangeKind>) {
+        // There is nothing to fold in a single line.
+        if start_line >= end_line {
+            return;
+        }
+
+        self.ranges.push(FoldingRange {
+            start_line,
+            start_character: None,
+            end_line,
+            end_character: None,
+            kind,
+            collapsed_text: None,
+        });
+    }
+
+    /// Checks if only whitespace precedes the offset on its line.
+    fn starts_line(&self, offset: TextOffset) -> bool {
+        let Some(content) = self.db.file_content(self.file) else {
+            return false;
+        };
+        content
+            .get(..offset.as_u32() as usize)
+            .and_then(|before| before.rsplit('\n').next())
+            .is_some_and(|line| line.trim().is_empty())
+    }
+
+    fn line_of(&self, offset: TextOffset) -> Option<u32> {
+        offset.position_in_file(self.db, self.file).map(|position| position.to_lsp().line)
+    }
+}
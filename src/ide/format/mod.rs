@@ -1,3 +1,4 @@
 pub mod documents;
+pub mod ranges;
 pub mod traits;
 pub mod types;
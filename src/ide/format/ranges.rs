@@ -0,0 +1,194 @@
+use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan, TextWidth};
+use cairo_lang_formatter::{FormatterConfig, get_formatted_file};
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_syntax::node::SyntaxNode;
+use cairo_lang_syntax::node::ast::{ImplItem, ModuleItem, Statement, TraitItem};
+use lsp_types::{DocumentOnTypeFormattingParams, DocumentRangeFormattingParams, TextEdit, Url};
+use tracing::error;
+
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::lsp::{LsProtoGroup, ToCairo, ToLsp};
+use crate::state::StateSnapshot;
+
+/// Format items and statements covering a range of a document.
+pub fn format_range(
+    params: DocumentRangeFormattingParams,
+    state: StateSnapshot,
+) -> Option<Vec<TextEdit>> {
+    let db = &state.db;
+    let file_uri = params.text_document.uri;
+    let file = db.file_for_url(&file_uri)?;
+    let range = params.range.to_cairo();
+    let span =
+        TextSpan::new(range.start.offset_in_file(db, file)?, range.end.offset_in_file(db, file)?);
+
+    format_span(db, file, span, formatter_config(&state, &file_uri))
+}
+
+/// Format the item or statement ended by the character which was just typed.
+pub fn format_on_type(
+    params: DocumentOnTypeFormattingParams,
+    state: StateSnapshot,
+) -> Option<Vec<TextEdit>> {
+    let db = &state.db;
+    let file_uri = params.text_document_position.text_document.uri;
+    let file = db.file_for_url(&file_uri)?;
+    let offset = params.text_document_position.position.to_cairo().offset_in_file(db, file)?;
+
+    // Skip the whitespace just typed, so a newline formats the line it ended.
+    let content = db.file_content(file)?;
+    let typed = TextSpan::new(TextOffset::START, offset).take(content).trim_end();
+    let span = TextSpan::cursor(TextOffset::START.add_width(TextWidth::from_str(typed)));
+
+    format_span(db, file, span, formatter_config(&state, &file_uri))
+}
+
+fn formatter_config(state: &StateSnapshot, file_uri: &Url) -> FormatterConfig {
+    file_uri
+        .to_file_path()
+        .ok()
+        .and_then(|path| state.configs_registry.config_for_file(&path))
+        .unwrap_or_default()
+        .fmt
+}
+
+fn format_span<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    span: TextSpan,
+    config: FormatterConfig,
+) -> Option<Vec<TextEdit>> {
+    let Ok(root) = db.file_syntax(file) else {
+        error!("formatting failed: cannot parse file");
+        return None;
+    };
+    let content = db.file_content(file)?;
+
+    // Parts of the file which do not parse are left untouched, as the formatter could break them.
+    let errors: Vec<_> = db
+        .file_syntax_diagnostics(file)
+        .get_all()
+        .into_iter()
+        .map(|diagnostic| diagnostic.location(db).span)
+        .collect();
+
+    let edits = covering_nodes(db, root, span)
+        .into_iter()
+        .filter(|node| {
+            let node_span = node.span(db);
+            !errors.iter().any(|error| intersects(node_span, *error))
+        })
+        .filter_map(|node| {
+            // The full span of a node placed on separate lines consists of whole lines.
+            let node_span = node.span(db);
+            let old_text = node_span.take(content);
+            let new_text = reindent(
+                &get_formatted_file(db, &node, config.clone()),
+                line_indent(content, node.span_without_trivia(db).start),
+                starts_line(content, node_span.start),
+                old_text,
+            );
+
+            (new_text != old_text).then(|| {
+                Some(TextEdit { range: node_span.position_in_file(db, file)?.to_lsp(), new_text })
+            })?
+        })
+        .collect();
+
+    Some(edits)
+}
+
+/// Finds the smallest items or statements which together cover the span.
+fn covering_nodes<'db>(
+    db: &'db AnalysisDatabase,
+    node: SyntaxNode<'db>,
+    span: TextSpan,
+) -> Vec<SyntaxNode<'db>> {
+    let units = intersecting_units(db, node, span);
+
+    // Narrow down to the inner items or statements if the span lies within a single one.
+    if let [unit] = units[..] {
+        let unit_span = unit.span_without_trivia(db);
+        if !(span.start <= unit_span.start && unit_span.end <= span.end) {
+            let inner = covering_nodes(db, unit, span);
+            if !inner.is_empty() {
+                return inner;
+            }
+        }
+    }
+
+    units
+}
+
+/// Finds the outermost items and statements within the node which intersect the span.
+fn intersecting_units<'db>(
+    db: &'db AnalysisDatabase,
+    node: SyntaxNode<'db>,
+    span: TextSpan,
+) -> Vec<SyntaxNode<'db>> {
+    let mut units = vec![];
+    for &child in node.get_children(db).iter() {
+        if !intersects(child.span_without_trivia(db), span) {
+            continue;
+        }
+
+        let kind = child.kind(db);
+        if ModuleItem::is_variant(kind)
+            || TraitItem::is_variant(kind)
+            || ImplItem::is_variant(kind)
+            || Statement::is_variant(kind)
+        {
+            units.push(child);
+        } else {
+            units.extend(intersecting_units(db, child, span));
+        }
+    }
+    units
+}
+
+/// Checks if spans overlap, counting spans which only touch each other as overlapping.
+fn intersects(a: TextSpan, b: TextSpan) -> bool {
+    a.start <= b.end && b.start <= a.end
+}
+
+/// Gets the indentation of the line containing the offset.
+fn line_indent(content: &str, offset: TextOffset) -> &str {
+    let before = TextSpan::new(TextOffset::START, offset).take(content);
+    let line = &before[before.rfind('\n').map_or(0, |newline| newline + 1)..];
+    &line[..line.len() - line.trim_start().len()]
+}
+
+fn starts_line(content: &str, offset: TextOffset) -> bool {
+    let before = TextSpan::new(TextOffset::START, offset).take(content);
+    before.is_empty() || before.ends_with('\n')
+}
+
+/// Indents the node formatted at the top level to the original position of the node,
+/// keeping the blank lines and whitespace which separated it from the surrounding code.
+fn reindent(formatted: &str, indent: &str, starts_line: bool, old_text: &str) -> String {
+    let leading_whitespace = &old_text[..old_text.len() - old_text.trim_start().len()];
+    let mut new_text = leading_whitespace
+        [..leading_whitespace.rfind('\n').map_or(0, |newline| newline + 1)]
+        .to_string();
+
+    for (index, line) in formatted.trim().lines().enumerate() {
+        if index > 0 {
+            new_text.push('\n');
+        }
+        if !line.is_empty() && (index > 0 || starts_line) {
+            new_text.push_str(indent);
+        }
+        new_text.push_str(line);
+    }
+
+    let trailing_whitespace = &old_text[old_text.trim_end().len()..];
+    if trailing_whitespace.contains('\n') {
+        new_text.push('\n');
+    } else {
+        new_text.push_str(trailing_whitespace);
+    }
+    new_text
+}
@@ -1,17 +1,10 @@
 use cairo_lang_filesystem::ids::FileId;
-use cairo_lang_semantic::Expr;
-use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::items::function_with_body::{
-    FunctionWithBodySemantic, SemanticExprLookup,
-};
-use cairo_lang_semantic::items::functions::FunctionsSemantic;
-use cairo_lang_semantic::lookup_item::LookupItemEx;
-use cairo_lang_syntax::node::ast::{self, ArgClause, BinaryOperator, ExprBinary, ExprFunctionCall};
+use cairo_lang_syntax::node::ast::{self, ArgClause, ExprFunctionCall};
 use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
-use cairo_language_common::CommonGroup;
 use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel};
 
-use crate::lang::db::{AnalysisDatabase, LsSemanticGroup};
+use crate::lang::calls::resolve_call_signature;
+use crate::lang::db::AnalysisDatabase;
 use crate::lang::lsp::ToLsp;
 use crate::lang::proc_macros::db::get_og_node;
 
@@ -35,46 +28,6 @@ pub fn param_inlay_hints<'db>(
         .collect()
 }
 
-/// Resolves a syntax-level function call to its semantic [`Signature`].
-fn resolve_call_signature<'db>(
-    db: &'db AnalysisDatabase,
-    call_syntax: &ExprFunctionCall<'db>,
-) -> Option<cairo_lang_semantic::Signature<'db>> {
-    let call_node = call_syntax.as_syntax_node();
-
-    let is_method_call = call_node
-        .parent(db)
-        .and_then(|parent| ExprBinary::cast(db, parent))
-        .is_some_and(|binary| matches!(binary.op(db), BinaryOperator::Dot(_)));
-
-    let semantic_db: &dyn SemanticGroup = db;
-
-    db.get_node_resultants(call_node)?
-        .iter()
-        .find_map(|resultant| {
-            let resultant_call = ExprFunctionCall::cast(db, *resultant)?;
-            let lookup_item = db.find_lookup_item(resultant_call.as_syntax_node())?;
-            let function_with_body = lookup_item.function_with_body()?;
-
-            let stable_ptr = if is_method_call {
-                let parent = resultant_call.as_syntax_node().parent(db)?;
-                ExprBinary::cast(db, parent)?.stable_ptr(db).into()
-            } else {
-                resultant_call.stable_ptr(db).into()
-            };
-
-            let expr_id = db.lookup_expr_by_ptr(function_with_body, stable_ptr).ok()?;
-            let Expr::FunctionCall(func_call) =
-                semantic_db.expr_semantic(function_with_body, expr_id)
-            else {
-                return None;
-            };
-
-            db.concrete_function_signature(func_call.function).ok()
-        })
-        .cloned()
-}
-
 /// Produces a parameter-name hint for a single argument, or [`None`] if skipped.
 /// Skips named args and args with a name matching the param name (`x: x`).
 fn hint_for_arg<'db>(
@@ -3,6 +3,7 @@ pub mod code_actions;
 pub mod code_lens;
 pub mod completion;
 mod doc_links;
+pub mod folding_ranges;
 pub mod format;
 pub mod hover;
 pub mod inlay_hints;
@@ -11,4 +12,6 @@ pub mod macros;
 mod markdown;
 pub mod navigation;
 pub mod scarb_toml;
+pub mod selection_range;
 pub mod semantic_highlighting;
+pub mod signature_help;
@@ -0,0 +1,226 @@
+use cairo_lang_defs::ids::{
+    ImplItemId, LanguageElementId, LookupItemId, ModuleItemId, TopLevelLanguageElementId,
+    TraitItemId,
+};
+use cairo_lang_filesystem::db::get_originating_location;
+use cairo_lang_filesystem::ids::SpanInFile;
+use cairo_lang_semantic::Expr;
+use cairo_lang_semantic::items::function_with_body::FunctionWithBodySemantic;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_syntax::node::ast::{self, BinaryOperator, PathSegment, TerminalIdentifier};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{Terminal, TypedStablePtr, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_language_common::CommonGroup;
+use lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams, Range,
+    SymbolKind,
+};
+
+use crate::lang::calls::function_lookup_item;
+use crate::lang::db::{AnalysisDatabase, LsSemanticGroup, LsSyntaxGroup};
+use crate::lang::defs::{SymbolDef, SymbolSearch};
+use crate::lang::lsp::{LsProtoGroup, ToCairo};
+use crate::lang::usages::search_scope::SearchScope;
+
+/// Finds the function referred to at the given position, which becomes the root of the call
+/// hierarchy.
+pub fn prepare_call_hierarchy(
+    params: CallHierarchyPrepareParams,
+    db: &AnalysisDatabase,
+) -> Option<Vec<CallHierarchyItem>> {
+    let file = db.file_for_url(&params.text_document_position_params.text_document.uri)?;
+    let position = params.text_document_position_params.position.to_cairo();
+    let identifier = db.find_identifier_at_position(file, position)?;
+    let function = find_function(db, &identifier)?;
+
+    Some(vec![call_hierarchy_item(db, function)?])
+}
+
+/// Finds all functions calling the function represented by the given call hierarchy item.
+pub fn incoming_calls(
+    params: CallHierarchyIncomingCallsParams,
+    db: &AnalysisDatabase,
+) -> Option<Vec<CallHierarchyIncomingCall>> {
+    let identifier = item_identifier(db, &params.item)?;
+    let symbol = SymbolSearch::find_definition(db, &identifier)?;
+
+    let mut callers: OrderedHashMap<LookupItemId<'_>, Vec<Range>> = OrderedHashMap::default();
+    for usage in symbol.usages(db).in_scope(SearchScope::everything(db)).collect() {
+        let location = usage.location();
+        let Some(caller) = calling_function(db, location) else {
+            continue;
+        };
+        let Some(call) = db.lsp_location(get_originating_location(db, location, None)) else {
+            continue;
+        };
+        callers.entry(caller).or_default().push(call.range);
+    }
+
+    Some(
+        callers
+            .into_iter()
+            .filter_map(|(caller, from_ranges)| {
+                Some(CallHierarchyIncomingCall {
+                    from: call_hierarchy_item(db, caller)?,
+                    from_ranges,
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Finds all functions called from the body of the function represented by the given call
+/// hierarchy item.
+///
+/// Calls of trait functions are reported as calls of the implementing function if the impl could
+/// be inferred, and calls made from code generated by macros are mapped back to the macro call.
+pub fn outgoing_calls(
+    params: CallHierarchyOutgoingCallsParams,
+    db: &AnalysisDatabase,
+) -> Option<Vec<CallHierarchyOutgoingCall>> {
+    let identifier = item_identifier(db, &params.item)?;
+    let function = find_function(db, &identifier)?;
+    let body = db.function_body(function.function_with_body()?).ok()?;
+
+    let mut callees: OrderedHashMap<LookupItemId<'_>, Vec<Range>> = OrderedHashMap::default();
+    for (_id, expr) in body.arenas.exprs.iter() {
+        let Expr::FunctionCall(call) = expr else {
+            continue;
+        };
+        // Calls desugared from operators do not have a callee name written in code.
+        let Some(callee_name) = called_name(db, call.stable_ptr.lookup(db)) else {
+            continue;
+        };
+        let Some(location) = db.originating_location(callee_name.as_syntax_node()) else {
+            continue;
+        };
+
+        let from_ranges = callees.entry(function_lookup_item(db, call.function)).or_default();
+        // Macros may expand a single call written by the user multiple times.
+        if !from_ranges.contains(&location.range) {
+            from_ranges.push(location.range);
+        }
+    }
+
+    Some(
+        callees
+            .into_iter()
+            .filter_map(|(callee, from_ranges)| {
+                Some(CallHierarchyOutgoingCall {
+                    to: call_hierarchy_item(db, callee)?,
+                    from_ranges,
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Finds the function defining or referred to by the identifier.
+fn find_function<'db>(
+    db: &'db AnalysisDatabase,
+    identifier: &TerminalIdentifier<'db>,
+) -> Option<LookupItemId<'db>> {
+    let SymbolDef::Item(item) = SymbolSearch::find_definition(db, identifier)?.def else {
+        return None;
+    };
+
+    matches!(
+        item.lookup_item_id(),
+        LookupItemId::ModuleItem(ModuleItemId::FreeFunction(_) | ModuleItemId::ExternFunction(_))
+            | LookupItemId::TraitItem(TraitItemId::Function(_))
+            | LookupItemId::ImplItem(ImplItemId::Function(_))
+    )
+    .then(|| item.lookup_item_id())
+}
+
+/// Finds the name of the function a call hierarchy item was created for.
+fn item_identifier<'db>(
+    db: &'db AnalysisDatabase,
+    item: &CallHierarchyItem,
+) -> Option<TerminalIdentifier<'db>> {
+    let file = db.file_for_url(&item.uri)?;
+    db.find_identifier_at_position(file, item.selection_range.start.to_cairo())
+}
+
+/// Finds the function with a body in which the usage at the given location is called.
+fn calling_function<'db>(
+    db: &'db AnalysisDatabase,
+    usage: SpanInFile<'db>,
+) -> Option<LookupItemId<'db>> {
+    let node = db.find_syntax_node_at_offset(usage.file_id, usage.span.start)?;
+
+    // Skip usages which are not calls, like imports or passing the function around.
+    let path = node.ancestor_of_kind(db, SyntaxKind::ExprPath)?;
+    if path.parent(db)?.kind(db) != SyntaxKind::ExprFunctionCall {
+        return None;
+    }
+
+    let caller = db.find_lookup_item(path)?;
+    caller.function_with_body().map(|_| caller)
+}
+
+/// Gets the name of the function called in the given call expression, which is either
+/// `function(..)` or `receiver.method(..)`.
+fn called_name<'db>(
+    db: &'db AnalysisDatabase,
+    expr: ast::Expr<'db>,
+) -> Option<TerminalIdentifier<'db>> {
+    match expr {
+        ast::Expr::FunctionCall(call) => match call.path(db).segments(db).elements(db).last()? {
+            PathSegment::Simple(segment) => Some(segment.ident(db)),
+            PathSegment::WithGenericArgs(segment) => Some(segment.ident(db)),
+        },
+        ast::Expr::Binary(binary) if matches!(binary.op(db), BinaryOperator::Dot(_)) => {
+            called_name(db, binary.rhs(db))
+        }
+        _ => None,
+    }
+}
+
+fn call_hierarchy_item<'db>(
+    db: &'db AnalysisDatabase,
+    function: LookupItemId<'db>,
+) -> Option<CallHierarchyItem> {
+    let (kind, detail) = match function {
+        LookupItemId::ModuleItem(item) => {
+            (SymbolKind::FUNCTION, item.parent_module(db).full_path(db))
+        }
+        LookupItemId::TraitItem(item) => (SymbolKind::METHOD, item.trait_id(db).full_path(db)),
+        LookupItemId::ImplItem(item) => (SymbolKind::METHOD, item.impl_def_id(db).full_path(db)),
+    };
+
+    let name = function.name_identifier(db);
+    let selection = db.originating_location(name.as_syntax_node())?;
+
+    // The full range must be in the same file and contain the selection range.
+    let range = name
+        .as_syntax_node()
+        .ancestor_of_kinds(
+            db,
+            &[
+                SyntaxKind::FunctionWithBody,
+                SyntaxKind::TraitItemFunction,
+                SyntaxKind::ItemExternFunction,
+            ],
+        )
+        .and_then(|node| db.originating_location(node))
+        .filter(|location| {
+            location.uri == selection.uri
+                && location.range.start <= selection.range.start
+                && selection.range.end <= location.range.end
+        })
+        .map_or(selection.range, |location| location.range);
+
+    Some(CallHierarchyItem {
+        name: name.text(db).to_string(db),
+        kind,
+        tags: None,
+        detail: Some(detail),
+        uri: selection.uri,
+        range,
+        selection_range: selection.range,
+        data: None,
+    })
+}
@@ -0,0 +1,75 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{ModuleItemId, NamedLanguageElementId};
+use cairo_lang_semantic::lsp_helpers::LspHelpers;
+use cairo_lang_syntax::node::ast::{ExprPath, PathSegment, TerminalIdentifier};
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_language_common::CommonGroup;
+use lsp_types::request::{GotoDeclarationParams, GotoDeclarationResponse};
+use lsp_types::{GotoDefinitionResponse, Location};
+
+use crate::lang::db::{AnalysisDatabase, LsSyntaxGroup};
+use crate::lang::defs::{SymbolDef, SymbolSearch};
+use crate::lang::lsp::{LsProtoGroup, ToCairo};
+
+/// Get the declaration location of a symbol at a given text document position.
+///
+/// Unlike the definition, this is the `use` statement that brings the name into scope,
+/// or the trait item declaring an impl item.
+pub fn goto_declaration(
+    params: GotoDeclarationParams,
+    db: &AnalysisDatabase,
+) -> Option<GotoDeclarationResponse> {
+    let file = db.file_for_url(&params.text_document_position_params.text_document.uri)?;
+    let position = params.text_document_position_params.position.to_cairo();
+    let identifier = db.find_identifier_at_position(file, position)?;
+
+    let resultants = db.get_node_resultants(identifier.as_syntax_node())?;
+    let locations: OrderedHashSet<_> =
+        resultants.iter().filter_map(|node| declaration(db, *node)).collect();
+    let mut locations: Vec<_> = locations.into_iter().collect();
+
+    match locations.len() {
+        0 => None,
+        1 => Some(GotoDefinitionResponse::Scalar(locations.pop().unwrap())),
+        _ => Some(GotoDefinitionResponse::Array(locations)),
+    }
+}
+
+fn declaration<'db>(db: &'db AnalysisDatabase, syntax_node: SyntaxNode<'db>) -> Option<Location> {
+    let identifier =
+        syntax_node.ancestors_with_self(db).find_map(|node| TerminalIdentifier::cast(db, node))?;
+    let symbol = SymbolSearch::find_declaration(db, &identifier)?.def;
+
+    if let SymbolDef::Item(_) | SymbolDef::Module(_) = symbol
+        && let Some(use_name) = find_use_of_path_head(db, &identifier)
+    {
+        return db.originating_location(use_name);
+    }
+
+    db.lsp_location(symbol.definition_originating_location(db)?)
+}
+
+/// Finds the name introduced by a `use` statement of the module for the identifier, if it is the
+/// first segment of a path.
+fn find_use_of_path_head<'db>(
+    db: &'db AnalysisDatabase,
+    identifier: &TerminalIdentifier<'db>,
+) -> Option<SyntaxNode<'db>> {
+    let path = identifier.as_syntax_node().ancestor_of_type::<ExprPath>(db)?;
+    let head = match path.segments(db).elements(db).next()? {
+        PathSegment::Simple(segment) => segment.ident(db),
+        PathSegment::WithGenericArgs(segment) => segment.ident(db),
+    };
+    if head.as_syntax_node() != identifier.as_syntax_node() {
+        return None;
+    }
+
+    let name = identifier.text(db);
+    let module_id = db.find_module_containing_node(identifier.as_syntax_node())?;
+    db.module_uses_ids(module_id)
+        .ok()?
+        .iter()
+        .find(|use_id| use_id.name(db) == name)
+        .map(|&use_id| ModuleItemId::Use(use_id).name_identifier(db).as_syntax_node())
+}
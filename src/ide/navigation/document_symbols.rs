@@ -0,0 +1,244 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId, ModuleItemId, NamedLanguageElementId};
+use cairo_lang_filesystem::db::get_originating_location;
+use cairo_lang_filesystem::ids::{FileId, SpanInFile};
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_syntax::node::ast::{
+    ImplItem, MaybeImplBody, MaybeTraitBody, TerminalIdentifier, TraitItem,
+};
+use cairo_lang_syntax::node::{SyntaxNode, TypedStablePtr, TypedSyntaxNode};
+use itertools::Itertools;
+use lsp_types::{DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse, SymbolKind};
+
+use crate::lang::db::{AnalysisDatabase, LsSyntaxGroup};
+use crate::lang::lsp::{LsProtoGroup, ToLsp};
+
+/// Builds the hierarchical outline of items defined in a given text document.
+pub fn document_symbols(
+    params: DocumentSymbolParams,
+    db: &AnalysisDatabase,
+) -> Option<DocumentSymbolResponse> {
+    let file = db.file_for_url(&params.text_document.uri)?;
+    let main_module = *db.file_modules(file).ok()?.first()?;
+
+    Some(DocumentSymbolResponse::Nested(module_symbols(db, file, main_module)))
+}
+
+fn module_symbols<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    module: ModuleId<'db>,
+) -> Vec<DocumentSymbol> {
+    let items = module.module_data(db).map(|data| data.items(db).to_vec()).unwrap_or_default();
+
+    let mut symbols =
+        items.into_iter().filter_map(|item| item_symbol(db, file, item)).collect_vec();
+
+    // Items produced by user-defined inline macros live in `MacroCall` modules,
+    // show them as if they were defined directly in this module.
+    for macro_module in db
+        .module_macro_calls_ids(module)
+        .map(|calls| calls.iter().filter_map(|&call| db.macro_call_module_id(call).ok()).collect())
+        .unwrap_or_default()
+    {
+        symbols.extend(module_symbols(db, file, macro_module));
+    }
+
+    sort_symbols(symbols)
+}
+
+fn item_symbol<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    item: ModuleItemId<'db>,
+) -> Option<DocumentSymbol> {
+    let (kind, children) = match item {
+        ModuleItemId::Submodule(id) => {
+            let children = if db.is_submodule_inline(id) {
+                module_symbols(db, file, ModuleId::Submodule(id))
+            } else {
+                vec![]
+            };
+            (SymbolKind::MODULE, children)
+        }
+        ModuleItemId::Struct(id) => {
+            let members = id
+                .stable_ptr(db)
+                .lookup(db)
+                .members(db)
+                .elements(db)
+                .filter_map(|member| {
+                    leaf_symbol(
+                        db,
+                        file,
+                        member.as_syntax_node(),
+                        member.name(db),
+                        SymbolKind::FIELD,
+                    )
+                })
+                .collect();
+            (SymbolKind::STRUCT, members)
+        }
+        ModuleItemId::Enum(id) => {
+            let variants = id
+                .stable_ptr(db)
+                .lookup(db)
+                .variants(db)
+                .elements(db)
+                .filter_map(|variant| {
+                    leaf_symbol(
+                        db,
+                        file,
+                        variant.as_syntax_node(),
+                        variant.name(db),
+                        SymbolKind::ENUM_MEMBER,
+                    )
+                })
+                .collect();
+            (SymbolKind::ENUM, variants)
+        }
+        ModuleItemId::Trait(id) => {
+            let items = match id.stable_ptr(db).lookup(db).body(db) {
+                MaybeTraitBody::Some(body) => body
+                    .items(db)
+                    .elements(db)
+                    .filter_map(|item| trait_item_symbol(db, file, item))
+                    .collect(),
+                MaybeTraitBody::None(_) => vec![],
+            };
+            (SymbolKind::INTERFACE, items)
+        }
+        ModuleItemId::Impl(id) => {
+            let items = match id.stable_ptr(db).lookup(db).body(db) {
+                MaybeImplBody::Some(body) => body
+                    .items(db)
+                    .elements(db)
+                    .filter_map(|item| impl_item_symbol(db, file, item))
+                    .collect(),
+                MaybeImplBody::None(_) => vec![],
+            };
+            (SymbolKind::OBJECT, items)
+        }
+        ModuleItemId::Constant(_) => (SymbolKind::CONSTANT, vec![]),
+        ModuleItemId::TypeAlias(_) => (SymbolKind::TYPE_PARAMETER, vec![]),
+        ModuleItemId::FreeFunction(_) | ModuleItemId::ExternFunction(_) => {
+            (SymbolKind::FUNCTION, vec![])
+        }
+        ModuleItemId::ExternType(_) => (SymbolKind::STRUCT, vec![]),
+        ModuleItemId::Use(_) | ModuleItemId::ImplAlias(_) | ModuleItemId::MacroDeclaration(_) => {
+            return None;
+        }
+    };
+
+    let mut symbol = leaf_symbol(
+        db,
+        file,
+        item.untyped_stable_ptr(db).lookup(db),
+        item.name_identifier(db),
+        kind,
+    )?;
+    symbol.children = Some(sort_symbols(children));
+
+    Some(symbol)
+}
+
+fn trait_item_symbol<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    item: TraitItem<'db>,
+) -> Option<DocumentSymbol> {
+    let (name, kind) = match &item {
+        TraitItem::Function(function) => (function.declaration(db).name(db), SymbolKind::METHOD),
+        TraitItem::Type(ty) => (ty.name(db), SymbolKind::TYPE_PARAMETER),
+        TraitItem::Constant(constant) => (constant.name(db), SymbolKind::CONSTANT),
+        TraitItem::Impl(imp) => (imp.name(db), SymbolKind::OBJECT),
+        TraitItem::Missing(_) => return None,
+    };
+
+    leaf_symbol(db, file, item.as_syntax_node(), name, kind)
+}
+
+fn impl_item_symbol<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    item: ImplItem<'db>,
+) -> Option<DocumentSymbol> {
+    let (name, kind) = match &item {
+        ImplItem::Function(function) => (function.declaration(db).name(db), SymbolKind::METHOD),
+        ImplItem::Type(ty) => (ty.name(db), SymbolKind::TYPE_PARAMETER),
+        ImplItem::Constant(constant) => (constant.name(db), SymbolKind::CONSTANT),
+        ImplItem::Impl(imp) => (imp.name(db), SymbolKind::OBJECT),
+        // Other items are not allowed in impls.
+        _ => return None,
+    };
+
+    leaf_symbol(db, file, item.as_syntax_node(), name, kind)
+}
+
+/// Creates a symbol without children for the item syntax node with the given name.
+///
+/// Both the item and its name may come from code generated by macros, in which case they are
+/// mapped back to the user code. Items which do not originate from a name written by the user in
+/// `file` are skipped, as they are an implementation detail of a macro.
+#[expect(deprecated)]
+fn leaf_symbol<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    item: SyntaxNode<'db>,
+    name: TerminalIdentifier<'db>,
+    kind: SymbolKind,
+) -> Option<DocumentSymbol> {
+    let name_span = originating_span(db, file, name.as_syntax_node())?;
+
+    let original_name = db
+        .find_syntax_node_at_offset(file, name_span.start)?
+        .ancestors_with_self(db)
+        .find_map(|node| TerminalIdentifier::cast(db, node))?;
+    if original_name.text(db) != name.text(db) {
+        return None;
+    }
+
+    // The selection range must be contained in the full range of a symbol.
+    let span = originating_span(db, file, item)
+        .filter(|span| span.start <= name_span.start && name_span.end <= span.end)
+        .unwrap_or(name_span);
+
+    Some(DocumentSymbol {
+        name: name.text(db).to_string(db),
+        detail: None,
+        kind,
+        tags: None,
+        deprecated: None,
+        range: span.position_in_file(db, file)?.to_lsp(),
+        selection_range: name_span.position_in_file(db, file)?.to_lsp(),
+        children: None,
+    })
+}
+
+/// Finds the span of user code in `file` the node originates from.
+fn originating_span<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    node: SyntaxNode<'db>,
+) -> Option<TextSpan> {
+    let SpanInFile { file_id, span } = get_originating_location(
+        db,
+        SpanInFile { file_id: node.stable_ptr(db).file_id(db), span: node.span_without_trivia(db) },
+        None,
+    );
+
+    (file_id == file).then_some(span)
+}
+
+/// Orders symbols as they appear in the document, dropping duplicates
+/// of items generated multiple times from the same user code.
+fn sort_symbols(symbols: Vec<DocumentSymbol>) -> Vec<DocumentSymbol> {
+    symbols
+        .into_iter()
+        .unique_by(|symbol| {
+            let start = symbol.selection_range.start;
+            (symbol.name.clone(), start.line, start.character)
+        })
+        .sorted_by_key(|symbol| (symbol.range.start.line, symbol.range.start.character))
+        .collect()
+}
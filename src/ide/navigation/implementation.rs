@@ -0,0 +1,120 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{
+    ImplDefId, ImplItemId, LookupItemId, ModuleItemId, NamedLanguageElementId, TraitId, TraitItemId,
+};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_semantic::items::imp::ImplSemantic;
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_syntax::node::ast::{ExprFunctionCall, TerminalIdentifier};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use itertools::Itertools;
+use lsp_types::request::{GotoImplementationParams, GotoImplementationResponse};
+use lsp_types::{GotoDefinitionResponse, Location};
+
+use crate::lang::calls::{function_lookup_item, resolve_call_function};
+use crate::lang::db::{AnalysisDatabase, LsSemanticGroup, LsSyntaxGroup};
+use crate::lang::defs::{SymbolDef, SymbolSearch};
+use crate::lang::lsp::{LsProtoGroup, ToCairo};
+
+/// Get the implementations of a trait, a trait function or a called method at a given text
+/// document position.
+pub fn goto_implementation(
+    params: GotoImplementationParams,
+    db: &AnalysisDatabase,
+) -> Option<GotoImplementationResponse> {
+    let file = db.file_for_url(&params.text_document_position_params.text_document.uri)?;
+    let position = params.text_document_position_params.position.to_cairo();
+    let identifier = db.find_identifier_at_position(file, position)?;
+
+    let implementations = match called_function(db, &identifier) {
+        // Inference selected the impl, so there is exactly one implementation to go to.
+        Some(function @ LookupItemId::ImplItem(_)) => vec![function],
+        _ => db
+            .get_node_resultants(identifier.as_syntax_node())?
+            .iter()
+            .filter_map(|node| {
+                node.ancestors_with_self(db).find_map(|node| TerminalIdentifier::cast(db, node))
+            })
+            .filter_map(|identifier| SymbolSearch::find_definition(db, &identifier))
+            .flat_map(|symbol| match symbol.def {
+                SymbolDef::Item(item) => implementations(db, item.lookup_item_id()),
+                _ => vec![],
+            })
+            .collect(),
+    };
+
+    let mut locations: Vec<_> = implementations
+        .into_iter()
+        .filter_map(|item| item_location(db, item))
+        .collect::<OrderedHashSet<_>>()
+        .into_iter()
+        .collect();
+
+    match locations.len() {
+        0 => None,
+        1 => Some(GotoDefinitionResponse::Scalar(locations.pop().unwrap())),
+        _ => Some(GotoDefinitionResponse::Array(locations)),
+    }
+}
+
+/// Resolves the function called with the identifier as its name, e.g. `method` in `x.method()`.
+fn called_function<'db>(
+    db: &'db AnalysisDatabase,
+    identifier: &TerminalIdentifier<'db>,
+) -> Option<LookupItemId<'db>> {
+    let path = identifier.as_syntax_node().ancestor_of_kind(db, SyntaxKind::ExprPath)?;
+    let call = ExprFunctionCall::cast(db, path.parent(db)?)?;
+    Some(function_lookup_item(db, resolve_call_function(db, &call)?))
+}
+
+/// Finds items implementing the given item.
+fn implementations<'db>(
+    db: &'db AnalysisDatabase,
+    item: LookupItemId<'db>,
+) -> Vec<LookupItemId<'db>> {
+    match item {
+        LookupItemId::ModuleItem(ModuleItemId::Trait(trait_id)) => trait_impls(db, trait_id)
+            .into_iter()
+            .map(|impl_def_id| LookupItemId::ModuleItem(ModuleItemId::Impl(impl_def_id)))
+            .collect(),
+        LookupItemId::TraitItem(TraitItemId::Function(trait_function)) => {
+            let name = trait_function.name(db);
+            trait_impls(db, trait_function.trait_id(db))
+                .into_iter()
+                .filter_map(|impl_def_id| {
+                    db.impl_functions(impl_def_id)
+                        .ok()?
+                        .values()
+                        .find(|impl_function| impl_function.name(db) == name)
+                        .copied()
+                })
+                .map(|impl_function| LookupItemId::ImplItem(ImplItemId::Function(impl_function)))
+                .collect()
+        }
+        // Usages of impl items are resolved to the impls by inference already.
+        LookupItemId::ImplItem(ImplItemId::Function(_)) => vec![item],
+        _ => vec![],
+    }
+}
+
+/// Finds impls of the trait in all crates loaded into the database.
+fn trait_impls<'db>(db: &'db AnalysisDatabase, trait_id: TraitId<'db>) -> Vec<ImplDefId<'db>> {
+    db.crates()
+        .iter()
+        .flat_map(|&crate_id| db.crate_modules(crate_id).iter().copied().collect_vec())
+        .flat_map(|module| {
+            module.module_data(db).map(|data| data.items(db).to_vec()).unwrap_or_default()
+        })
+        .filter_map(|item| match item {
+            ModuleItemId::Impl(impl_def_id) => Some(impl_def_id),
+            _ => None,
+        })
+        .filter(|&impl_def_id| db.impl_def_trait(impl_def_id).is_ok_and(|id| id == trait_id))
+        .collect()
+}
+
+/// Gets the location of the item name in user code.
+fn item_location<'db>(db: &'db AnalysisDatabase, item: LookupItemId<'db>) -> Option<Location> {
+    db.originating_location(item.name_identifier(db).as_syntax_node())
+}
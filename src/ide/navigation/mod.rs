@@ -1,4 +1,11 @@
+pub mod call_hierarchy;
+pub mod declaration;
+pub mod document_symbols;
 pub mod goto_definition;
 pub mod highlight;
+pub mod implementation;
 pub mod references;
 pub mod rename;
+pub mod type_definition;
+pub mod type_hierarchy;
+pub mod workspace_symbols;
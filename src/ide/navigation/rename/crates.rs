@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use cairo_lang_filesystem::db::{CrateConfiguration, FilesGroup};
+use cairo_lang_filesystem::ids::{CrateId, CrateLongId, Directory, FileId};
+use itertools::chain;
+use lsp_server::ErrorCode;
+use lsp_types::{TextDocumentPositionParams, TextEdit, Url};
+use toml_edit::Document;
+
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::lsp::{LsProtoGroup, ToCairo, Utf8Span};
+use crate::lsp::result::{LSPError, LSPResult};
+use crate::project::ConfigsRegistry;
+use crate::toolchain::scarb::ScarbToolchain;
+
+const MANIFEST_FILE_NAME: &str = "Scarb.toml";
+
+/// Tables of a manifest whose keys are names of dependencies.
+const DEPENDENCY_TABLES: [&[&str]; 3] =
+    [&["dependencies"], &["dev-dependencies"], &["workspace", "dependencies"]];
+
+/// Finds the crate of the package whose name is at the given position in `Scarb.toml`.
+///
+/// Returns the crate along with the range of the package name.
+pub fn crate_at_manifest_position<'db>(
+    params: &TextDocumentPositionParams,
+    db: &'db AnalysisDatabase,
+) -> LSPResult<Option<(CrateId<'db>, lsp_types::Range)>> {
+    let Some(file_id) = db.file_for_url(&params.text_document.uri) else {
+        return Ok(None);
+    };
+    let Some(content) = db.file_content(file_id) else {
+        return Ok(None);
+    };
+    let Some(offset) = params.position.to_cairo().offset_in_file(db, file_id) else {
+        return Ok(None);
+    };
+    let offset = offset.as_u32() as usize;
+
+    let Some((name, span)) = package_name(content) else {
+        return Ok(None);
+    };
+    // Include the closing quote, so that a cursor placed right after the name still matches.
+    if !(span.start..=span.end).contains(&offset) {
+        return Err(LSPError::new(
+            anyhow!("Only the package name can be renamed in {MANIFEST_FILE_NAME}"),
+            ErrorCode::RequestFailed,
+        ));
+    }
+
+    let Ok(manifest_path) = params.text_document.uri.to_file_path() else {
+        return Ok(None);
+    };
+    let manifest_dir = manifest_path.parent().unwrap_or(&manifest_path);
+    let crate_id = db
+        .crates()
+        .iter()
+        .copied()
+        .find(|&crate_id| {
+            crate_name(db, crate_id).as_deref() == Some(name.as_str())
+                && crate_root(db, crate_id).is_some_and(|root| root.starts_with(manifest_dir))
+        })
+        .ok_or_else(|| {
+            LSPError::new(
+                anyhow!("Package `{name}` is not loaded by the language server"),
+                ErrorCode::RequestFailed,
+            )
+        })?;
+
+    let Some(range) = Utf8Span::new(span.start, span.end).to_lsp_range(db, file_id) else {
+        return Ok(None);
+    };
+
+    Ok(Some((crate_id, range)))
+}
+
+/// Collects edits renaming the package of the crate in its manifest
+/// and the dependency entries referring to it in manifests of other packages in the workspace.
+pub fn manifest_edits<'db>(
+    db: &'db AnalysisDatabase,
+    crate_id: CrateId<'db>,
+    new_name: &str,
+    configs_registry: &ConfigsRegistry,
+    scarb_toolchain: &ScarbToolchain,
+) -> LSPResult<HashMap<Url, Vec<TextEdit>>> {
+    let manifest_path = package_manifest_path(db, crate_id, configs_registry)?;
+    let old_name = crate_name(db, crate_id).unwrap_or_default();
+
+    let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+
+    let ((manifest_url, manifest_file_id), content) = manifest_file(db, &manifest_path)
+        .ok_or_else(|| {
+            LSPError::new(
+                anyhow!("Could not read {}", manifest_path.display()),
+                ErrorCode::RequestFailed,
+            )
+        })?;
+    match package_name(content) {
+        Some((package_name, span)) if package_name == old_name => {
+            changes.entry(manifest_url).or_default().extend(text_edit(
+                db,
+                manifest_file_id,
+                span,
+                new_name,
+            ));
+        }
+        _ => {
+            return Err(LSPError::new(
+                anyhow!(
+                    "Crate `{old_name}` cannot be renamed because its name differs from the name \
+                     of its package"
+                ),
+                ErrorCode::RequestFailed,
+            ));
+        }
+    }
+
+    // Dependencies may be declared by other members and in the workspace root manifest,
+    // which is not a package itself.
+    let workspace_manifests = manifest_path
+        .ancestors()
+        .skip(2)
+        .map(|dir| dir.join(MANIFEST_FILE_NAME))
+        .filter(|path| path.exists());
+    let package_manifests = configs_registry
+        .manifest_paths()
+        .filter(|path| !scarb_toolchain.is_from_scarb_cache(path))
+        .map(Path::to_path_buf);
+
+    let mut manifests: Vec<_> = chain!(workspace_manifests, package_manifests).collect();
+    manifests.sort();
+    manifests.dedup();
+
+    for path in manifests {
+        let Some(((url, file_id), content)) = manifest_file(db, &path) else {
+            continue;
+        };
+        let edits = dependency_key_spans(content, &old_name)
+            .into_iter()
+            .filter_map(|span| text_edit(db, file_id, span, new_name));
+        changes.entry(url).or_default().extend(edits);
+    }
+
+    changes.retain(|_, edits| !edits.is_empty());
+    Ok(changes)
+}
+
+/// Finds the manifest of the package defining the crate.
+pub fn package_manifest_path<'db>(
+    db: &'db AnalysisDatabase,
+    crate_id: CrateId<'db>,
+    configs_registry: &ConfigsRegistry,
+) -> LSPResult<PathBuf> {
+    crate_root(db, crate_id)
+        .and_then(|root| configs_registry.manifest_dir_for_file(&root))
+        .map(|dir| dir.join(MANIFEST_FILE_NAME))
+        .filter(|path| path.exists())
+        .ok_or_else(|| {
+            LSPError::new(
+                anyhow!("Renaming crates is only supported for Scarb packages"),
+                ErrorCode::RequestFailed,
+            )
+        })
+}
+
+fn crate_name<'db>(db: &'db AnalysisDatabase, crate_id: CrateId<'db>) -> Option<String> {
+    let CrateLongId::Real { name, .. } = crate_id.long(db) else {
+        return None;
+    };
+    Some(name.to_string(db))
+}
+
+fn crate_root<'db>(db: &'db AnalysisDatabase, crate_id: CrateId<'db>) -> Option<PathBuf> {
+    let CrateConfiguration { root: Directory::Real(root), .. } = db.crate_config(crate_id)? else {
+        return None;
+    };
+    Some(root.clone())
+}
+
+fn manifest_file<'db>(
+    db: &'db AnalysisDatabase,
+    path: &Path,
+) -> Option<((Url, FileId<'db>), &'db str)> {
+    let url = Url::from_file_path(path).ok()?;
+    let file_id = db.file_for_url(&url)?;
+    let content = db.file_content(file_id)?;
+    Some(((url, file_id), content))
+}
+
+/// Finds the package name in the manifest along with the span of its value, without quotes.
+fn package_name(raw_toml: &str) -> Option<(String, Range<usize>)> {
+    let doc = Document::parse(raw_toml).ok()?;
+    let name = doc.as_item().get("package")?.get("name")?;
+    let span = name.span()?;
+    Some((name.as_str()?.to_string(), span.start + 1..span.end - 1))
+}
+
+/// Finds spans of keys naming the dependency in all dependency tables of the manifest.
+fn dependency_key_spans(raw_toml: &str, dependency: &str) -> Vec<Range<usize>> {
+    let Ok(doc) = Document::parse(raw_toml) else {
+        return vec![];
+    };
+
+    DEPENDENCY_TABLES
+        .iter()
+        .filter_map(|path| {
+            let table = path.iter().try_fold(doc.as_item(), |item, key| item.get(key))?;
+            let (key, _) = table.as_table_like()?.get_key_value(dependency)?;
+            key.span()
+        })
+        .collect()
+}
+
+fn text_edit<'db>(
+    db: &'db AnalysisDatabase,
+    file_id: FileId<'db>,
+    span: Range<usize>,
+    new_text: &str,
+) -> Option<TextEdit> {
+    let range = Utf8Span::new(span.start, span.end).to_lsp_range(db, file_id)?;
+    Some(TextEdit { range, new_text: new_text.to_string() })
+}
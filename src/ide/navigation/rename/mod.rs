@@ -0,0 +1,391 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_filesystem::ids::{CrateId, FileLongId, SpanInFile};
+use cairo_lang_semantic::keyword::SELF_TYPE_KW;
+use cairo_lang_syntax::node::ast::TerminalIdentifier;
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedSyntaxNode};
+use cairo_language_common::CommonGroup;
+use itertools::Itertools;
+use lsp_server::ErrorCode;
+use lsp_types::{
+    ClientCapabilities, DocumentChangeOperation, DocumentChanges, Location, OneOf,
+    OptionalVersionedTextDocumentIdentifier, PrepareRenameResponse, RenameFile, RenameParams,
+    ResourceOp, TextDocumentEdit, TextDocumentPositionParams, TextEdit, Url, WorkspaceEdit,
+};
+
+use crate::lang::db::{AnalysisDatabase, LsSyntaxGroup};
+use crate::lang::defs::{NonMacroModuleId, SymbolDef, SymbolSearch};
+use crate::lang::identifiers::is_identifier;
+use crate::lang::lsp::{LsProtoGroup, ToCairo};
+use crate::lsp::capabilities::client::ClientCapabilitiesExt;
+use crate::lsp::result::{LSPError, LSPResult};
+use crate::project::ConfigsRegistry;
+use crate::server::is_scarb_manifest;
+use crate::toolchain::scarb::ScarbToolchain;
+
+mod crates;
+
+/// Checks whether the symbol at the given position can be renamed.
+///
+/// Returns the range of the identifier along with its current name to be used as a placeholder.
+pub fn prepare_rename(
+    params: TextDocumentPositionParams,
+    db: &AnalysisDatabase,
+    configs_registry: &ConfigsRegistry,
+    scarb_toolchain: &ScarbToolchain,
+) -> LSPResult<Option<PrepareRenameResponse>> {
+    if is_scarb_manifest(&params.text_document.uri) {
+        let Some((crate_id, range)) = crates::crate_at_manifest_position(&params, db)? else {
+            return Ok(None);
+        };
+        let Some(symbol) = SymbolSearch::for_crate(db, crate_id) else {
+            return Ok(None);
+        };
+        ensure_renamable(db, &symbol, configs_registry, scarb_toolchain)?;
+
+        return Ok(Some(PrepareRenameResponse::RangeWithPlaceholder {
+            range,
+            placeholder: symbol.def.name(db),
+        }));
+    }
+
+    let Some(file) = db.file_for_url(&params.text_document.uri) else {
+        return Ok(None);
+    };
+    let position = params.position.to_cairo();
+    let Some(identifier) = db.find_identifier_at_position(file, position) else {
+        return Ok(None);
+    };
+
+    let symbols =
+        renamable_symbols(db, &identifier, configs_registry, scarb_toolchain)?.unwrap_or_default();
+    if symbols.is_empty() {
+        return Err(LSPError::new(
+            anyhow!("This element cannot be renamed"),
+            ErrorCode::RequestFailed,
+        ));
+    }
+
+    let span = identifier.as_syntax_node().span_without_trivia(db);
+    let Some(Location { range, .. }) = db.lsp_location(SpanInFile { file_id: file, span }) else {
+        return Ok(None);
+    };
+
+    Ok(Some(PrepareRenameResponse::RangeWithPlaceholder {
+        range,
+        placeholder: identifier.text(db).to_string(db),
+    }))
+}
+
+pub fn rename(
+    params: RenameParams,
+    db: &AnalysisDatabase,
+    client_capabilities: &ClientCapabilities,
+    configs_registry: &ConfigsRegistry,
+    scarb_toolchain: &ScarbToolchain,
+) -> LSPResult<Option<WorkspaceEdit>> {
+    let new_name = params.new_name;
+
+    if !is_identifier(db, &new_name) {
+        return Err(LSPError::new(
+            anyhow!("`{new_name}` is not a valid identifier"),
+            ErrorCode::RequestFailed,
+        ));
+    }
+
+    let symbols = if is_scarb_manifest(&params.text_document_position.text_document.uri) {
+        let Some((crate_id, _)) =
+            crates::crate_at_manifest_position(&params.text_document_position, db)?
+        else {
+            return Ok(None);
+        };
+        let Some(symbol) = SymbolSearch::for_crate(db, crate_id) else {
+            return Ok(None);
+        };
+        ensure_renamable(db, &symbol, configs_registry, scarb_toolchain)?;
+        vec![symbol]
+    } else {
+        let Some(file) = db.file_for_url(&params.text_document_position.text_document.uri) else {
+            return Ok(None);
+        };
+        let position = params.text_document_position.position.to_cairo();
+        let Some(identifier) = db.find_identifier_at_position(file, position) else {
+            return Ok(None);
+        };
+
+        let Some(symbols) = renamable_symbols(db, &identifier, configs_registry, scarb_toolchain)?
+        else {
+            return Ok(None);
+        };
+        symbols
+    };
+
+    let mut resource_ops = vec![];
+    let mut manifest_changes: HashMap<_, Vec<TextEdit>> = HashMap::new();
+    for symbol in &symbols {
+        let SymbolDef::Module(module_def) = &symbol.def else {
+            continue;
+        };
+        match module_def.non_macro_module_id() {
+            NonMacroModuleId::CrateRoot(crate_id) => {
+                let edits = crates::manifest_edits(
+                    db,
+                    crate_id,
+                    &new_name,
+                    configs_registry,
+                    scarb_toolchain,
+                )?;
+                for (url, edits) in edits {
+                    manifest_changes.entry(url).or_default().extend(edits);
+                }
+            }
+            NonMacroModuleId::Submodule(submodule_id) => {
+                if !db.is_submodule_inline(submodule_id) {
+                    let res_op = resource_op_for_non_inline_submodule(
+                        db,
+                        module_def.module_id(),
+                        client_capabilities,
+                        &new_name,
+                    )?;
+                    resource_ops.extend(res_op);
+                }
+            }
+        }
+    }
+
+    let locations = symbols
+        .into_iter()
+        .flat_map(|symbol| find_usages(db, symbol))
+        .filter_map(|loc| db.lsp_location(loc))
+        .collect::<Vec<_>>();
+
+    let changes: HashMap<_, Vec<TextEdit>> =
+        locations.into_iter().fold(manifest_changes, |mut acc, Location { uri, range }| {
+            acc.entry(uri).or_default().push(TextEdit { range, new_text: new_name.clone() });
+            acc
+        });
+
+    let workspace_edit = if client_capabilities.workspace_edit_rename_resource_support() {
+        WorkspaceEdit {
+            changes: None,
+            document_changes: Some(merge_into_document_changes(changes, resource_ops)),
+            change_annotations: None,
+        }
+    } else {
+        WorkspaceEdit { changes: Some(changes), document_changes: None, change_annotations: None }
+    };
+
+    Ok(Some(workspace_edit))
+}
+
+/// Finds declarations of symbols referred to by the identifier,
+/// failing if any of them cannot be renamed.
+fn renamable_symbols<'db>(
+    db: &'db AnalysisDatabase,
+    identifier: &TerminalIdentifier<'db>,
+    configs_registry: &ConfigsRegistry,
+    scarb_toolchain: &ScarbToolchain,
+) -> LSPResult<Option<Vec<SymbolSearch<'db>>>> {
+    if identifier.text(db).to_string(db) == SELF_TYPE_KW {
+        return Err(LSPError::new(
+            anyhow!(
+                "Renaming via `{SELF_TYPE_KW}` reference is not supported. Rename the item directly instead."
+            ),
+            ErrorCode::RequestFailed,
+        ));
+    }
+
+    if !matches!(identifier.stable_ptr(db).file_id(db).long(db), FileLongId::OnDisk(_)) {
+        return Err(LSPError::new(
+            anyhow!("Cannot rename symbols in virtual files"),
+            ErrorCode::RequestFailed,
+        ));
+    }
+
+    let Some(resultants) = db.get_node_resultants(identifier.as_syntax_node()) else {
+        return Ok(None);
+    };
+
+    let symbols: Vec<_> =
+        resultants.iter().filter_map(|node| declaration_from_resultant(db, *node)).collect();
+
+    for symbol in &symbols {
+        ensure_renamable(db, symbol, configs_registry, scarb_toolchain)?;
+    }
+
+    Ok(Some(symbols))
+}
+
+/// Fails if the symbol is one of the special cases which cannot be renamed.
+fn ensure_renamable<'db>(
+    db: &'db AnalysisDatabase,
+    symbol: &SymbolSearch<'db>,
+    configs_registry: &ConfigsRegistry,
+    scarb_toolchain: &ScarbToolchain,
+) -> LSPResult<()> {
+    if let SymbolDef::PluginInlineMacro(_) = &symbol.def {
+        return Err(LSPError::new(
+            anyhow!("Renaming builtin inline macros is not supported"),
+            ErrorCode::RequestFailed,
+        ));
+    }
+
+    ensure_writable_definition(db, symbol, scarb_toolchain)?;
+
+    // Renaming a crate requires renaming its package as well.
+    if let SymbolDef::Module(module_def) = &symbol.def
+        && let NonMacroModuleId::CrateRoot(crate_id) = module_def.non_macro_module_id()
+    {
+        crates::package_manifest_path(db, crate_id, configs_registry)?;
+    }
+
+    Ok(())
+}
+
+/// Fails if the symbol is defined in a location the user does not control,
+/// i.e. the corelib, a dependency from the Scarb cache or generated code.
+fn ensure_writable_definition<'db>(
+    db: &'db AnalysisDatabase,
+    symbol: &SymbolSearch<'db>,
+    scarb_toolchain: &ScarbToolchain,
+) -> LSPResult<()> {
+    let Some(SpanInFile { file_id, .. }) = symbol.def.definition_originating_location(db) else {
+        return Ok(());
+    };
+    let name = symbol.def.name(db);
+
+    let FileLongId::OnDisk(path) = file_id.long(db) else {
+        return Err(LSPError::new(
+            anyhow!("Cannot rename `{name}` because it is defined in generated code"),
+            ErrorCode::RequestFailed,
+        ));
+    };
+
+    let is_corelib = db
+        .file_modules(file_id)
+        .ok()
+        .and_then(|modules| modules.first().map(|module_id| module_id.owning_crate(db)))
+        .is_some_and(|crate_id| crate_id == CrateId::core(db));
+    if is_corelib {
+        return Err(LSPError::new(
+            anyhow!("Cannot rename `{name}` because it is defined in the corelib"),
+            ErrorCode::RequestFailed,
+        ));
+    }
+
+    if scarb_toolchain.is_from_scarb_cache(path) {
+        return Err(LSPError::new(
+            anyhow!("Cannot rename `{name}` because it is defined in a dependency"),
+            ErrorCode::RequestFailed,
+        ));
+    }
+
+    Ok(())
+}
+
+fn declaration_from_resultant<'db>(
+    db: &'db AnalysisDatabase,
+    resultant: SyntaxNode<'db>,
+) -> Option<SymbolSearch<'db>> {
+    let identifier =
+        resultant.ancestors_with_self(db).find_map(|node| TerminalIdentifier::cast(db, node))?;
+    // Declaration is used here because rename without changing the declaration would break the compilation,
+    // e.g. when renaming trait usage - we also rename the trait
+    SymbolSearch::find_declaration(db, &identifier)
+}
+
+fn find_usages<'db>(db: &'db AnalysisDatabase, symbol: SymbolSearch<'db>) -> Vec<SpanInFile<'db>> {
+    let symbol_name = Some(symbol.def.name(db));
+
+    symbol
+        .usages(db)
+        .include_declaration(true)
+        .originating_locations(db)
+        .filter(|&SpanInFile { file_id, span }| {
+            db.find_syntax_node_at_offset(file_id, span.start)
+                // 1. Sanity check: `span` is a span of a terminal identifier without trivia.
+                //    It should be the same as the span of a token at offset `span.start`.
+                // 2. Filter out any symbol with a different name than the definition.
+                //    Rationale: These symbols most likely are nodes mapped back to call site.
+                //    If this is not the case, it means that the macro does something unusual and
+                //    the user should rename the remaining cases themselves.
+                .is_some_and(|node| {
+                    node.span(db) == span && node.text(db).map(|t| t.to_string(db)) == symbol_name
+                })
+        })
+        .unique()
+        .collect()
+}
+
+fn resource_op_for_non_inline_submodule<'db>(
+    db: &'db AnalysisDatabase,
+    module_id: ModuleId<'db>,
+    client_capabilities: &ClientCapabilities,
+    new_name: &str,
+) -> LSPResult<Option<ResourceOp>> {
+    if !client_capabilities.workspace_edit_rename_resource_support() {
+        return Err(LSPError::new(
+            anyhow!(
+                "Renaming this module requires renaming files \
+                 which is not supported by this editor."
+            ),
+            ErrorCode::RequestFailed,
+        ));
+    }
+
+    let file_id = db.module_main_file(module_id).map_err(|_| {
+        LSPError::new(anyhow!("Module main file not found"), ErrorCode::RequestFailed)
+    })?;
+
+    let path = match file_id.long(db) {
+        FileLongId::OnDisk(path) => path,
+        FileLongId::Virtual(_) | FileLongId::External(_) => {
+            return Err(LSPError::new(
+                anyhow!("Cannot rename virtual files"),
+                ErrorCode::RequestFailed,
+            ));
+        }
+    };
+
+    let ops = if path.exists() {
+        let old_uri = Url::from_file_path(path).unwrap();
+        let new_uri =
+            Url::from_file_path(path.with_file_name(format!("{new_name}.cairo"))).unwrap();
+
+        Some(ResourceOp::Rename(RenameFile {
+            old_uri,
+            new_uri,
+            options: None,
+            annotation_id: None,
+        }))
+    } else {
+        None
+    };
+
+    Ok(ops)
+}
+
+fn merge_into_document_changes(
+    changes: HashMap<Url, Vec<TextEdit>>,
+    resource_ops: Vec<ResourceOp>,
+) -> DocumentChanges {
+    let text_document_edits: Vec<_> = changes
+        .into_iter()
+        .map(|(uri, text_edits)| TextDocumentEdit {
+            text_document: OptionalVersionedTextDocumentIdentifier { uri, version: None },
+            edits: text_edits.into_iter().map(OneOf::Left).collect(),
+        })
+        .map(DocumentChangeOperation::Edit)
+        .collect();
+
+    let document_change_operations = resource_ops
+        .into_iter()
+        .map(DocumentChangeOperation::Op)
+        .chain(text_document_edits)
+        .collect();
+
+    DocumentChanges::Operations(document_change_operations)
+}
@@ -0,0 +1,112 @@
+use cairo_lang_defs::ids::{
+    GenericTypeId, LanguageElementId, ModuleItemId, NamedLanguageElementId,
+};
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::{GenericArgumentId, TypeId, TypeLongId};
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_syntax::node::ast::TerminalIdentifier;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use lsp_types::request::{GotoTypeDefinitionParams, GotoTypeDefinitionResponse};
+use lsp_types::{GotoDefinitionResponse, Location};
+
+use crate::lang::db::{AnalysisDatabase, LsSemanticGroup, LsSyntaxGroup};
+use crate::lang::defs::{SymbolDef, SymbolSearch};
+use crate::lang::lsp::{LsProtoGroup, ToCairo};
+use crate::lang::types::innermost_expr_type;
+
+/// Corelib types which only wrap the types they are generic over.
+/// Going to their definition is rarely useful, so only the wrapped types are offered.
+const WRAPPER_TYPES: [&str; 4] = ["Box", "Array", "Span", "Nullable"];
+
+/// Get the definitions of types of a variable or an expression at a given text document position.
+pub fn goto_type_definition(
+    params: GotoTypeDefinitionParams,
+    db: &AnalysisDatabase,
+) -> Option<GotoTypeDefinitionResponse> {
+    let file = db.file_for_url(&params.text_document_position_params.text_document.uri)?;
+    let position = params.text_document_position_params.position.to_cairo();
+    let node = match db.find_identifier_at_position(file, position) {
+        Some(identifier) => identifier.as_syntax_node(),
+        None => db.find_syntax_node_at_position(file, position)?,
+    };
+
+    let mut types = OrderedHashSet::default();
+    for node in db.get_node_resultants(node)?.iter().copied() {
+        let variable_ty = node
+            .ancestors_with_self(db)
+            .find_map(|node| TerminalIdentifier::cast(db, node))
+            .and_then(|identifier| SymbolSearch::find_definition(db, &identifier))
+            .and_then(|symbol| match symbol.def {
+                SymbolDef::Variable(var) => var.ty(db),
+                _ => None,
+            });
+
+        if let Some(ty) = variable_ty.or_else(|| Some(innermost_expr_type(db, node)?.1)) {
+            collect_type_items(db, ty, &mut types);
+        }
+    }
+
+    let mut locations: Vec<_> = types
+        .into_iter()
+        .filter_map(|item| item_location(db, item))
+        .collect::<OrderedHashSet<_>>()
+        .into_iter()
+        .collect();
+
+    match locations.len() {
+        0 => None,
+        1 => Some(GotoDefinitionResponse::Scalar(locations.pop().unwrap())),
+        _ => Some(GotoDefinitionResponse::Array(locations)),
+    }
+}
+
+/// Collects items defining the type and all types it is built of, skipping corelib wrappers.
+fn collect_type_items<'db>(
+    db: &'db AnalysisDatabase,
+    ty: TypeId<'db>,
+    items: &mut OrderedHashSet<ModuleItemId<'db>>,
+) {
+    match ty.long(db) {
+        TypeLongId::Concrete(concrete_type) => {
+            let item = match concrete_type.generic_type(db) {
+                GenericTypeId::Struct(struct_id) => ModuleItemId::Struct(struct_id),
+                GenericTypeId::Enum(enum_id) => ModuleItemId::Enum(enum_id),
+                GenericTypeId::Extern(extern_type_id) => ModuleItemId::ExternType(extern_type_id),
+            };
+            let generic_types: Vec<_> = concrete_type
+                .generic_args(db)
+                .into_iter()
+                .filter_map(|arg| match arg {
+                    GenericArgumentId::Type(ty) => Some(ty),
+                    _ => None,
+                })
+                .collect();
+
+            if generic_types.is_empty() || !is_wrapper_type(db, item) {
+                items.insert(item);
+            }
+            for ty in generic_types {
+                collect_type_items(db, ty, items);
+            }
+        }
+        TypeLongId::Snapshot(ty) | TypeLongId::FixedSizeArray { type_id: ty, .. } => {
+            collect_type_items(db, *ty, items);
+        }
+        TypeLongId::Tuple(types) => {
+            for ty in types {
+                collect_type_items(db, *ty, items);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn is_wrapper_type<'db>(db: &'db AnalysisDatabase, item: ModuleItemId<'db>) -> bool {
+    item.parent_module(db).owning_crate(db) == CrateId::core(db)
+        && WRAPPER_TYPES.contains(&item.name(db).to_string(db).as_str())
+}
+
+/// Gets the location of the item name in user code.
+fn item_location<'db>(db: &'db AnalysisDatabase, item: ModuleItemId<'db>) -> Option<Location> {
+    db.originating_location(item.name_identifier(db).as_syntax_node())
+}
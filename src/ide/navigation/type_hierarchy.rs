@@ -0,0 +1,253 @@
+use cairo_lang_defs::ids::{LanguageElementId, LookupItemId, ModuleItemId, NamedLanguageElementId};
+use cairo_lang_filesystem::ids::SpanInFile;
+use cairo_lang_syntax::node::ast::{ExprPath, PathSegment, TerminalIdentifier};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedStablePtr, TypedSyntaxNode};
+use cairo_language_common::CommonGroup;
+use itertools::Itertools;
+use lsp_types::{
+    SymbolKind, TypeHierarchyItem, TypeHierarchyPrepareParams, TypeHierarchySubtypesParams,
+    TypeHierarchySupertypesParams,
+};
+
+use crate::lang::db::{AnalysisDatabase, LsSemanticGroup, LsSyntaxGroup};
+use crate::lang::defs::{SymbolDef, SymbolSearch};
+use crate::lang::lsp::{LsProtoGroup, ToCairo};
+use crate::lang::usages::search_scope::SearchScope;
+
+// The hierarchy is built as follows: traits are the supertypes of their impls, and impls are the
+// supertypes of types they are implemented for and of impl aliases referring to them.
+
+/// Finds the trait, impl or type referred to at the given position, which becomes the root of the
+/// type hierarchy.
+pub fn prepare_type_hierarchy(
+    params: TypeHierarchyPrepareParams,
+    db: &AnalysisDatabase,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let file = db.file_for_url(&params.text_document_position_params.text_document.uri)?;
+    let position = params.text_document_position_params.position.to_cairo();
+    let identifier = db.find_identifier_at_position(file, position)?;
+    let item = find_item(db, &identifier)?;
+
+    Some(vec![type_hierarchy_item(db, item)?])
+}
+
+/// Finds the trait of an impl, the impl of an impl alias or all impls mentioning a type in their
+/// headers.
+pub fn supertypes(
+    params: TypeHierarchySupertypesParams,
+    db: &AnalysisDatabase,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let identifier = item_identifier(db, &params.item)?;
+
+    let supertypes = match find_item(db, &identifier)? {
+        ModuleItemId::Impl(id) => {
+            path_item(db, id.stable_ptr(db).lookup(db).trait_path(db)).into_iter().collect()
+        }
+        ModuleItemId::ImplAlias(id) => {
+            path_item(db, id.stable_ptr(db).lookup(db).impl_path(db)).into_iter().collect()
+        }
+        ModuleItemId::Struct(_) | ModuleItemId::Enum(_) | ModuleItemId::ExternType(_) => {
+            referring_impls(db, &identifier, |_, _| true)
+        }
+        _ => vec![],
+    };
+
+    Some(supertypes.into_iter().filter_map(|item| type_hierarchy_item(db, item)).collect())
+}
+
+/// Finds impls of a trait, or types an impl is implemented for together with impl aliases
+/// referring to it.
+pub fn subtypes(
+    params: TypeHierarchySubtypesParams,
+    db: &AnalysisDatabase,
+) -> Option<Vec<TypeHierarchyItem>> {
+    let identifier = item_identifier(db, &params.item)?;
+
+    let subtypes = match find_item(db, &identifier)? {
+        ModuleItemId::Trait(_) => referring_impls(db, &identifier, |item, usage| match item {
+            ModuleItemId::Impl(id) => {
+                is_in_path(db, usage, id.stable_ptr(db).lookup(db).trait_path(db))
+            }
+            _ => false,
+        }),
+        ModuleItemId::Impl(id) => {
+            // Types an impl is for are the ones used as generic arguments of its trait.
+            let types = id
+                .stable_ptr(db)
+                .lookup(db)
+                .trait_path(db)
+                .as_syntax_node()
+                .descendants(db)
+                .filter_map(|node| TerminalIdentifier::cast(db, node))
+                .filter_map(|identifier| find_item(db, &identifier))
+                .filter(|item| {
+                    matches!(
+                        item,
+                        ModuleItemId::Struct(_)
+                            | ModuleItemId::Enum(_)
+                            | ModuleItemId::ExternType(_)
+                    )
+                })
+                .unique()
+                .collect_vec();
+
+            let aliases = referring_impls(db, &identifier, |item, usage| match item {
+                ModuleItemId::ImplAlias(id) => {
+                    is_in_path(db, usage, id.stable_ptr(db).lookup(db).impl_path(db))
+                }
+                _ => false,
+            });
+
+            types.into_iter().chain(aliases).collect()
+        }
+        _ => vec![],
+    };
+
+    Some(subtypes.into_iter().filter_map(|item| type_hierarchy_item(db, item)).collect())
+}
+
+/// Finds the trait, impl or type defined or referred to by the identifier.
+fn find_item<'db>(
+    db: &'db AnalysisDatabase,
+    identifier: &TerminalIdentifier<'db>,
+) -> Option<ModuleItemId<'db>> {
+    let SymbolDef::Item(item) = SymbolSearch::find_definition(db, identifier)?.def else {
+        return None;
+    };
+
+    match item.lookup_item_id() {
+        LookupItemId::ModuleItem(
+            item @ (ModuleItemId::Trait(_)
+            | ModuleItemId::Impl(_)
+            | ModuleItemId::ImplAlias(_)
+            | ModuleItemId::Struct(_)
+            | ModuleItemId::Enum(_)
+            | ModuleItemId::ExternType(_)),
+        ) => Some(item),
+        _ => None,
+    }
+}
+
+/// Finds the name of the item a type hierarchy item was created for.
+fn item_identifier<'db>(
+    db: &'db AnalysisDatabase,
+    item: &TypeHierarchyItem,
+) -> Option<TerminalIdentifier<'db>> {
+    let file = db.file_for_url(&item.uri)?;
+    db.find_identifier_at_position(file, item.selection_range.start.to_cairo())
+}
+
+/// Resolves the item referred to by the last segment of the path.
+fn path_item<'db>(db: &'db AnalysisDatabase, path: ExprPath<'db>) -> Option<ModuleItemId<'db>> {
+    let identifier = match path.segments(db).elements(db).last()? {
+        PathSegment::Simple(segment) => segment.ident(db),
+        PathSegment::WithGenericArgs(segment) => segment.ident(db),
+    };
+
+    find_item(db, &identifier)
+}
+
+/// Checks if the usage is a segment of the path, not a part of its generic arguments.
+fn is_in_path<'db>(db: &'db AnalysisDatabase, usage: SyntaxNode<'db>, path: ExprPath<'db>) -> bool {
+    usage.ancestor_of_kind(db, SyntaxKind::ExprPath) == Some(path.as_syntax_node())
+}
+
+/// Finds impls and impl aliases referring to the item defined by the identifier in their headers.
+///
+/// The `filter` is called with the impl item and the syntax node of the usage.
+fn referring_impls<'db>(
+    db: &'db AnalysisDatabase,
+    identifier: &TerminalIdentifier<'db>,
+    filter: impl Fn(ModuleItemId<'db>, SyntaxNode<'db>) -> bool,
+) -> Vec<ModuleItemId<'db>> {
+    let Some(symbol) = SymbolSearch::find_definition(db, identifier) else {
+        return vec![];
+    };
+
+    let mut impls = vec![];
+    for usage in symbol.usages(db).in_scope(SearchScope::everything(db)).collect() {
+        let SpanInFile { file_id, span } = usage.location();
+        let Some(node) = db.find_syntax_node_at_offset(file_id, span.start) else {
+            continue;
+        };
+
+        // Usages in impl bodies are not a part of the relation between the impl and the item.
+        if node.ancestor_of_kind(db, SyntaxKind::ImplBody).is_some() {
+            continue;
+        }
+
+        let Some(LookupItemId::ModuleItem(item)) = node
+            .ancestor_of_kinds(db, &[SyntaxKind::ItemImpl, SyntaxKind::ItemImplAlias])
+            .and_then(|impl_node| db.find_lookup_item(impl_node))
+        else {
+            continue;
+        };
+
+        if filter(item, node) && !impls.contains(&item) {
+            impls.push(item);
+        }
+    }
+
+    impls
+}
+
+fn type_hierarchy_item<'db>(
+    db: &'db AnalysisDatabase,
+    item: ModuleItemId<'db>,
+) -> Option<TypeHierarchyItem> {
+    let text = |node: SyntaxNode<'db>| node.get_text_without_trivia(db).to_string(db);
+
+    let (kind, detail) = match item {
+        ModuleItemId::Trait(_) => (SymbolKind::INTERFACE, item.parent_module(db).full_path(db)),
+        ModuleItemId::Struct(_) | ModuleItemId::ExternType(_) => {
+            (SymbolKind::STRUCT, item.parent_module(db).full_path(db))
+        }
+        ModuleItemId::Enum(_) => (SymbolKind::ENUM, item.parent_module(db).full_path(db)),
+        // Show impl headers to make generic impls distinguishable by their constraints.
+        ModuleItemId::Impl(id) => {
+            let imp = id.stable_ptr(db).lookup(db);
+            let header = format!(
+                "impl {}{} of {}",
+                imp.name(db).text(db).to_string(db),
+                text(imp.generic_params(db).as_syntax_node()),
+                text(imp.trait_path(db).as_syntax_node()),
+            );
+            (SymbolKind::OBJECT, header)
+        }
+        ModuleItemId::ImplAlias(id) => {
+            let alias = id.stable_ptr(db).lookup(db);
+            let header = format!(
+                "impl {}{} = {}",
+                alias.name(db).text(db).to_string(db),
+                text(alias.generic_params(db).as_syntax_node()),
+                text(alias.impl_path(db).as_syntax_node()),
+            );
+            (SymbolKind::OBJECT, header)
+        }
+        _ => return None,
+    };
+
+    let selection = db.originating_location(item.name_identifier(db).as_syntax_node())?;
+
+    // The full range must be in the same file and contain the selection range.
+    let range = db
+        .originating_location(item.untyped_stable_ptr(db).lookup(db))
+        .filter(|location| {
+            location.uri == selection.uri
+                && location.range.start <= selection.range.start
+                && selection.range.end <= location.range.end
+        })
+        .map_or(selection.range, |location| location.range);
+
+    Some(TypeHierarchyItem {
+        name: item.name(db).to_string(db),
+        kind,
+        tags: None,
+        detail: Some(detail),
+        uri: selection.uri,
+        range,
+        selection_range: selection.range,
+        data: None,
+    })
+}
@@ -0,0 +1,249 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{
+    ModuleId, ModuleItemId, NamedLanguageElementId, TopLevelLanguageElementId,
+};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::{CrateId, CrateInput};
+use cairo_lang_semantic::items::imp::ImplSemantic;
+use cairo_lang_semantic::items::trt::TraitSemantic;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+use itertools::Itertools;
+use lsp_types::{
+    Location, SymbolInformation, SymbolKind, Url, WorkspaceSymbolParams, WorkspaceSymbolResponse,
+};
+
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::lsp::LsProtoGroup;
+use crate::lang::text_matching::text_matches;
+
+/// The maximum number of symbols returned for a single query.
+const MAX_RESULTS: usize = 256;
+
+/// Searches for symbols matching the query in all crates loaded into the database.
+pub fn workspace_symbols(
+    params: WorkspaceSymbolParams,
+    db: &AnalysisDatabase,
+    index: &WorkspaceSymbolIndex,
+) -> Option<WorkspaceSymbolResponse> {
+    let symbols = index
+        .crate_symbols(db)
+        .iter()
+        .flat_map(|symbols| symbols.iter())
+        .filter(|symbol| text_matches(&symbol.name, &params.query))
+        // Prefer exact and prefix matches, the sort is stable, so user crates still go first.
+        .sorted_by_key(|symbol| {
+            (symbol.name != params.query, !symbol.name.starts_with(&params.query))
+        })
+        .take(MAX_RESULTS)
+        .map(IndexedSymbol::to_symbol_information)
+        .collect();
+
+    Some(WorkspaceSymbolResponse::Flat(symbols))
+}
+
+/// Index of symbols defined in crates analysed by the language server.
+///
+/// Collecting symbols requires walking through every module of a crate, which is too slow to be
+/// done on every keystroke in large workspaces.
+/// Therefore, symbols are collected once per crate, and only crates that were (re)loaded into the
+/// project model or had one of their files changed are indexed again.
+#[derive(Clone, Default)]
+pub struct WorkspaceSymbolIndex {
+    state: Arc<RwLock<WorkspaceSymbolIndexState>>,
+}
+
+#[derive(Default)]
+struct WorkspaceSymbolIndexState {
+    crates: HashMap<CrateInput, Arc<[IndexedSymbol]>>,
+    /// Crates which have to be indexed again before the next query, with the generation in which
+    /// they were marked as outdated.
+    outdated_crates: HashMap<CrateInput, u64>,
+    /// Files edited since the last query, their crates have to be indexed again.
+    outdated_files: HashSet<Url>,
+    /// Incremented every time a crate is marked as outdated.
+    ///
+    /// Indexing runs without holding the lock, so a crate may be marked as outdated again while
+    /// it is being indexed, in which case it must stay outdated.
+    generation: u64,
+}
+
+impl WorkspaceSymbolIndexState {
+    fn mark_outdated(&mut self, crates: impl IntoIterator<Item = CrateInput>) {
+        for input in crates {
+            self.generation += 1;
+            self.outdated_crates.insert(input, self.generation);
+        }
+    }
+}
+
+impl WorkspaceSymbolIndex {
+    /// Marks crates which were (re)loaded into the project model as requiring indexing.
+    pub fn on_crates_loaded(&self, crates: impl IntoIterator<Item = CrateInput>) {
+        self.state.write().unwrap().mark_outdated(crates);
+    }
+
+    /// Marks the crate owning an edited, created or deleted file as requiring indexing.
+    ///
+    /// The owning crate is resolved lazily to keep this method cheap.
+    pub fn on_file_changed(&self, url: Url) {
+        self.state.write().unwrap().outdated_files.insert(url);
+    }
+
+    /// Returns symbols of all crates in the database, indexing the ones that are missing or
+    /// outdated.
+    fn crate_symbols(&self, db: &AnalysisDatabase) -> Vec<Arc<[IndexedSymbol]>> {
+        let crates = db
+            .crates()
+            .iter()
+            .map(|&crate_id| (crate_id.long(db).clone().into_crate_input(db), crate_id))
+            // Symbols of the corelib are the least likely to be searched for.
+            .sorted_by_key(|&(_, crate_id)| crate_id == CrateId::core(db))
+            .collect_vec();
+
+        // Queries below may be cancelled by salsa, so they are run without holding the lock,
+        // and the outdated entries are cleared only after the work is done.
+        let outdated_files = self.state.read().unwrap().outdated_files.clone();
+        let edited_crates = outdated_files
+            .iter()
+            .filter_map(|url| db.file_for_url(url))
+            .filter_map(|file| Some(db.file_modules(file).ok()?.first()?.owning_crate(db)))
+            .map(|crate_id| crate_id.long(db).clone().into_crate_input(db))
+            .collect_vec();
+
+        let to_index = {
+            let mut state = self.state.write().unwrap();
+            state.outdated_files.retain(|url| !outdated_files.contains(url));
+            state.mark_outdated(edited_crates);
+
+            // Forget crates which are no longer loaded.
+            state.crates.retain(|input, _| crates.iter().any(|(loaded, _)| loaded == input));
+
+            crates
+                .iter()
+                .filter_map(|(input, crate_id)| {
+                    let generation = state.outdated_crates.get(input).copied();
+                    (generation.is_some() || !state.crates.contains_key(input))
+                        .then(|| (input.clone(), *crate_id, generation))
+                })
+                .collect_vec()
+        };
+
+        let indexed = to_index
+            .into_iter()
+            .map(|(input, crate_id, generation)| (input, generation, index_crate(db, crate_id)))
+            .collect_vec();
+
+        let mut state = self.state.write().unwrap();
+        for (input, generation, symbols) in indexed {
+            // Keep crates marked as outdated while they were being indexed for the next query.
+            if state.outdated_crates.get(&input).copied() == generation {
+                state.outdated_crates.remove(&input);
+            }
+            state.crates.insert(input, symbols);
+        }
+
+        crates.iter().filter_map(|(input, _)| state.crates.get(input).cloned()).collect()
+    }
+}
+
+struct IndexedSymbol {
+    name: String,
+    kind: SymbolKind,
+    container_name: String,
+    location: Location,
+}
+
+impl IndexedSymbol {
+    fn new<'db>(
+        db: &'db AnalysisDatabase,
+        name: String,
+        kind: SymbolKind,
+        container_name: String,
+        name_node: SyntaxNode<'db>,
+    ) -> Option<Self> {
+        Some(Self { name, kind, container_name, location: db.originating_location(name_node)? })
+    }
+
+    #[expect(deprecated)]
+    fn to_symbol_information(&self) -> SymbolInformation {
+        SymbolInformation {
+            name: self.name.clone(),
+            kind: self.kind,
+            tags: None,
+            deprecated: None,
+            location: self.location.clone(),
+            container_name: Some(self.container_name.clone()),
+        }
+    }
+}
+
+fn index_crate<'db>(db: &'db AnalysisDatabase, crate_id: CrateId<'db>) -> Arc<[IndexedSymbol]> {
+    db.crate_modules(crate_id).iter().flat_map(|&module| module_symbols(db, module)).collect()
+}
+
+fn module_symbols<'db>(db: &'db AnalysisDatabase, module: ModuleId<'db>) -> Vec<IndexedSymbol> {
+    let Ok(module_data) = module.module_data(db) else {
+        return vec![];
+    };
+    let container_name = module.full_path(db);
+
+    let mut symbols = vec![];
+    for &item in module_data.items(db) {
+        let kind = match item {
+            ModuleItemId::Submodule(_) => SymbolKind::MODULE,
+            ModuleItemId::Struct(_) | ModuleItemId::ExternType(_) => SymbolKind::STRUCT,
+            ModuleItemId::Enum(_) => SymbolKind::ENUM,
+            ModuleItemId::Trait(_) => SymbolKind::INTERFACE,
+            ModuleItemId::Impl(_) | ModuleItemId::ImplAlias(_) => SymbolKind::OBJECT,
+            ModuleItemId::Constant(_) => SymbolKind::CONSTANT,
+            ModuleItemId::TypeAlias(_) => SymbolKind::TYPE_PARAMETER,
+            ModuleItemId::FreeFunction(_) | ModuleItemId::ExternFunction(_) => SymbolKind::FUNCTION,
+            ModuleItemId::MacroDeclaration(_) => SymbolKind::FUNCTION,
+            ModuleItemId::Use(_) => continue,
+        };
+
+        symbols.extend(IndexedSymbol::new(
+            db,
+            item.name(db).to_string(db),
+            kind,
+            container_name.clone(),
+            item.name_identifier(db).as_syntax_node(),
+        ));
+
+        match item {
+            ModuleItemId::Trait(trait_id) => {
+                let container_name = trait_id.full_path(db);
+                for &function in
+                    db.trait_functions(trait_id).into_iter().flat_map(|fns| fns.values())
+                {
+                    symbols.extend(IndexedSymbol::new(
+                        db,
+                        function.name(db).to_string(db),
+                        SymbolKind::METHOD,
+                        container_name.clone(),
+                        function.name_identifier(db).as_syntax_node(),
+                    ));
+                }
+            }
+            ModuleItemId::Impl(impl_id) => {
+                let container_name = impl_id.full_path(db);
+                for &function in db.impl_functions(impl_id).into_iter().flat_map(|fns| fns.values())
+                {
+                    symbols.extend(IndexedSymbol::new(
+                        db,
+                        function.name(db).to_string(db),
+                        SymbolKind::METHOD,
+                        container_name.clone(),
+                        function.name_identifier(db).as_syntax_node(),
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    symbols
+}
@@ -0,0 +1,78 @@
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextPosition, TextSpan};
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use lsp_types::{Range, SelectionRange, SelectionRangeParams};
+
+use crate::lang::db::{AnalysisDatabase, LsSyntaxGroup};
+use crate::lang::lsp::{LsProtoGroup, ToCairo, ToLsp};
+
+/// Computes syntax-aware selection ranges for each of the given positions.
+///
+/// The result contains exactly one range per position, as required by the protocol.
+pub fn selection_ranges(
+    params: SelectionRangeParams,
+    db: &AnalysisDatabase,
+) -> Option<Vec<SelectionRange>> {
+    let file = db.file_for_url(&params.text_document.uri)?;
+
+    Some(
+        params
+            .positions
+            .into_iter()
+            .map(|position| {
+                selection_range(db, file, position.to_cairo()).unwrap_or(SelectionRange {
+                    range: Range::new(position, position),
+                    parent: None,
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Builds the chain of selection ranges by walking up the syntax tree from the given position.
+///
+/// Each step widens the selection to the next node covering more code than the previous one,
+/// so that nodes having the same span as their child (e.g. a path consisting of a single segment)
+/// do not result in repeated selections.
+fn selection_range<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    position: TextPosition,
+) -> Option<SelectionRange> {
+    let offset = position.offset_in_file(db, file)?;
+
+    // Prefer identifiers, so that `ident<caret>()` starts with selecting `ident`.
+    let node = db
+        .find_identifier_at_position(file, position)
+        .map(|identifier| identifier.as_syntax_node())
+        .or_else(|| db.find_syntax_node_at_position(file, position))?;
+
+    let mut spans: Vec<TextSpan> = vec![];
+    for node in node.ancestors_with_self(db) {
+        // Whitespace around the cursor has nothing meaningful to select.
+        if matches!(
+            node.kind(db),
+            SyntaxKind::Trivia | SyntaxKind::TokenWhitespace | SyntaxKind::TokenNewline
+        ) {
+            continue;
+        }
+
+        let span = node.span_without_trivia(db);
+        // Terminals are visited with their trivia, which may be the only part covering the cursor.
+        if !(span.start <= offset && offset <= span.end) {
+            continue;
+        }
+
+        if spans.last().is_none_or(|last| *last != span) {
+            spans.push(span);
+        }
+    }
+
+    spans.into_iter().rev().try_fold(None, |parent, span| {
+        Some(Some(SelectionRange {
+            range: span.position_in_file(db, file)?.to_lsp(),
+            parent: parent.map(Box::new),
+        }))
+    })?
+}
@@ -1,3 +1,10 @@
+use std::collections::HashMap;
+
+use lsp_types::{Range, SemanticToken, SemanticTokensEdit, Url};
+
+/// Maximum number of documents whose tokens are kept for computing deltas.
+const MAX_CACHED_DOCUMENTS: usize = 64;
+
 /// LSP protocol is using a differential position encoding to report the tokens.
 /// This encoder outputs this encoding.
 #[derive(Default)]
@@ -21,6 +28,11 @@ impl TokenEncoder {
         self.col += width;
     }
 
+    /// Current line number.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
     /// Moves to the next line.
     pub fn next_line(&mut self) {
         self.line += 1;
@@ -39,3 +51,93 @@ impl TokenEncoder {
         EncodedToken { delta_line, delta_start }
     }
 }
+
+/// Tokens last sent to the client for each document, identified by result IDs.
+/// Used to answer delta requests with only the tokens which changed.
+#[derive(Default)]
+pub struct SemanticTokensCache {
+    next_result_id: u64,
+    documents: HashMap<Url, (String, Vec<SemanticToken>)>,
+}
+
+impl SemanticTokensCache {
+    /// Remembers the tokens sent for the document and returns the result ID identifying them.
+    pub fn store(&mut self, uri: Url, tokens: Vec<SemanticToken>) -> String {
+        // Forgetting tokens only makes the next request for them fall back to full tokens.
+        if self.documents.len() >= MAX_CACHED_DOCUMENTS && !self.documents.contains_key(&uri) {
+            self.documents.clear();
+        }
+
+        self.next_result_id += 1;
+        let result_id = self.next_result_id.to_string();
+        self.documents.insert(uri, (result_id.clone(), tokens));
+        result_id
+    }
+
+    /// Gets the tokens sent for the document, if they are identified by the result ID.
+    pub fn get(&self, uri: &Url, result_id: &str) -> Option<&[SemanticToken]> {
+        self.documents
+            .get(uri)
+            .filter(|(id, _)| id == result_id)
+            .map(|(_, tokens)| tokens.as_slice())
+    }
+}
+
+/// Computes edits turning the previously sent encoding into the new one.
+///
+/// Tokens are encoded relative to each other, so an edit of the document usually changes only
+/// the tokens it touches and a single edit replacing them is enough.
+pub fn tokens_delta(old: &[SemanticToken], new: &[SemanticToken]) -> Vec<SemanticTokensEdit> {
+    let prefix = old.iter().zip(new).take_while(|(old, new)| old == new).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+
+    let deleted = &old[prefix..old.len() - suffix];
+    let inserted = &new[prefix..new.len() - suffix];
+    if deleted.is_empty() && inserted.is_empty() {
+        return vec![];
+    }
+
+    // Edits index the flattened array, in which every token takes 5 integers.
+    vec![SemanticTokensEdit {
+        start: prefix as u32 * 5,
+        delete_count: deleted.len() as u32 * 5,
+        data: Some(inserted.to_vec()),
+    }]
+}
+
+/// Keeps only the tokens starting within the range, encoded relative to the document start.
+pub fn tokens_in_range(tokens: &[SemanticToken], range: Range) -> Vec<SemanticToken> {
+    let mut result = vec![];
+    let mut encoder = TokenEncoder::default();
+    let mut line = 0;
+    let mut col = 0;
+
+    for token in tokens {
+        if token.delta_line > 0 {
+            col = 0;
+        }
+        line += token.delta_line;
+        col += token.delta_start;
+
+        if (line, col) < (range.start.line, range.start.character) {
+            continue;
+        }
+        if (line, col) >= (range.end.line, range.end.character) {
+            break;
+        }
+
+        while encoder.line < line {
+            encoder.next_line();
+        }
+        encoder.col = col;
+        let EncodedToken { delta_line, delta_start } = encoder.encode(token.length);
+        result.push(SemanticToken { delta_line, delta_start, ..*token });
+    }
+
+    result
+}
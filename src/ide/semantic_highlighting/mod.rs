@@ -1,8 +1,15 @@
 use cairo_lang_parser::db::ParserGroup;
-use lsp_types::{SemanticTokens, SemanticTokensParams, SemanticTokensResult};
+use lsp_types::{
+    SemanticToken, SemanticTokens, SemanticTokensDelta, SemanticTokensDeltaParams,
+    SemanticTokensFullDeltaResult, SemanticTokensParams, SemanticTokensRangeParams,
+    SemanticTokensRangeResult, SemanticTokensResult, Url,
+};
 use tracing::error;
 
+pub use self::encoder::SemanticTokensCache;
+use self::encoder::{tokens_delta, tokens_in_range};
 pub use self::token_kind::SemanticTokenKind;
+pub use self::token_modifiers::SemanticTokenModifiers;
 use crate::ide::semantic_highlighting::token_traverser::SemanticTokensTraverser;
 use crate::lang::db::AnalysisDatabase;
 use crate::lang::lsp::LsProtoGroup;
@@ -10,23 +17,82 @@ use crate::state::MetaState;
 
 mod encoder;
 pub mod token_kind;
+pub mod token_modifiers;
 mod token_traverser;
 
 /// Resolves the semantic tokens of a given file.
 pub fn semantic_highlight_full(
     params: SemanticTokensParams,
     db: &AnalysisDatabase,
-    _ls_meta_state: MetaState,
+    ls_meta_state: MetaState,
 ) -> Option<SemanticTokensResult> {
     let file_uri = params.text_document.uri;
-    let file = db.file_for_url(&file_uri)?;
+    let data = file_tokens(db, &file_uri, SemanticTokensTraverser::default())?;
+    let result_id = cache_tokens(&ls_meta_state, file_uri, data.clone());
+
+    Some(SemanticTokensResult::Tokens(SemanticTokens { result_id: Some(result_id), data }))
+}
+
+/// Resolves the changes in semantic tokens of a given file since the previous result.
+///
+/// Falls back to full tokens if the previous result is no longer known.
+pub fn semantic_highlight_full_delta(
+    params: SemanticTokensDeltaParams,
+    db: &AnalysisDatabase,
+    ls_meta_state: MetaState,
+) -> Option<SemanticTokensFullDeltaResult> {
+    let file_uri = params.text_document.uri;
+    let data = file_tokens(db, &file_uri, SemanticTokensTraverser::default())?;
+
+    let mut meta_state = ls_meta_state.lock().expect("should be able to acquire the MetaState");
+    let cache = &mut meta_state.semantic_tokens_cache;
+    let edits = cache
+        .get(&file_uri, &params.previous_result_id)
+        .map(|previous| tokens_delta(previous, &data));
+    let result_id = Some(cache.store(file_uri, data.clone()));
+
+    Some(match edits {
+        Some(edits) => {
+            SemanticTokensFullDeltaResult::TokensDelta(SemanticTokensDelta { result_id, edits })
+        }
+        None => SemanticTokensFullDeltaResult::Tokens(SemanticTokens { result_id, data }),
+    })
+}
+
+/// Resolves the semantic tokens within a range of a given file.
+///
+/// The file is not analysed past the end of the range.
+pub fn semantic_highlight_range(
+    params: SemanticTokensRangeParams,
+    db: &AnalysisDatabase,
+) -> Option<SemanticTokensRangeResult> {
+    let traverser = SemanticTokensTraverser::up_to_line(params.range.end.line);
+    let data = file_tokens(db, &params.text_document.uri, traverser)?;
+
+    Some(SemanticTokensRangeResult::Tokens(SemanticTokens {
+        result_id: None,
+        data: tokens_in_range(&data, params.range),
+    }))
+}
+
+fn file_tokens(
+    db: &AnalysisDatabase,
+    file_uri: &Url,
+    mut traverser: SemanticTokensTraverser,
+) -> Option<Vec<SemanticToken>> {
+    let file = db.file_for_url(file_uri)?;
     let Ok(node) = db.file_syntax(file) else {
         error!("semantic analysis failed: file '{file_uri}' does not exist");
         return None;
     };
 
-    Some(SemanticTokensResult::Tokens(SemanticTokens {
-        result_id: None,
-        data: SemanticTokensTraverser::default().get_semantic_tokens(db, node),
-    }))
+    Some(traverser.get_semantic_tokens(db, node))
+}
+
+fn cache_tokens(ls_meta_state: &MetaState, file_uri: Url, tokens: Vec<SemanticToken>) -> String {
+    ls_meta_state
+        .lock()
+        .expect("should be able to acquire the MetaState")
+        .semantic_tokens_cache
+        .store(file_uri, tokens)
 }
@@ -12,6 +12,7 @@ use cairo_language_common::CommonGroup;
 use lsp_types::SemanticTokenType;
 
 use crate::lang::db::{AnalysisDatabase, LsSemanticGroup};
+use crate::lang::defs::ResolvedItem;
 
 #[derive(Clone, Copy)]
 pub enum SemanticTokenKind {
@@ -42,12 +43,19 @@ pub enum SemanticTokenKind {
 }
 
 impl SemanticTokenKind {
-    pub fn from_syntax_node<'db>(db: &'db AnalysisDatabase, node: SyntaxNode<'db>) -> Option<Self> {
+    /// Classifies a token.
+    ///
+    /// If an identifier is classified by resolving the item it refers to, the item is returned
+    /// as well, so that it does not have to be resolved again.
+    pub fn from_syntax_node<'db>(
+        db: &'db AnalysisDatabase,
+        node: SyntaxNode<'db>,
+    ) -> Option<(Self, Option<ResolvedItem<'db>>)> {
         let node_kind = node.kind(db);
 
         // Simple tokens.
         if !matches!(node_kind, SyntaxKind::TokenIdentifier) {
-            return Self::from_simple_token_kind(db, &node);
+            return Self::from_simple_token_kind(db, &node).map(|kind| (kind, None));
         }
 
         let identifier = node.ancestor_of_type::<ast::TerminalIdentifier>(db)?;
@@ -55,24 +63,24 @@ impl SemanticTokenKind {
         // Non-keyword keywords.
         if [SUPER_KW, SELF_TYPE_KW, CRATE_KW].contains(&identifier.text(db).to_string(db).as_str())
         {
-            return Some(SemanticTokenKind::Keyword);
+            return Some((SemanticTokenKind::Keyword, None));
         }
 
         let identifier_parent = identifier.as_syntax_node().parent(db)?;
         if let Some(kind) = Self::from_identifier(db, &identifier) {
-            return Some(kind);
+            return Some((kind, None));
         }
 
         for node in identifier_parent.ancestors_with_self(db) {
             if is_inline_macro(db, node) {
-                return Some(SemanticTokenKind::InlineMacro);
+                return Some((SemanticTokenKind::InlineMacro, None));
             }
 
             match node.kind(db) {
-                SyntaxKind::Member => return Some(SemanticTokenKind::Variable),
-                SyntaxKind::PatternIdentifier => return Some(SemanticTokenKind::Variable),
-                SyntaxKind::Variant => return Some(SemanticTokenKind::EnumMember),
-                SyntaxKind::Attribute => return Some(SemanticTokenKind::Annotation),
+                SyntaxKind::Member => return Some((SemanticTokenKind::Variable, None)),
+                SyntaxKind::PatternIdentifier => return Some((SemanticTokenKind::Variable, None)),
+                SyntaxKind::Variant => return Some((SemanticTokenKind::EnumMember, None)),
+                SyntaxKind::Attribute => return Some((SemanticTokenKind::Annotation, None)),
                 _ => {}
             };
 
@@ -88,12 +96,14 @@ impl SemanticTokenKind {
                     continue;
                 };
                 for lookup_item_id in lookup_items {
-                    if let Some(kind) = Self::from_resultant(db, *resultant, *lookup_item_id) {
-                        return Some(kind);
+                    if let Some((kind, item)) =
+                        Self::from_resultant(db, *resultant, *lookup_item_id)
+                    {
+                        return Some((kind, Some(item)));
                     }
 
                     if let Some(kind) = Self::from_expr_path(db, *resultant, *lookup_item_id) {
-                        return Some(kind);
+                        return Some((kind, None));
                     }
                 }
             }
@@ -216,16 +226,17 @@ impl SemanticTokenKind {
     /// - `resultant`: The resultant syntax node.
     /// - `lookup_item_id`: The lookup item ID to use for the semantic lookup.
     ///   Returns
-    /// - `Some(SemanticTokenKind)` if a corresponding semantic token kind is found, otherwise returns `None`.
-    fn from_resultant(
-        db: &AnalysisDatabase,
-        resultant: SyntaxNode,
-        lookup_item_id: LookupItemId,
-    ) -> Option<SemanticTokenKind> {
+    /// - `Some((SemanticTokenKind, ResolvedItem))` if a corresponding semantic token kind is found,
+    ///   along with the item the resultant is resolved to, otherwise returns `None`.
+    fn from_resultant<'db>(
+        db: &'db AnalysisDatabase,
+        resultant: SyntaxNode<'db>,
+        lookup_item_id: LookupItemId<'db>,
+    ) -> Option<(SemanticTokenKind, ResolvedItem<'db>)> {
         let terminal_ptr = find_closest_terminal_ancestor_or_self(db, resultant)?;
 
         if let Some(item) = db.lookup_resolved_generic_item_by_ptr(lookup_item_id, terminal_ptr) {
-            return Some(match item {
+            let kind = match &item {
                 ResolvedGenericItem::GenericConstant(_) => SemanticTokenKind::EnumMember,
                 ResolvedGenericItem::Module(_) => SemanticTokenKind::Namespace,
                 ResolvedGenericItem::GenericFunction(_) => SemanticTokenKind::Function,
@@ -248,11 +259,12 @@ impl SemanticTokenKind {
                     TraitItemId::Impl(_) => SemanticTokenKind::Class,
                 },
                 ResolvedGenericItem::Macro(_) => SemanticTokenKind::InlineMacro,
-            });
+            };
+            return Some((kind, ResolvedItem::Generic(item)));
         }
 
         if let Some(item) = db.lookup_resolved_concrete_item_by_ptr(lookup_item_id, terminal_ptr) {
-            return Some(match item {
+            let kind = match &item {
                 ResolvedConcreteItem::Constant(_) => SemanticTokenKind::EnumMember,
                 ResolvedConcreteItem::Module(_) => SemanticTokenKind::Namespace,
                 ResolvedConcreteItem::Function(_) => SemanticTokenKind::Function,
@@ -267,7 +279,8 @@ impl SemanticTokenKind {
                 }
                 ResolvedConcreteItem::Impl(_) => SemanticTokenKind::Class,
                 ResolvedConcreteItem::Macro(_) => SemanticTokenKind::InlineMacro,
-            });
+            };
+            return Some((kind, ResolvedItem::Concrete(item)));
         }
 
         None
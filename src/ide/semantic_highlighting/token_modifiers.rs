@@ -0,0 +1,168 @@
+use std::ops::BitOrAssign;
+
+use cairo_lang_defs::ids::{ImplItemId, LookupItemId, ModuleItemId, TraitItemId};
+use cairo_lang_filesystem::ids::CrateId;
+use cairo_lang_semantic::lsp_helpers::LspHelpers;
+use cairo_lang_syntax::attribute::consts::DEPRECATED_ATTR;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode, ast};
+use lsp_types::SemanticTokenModifier;
+
+use super::token_kind::SemanticTokenKind;
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::defs::{ResolvedItem, SymbolDef, SymbolSearch};
+
+/// A set of semantic token modifiers, encoded as a bitset indexed by positions in the legend.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct SemanticTokenModifiers(u32);
+
+impl SemanticTokenModifiers {
+    /// The identifier names a symbol at its definition site.
+    pub const DECLARATION: Self = Self(1 << 0);
+    /// The variable is bound with `mut` or is a `ref` parameter.
+    pub const MUTABLE: Self = Self(1 << 1);
+    /// The item is marked with `#[deprecated]`.
+    pub const DEPRECATED: Self = Self(1 << 2);
+    /// The item comes from the corelib.
+    pub const DEFAULT_LIBRARY: Self = Self(1 << 3);
+    /// The symbol is a constant.
+    pub const STATIC: Self = Self(1 << 4);
+
+    pub fn legend() -> Vec<SemanticTokenModifier> {
+        vec![
+            SemanticTokenModifier::DECLARATION,
+            SemanticTokenModifier::new("mutable"),
+            SemanticTokenModifier::DEPRECATED,
+            SemanticTokenModifier::DEFAULT_LIBRARY,
+            SemanticTokenModifier::STATIC,
+        ]
+    }
+
+    /// Gets the bitset to put in a semantic token.
+    pub fn bitset(self) -> u32 {
+        self.0
+    }
+
+    /// Computes modifiers of the symbol an identifier token refers to.
+    /// Tokens other than identifiers have no modifiers.
+    ///
+    /// The item resolved when classifying the token is reused if given, the definition of the
+    /// symbol is searched for otherwise, but only for token kinds that can carry modifiers.
+    pub fn from_syntax_node<'db>(
+        db: &'db AnalysisDatabase,
+        node: SyntaxNode<'db>,
+        kind: SemanticTokenKind,
+        resolved_item: Option<ResolvedItem<'db>>,
+    ) -> Self {
+        let mut modifiers = Self::default();
+        if node.kind(db) != SyntaxKind::TokenIdentifier {
+            return modifiers;
+        }
+        let Some(identifier) = node.ancestor_of_type::<ast::TerminalIdentifier>(db) else {
+            return modifiers;
+        };
+        let symbol = match resolved_item {
+            Some(resolved_item) => SymbolSearch::from_resolved_item(db, resolved_item, None),
+            None if may_have_modifiers(kind) => SymbolSearch::find_definition(db, &identifier),
+            None => None,
+        };
+        let Some(symbol) = symbol else {
+            return modifiers;
+        };
+
+        let identifier_node = identifier.as_syntax_node();
+        if symbol.def.definition_location(db).is_some_and(|location| {
+            location.file_id == identifier_node.stable_ptr(db).file_id(db)
+                && location.span == identifier_node.span_without_trivia(db)
+        }) {
+            modifiers |= Self::DECLARATION;
+        }
+
+        match &symbol.def {
+            SymbolDef::Variable(var) => {
+                if var.is_mutable(db) {
+                    modifiers |= Self::MUTABLE;
+                }
+                if var.is_constant(db) {
+                    modifiers |= Self::STATIC;
+                }
+            }
+            SymbolDef::Item(item) => {
+                if is_constant(item.lookup_item_id()) {
+                    modifiers |= Self::STATIC;
+                }
+            }
+            _ => {}
+        }
+
+        if let SymbolDef::Item(_) | SymbolDef::Member(_) | SymbolDef::Variant(_) = &symbol.def
+            && let Some(ptr) = symbol.def.definition_stable_ptr(db)
+        {
+            let definition = ptr.lookup(db);
+            if is_deprecated(db, definition) {
+                modifiers |= Self::DEPRECATED;
+            }
+            if db
+                .find_module_containing_node(definition)
+                .is_some_and(|module_id| module_id.owning_crate(db) == CrateId::core(db))
+            {
+                modifiers |= Self::DEFAULT_LIBRARY;
+            }
+        }
+
+        modifiers
+    }
+}
+
+impl BitOrAssign for SemanticTokenModifiers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Checks if tokens of the kind can refer to variables, constants or items that can be deprecated.
+///
+/// Searching for definitions is expensive, so it is skipped for other kinds.
+fn may_have_modifiers(kind: SemanticTokenKind) -> bool {
+    matches!(
+        kind,
+        SemanticTokenKind::Variable
+            | SemanticTokenKind::Parameter
+            | SemanticTokenKind::Field
+            | SemanticTokenKind::EnumMember
+            | SemanticTokenKind::Function
+            | SemanticTokenKind::Struct
+            | SemanticTokenKind::Enum
+            | SemanticTokenKind::Interface
+            | SemanticTokenKind::Class
+            | SemanticTokenKind::Type
+    )
+}
+
+fn is_constant(lookup_item_id: LookupItemId) -> bool {
+    matches!(
+        lookup_item_id,
+        LookupItemId::ModuleItem(ModuleItemId::Constant(_))
+            | LookupItemId::TraitItem(TraitItemId::Constant(_))
+            | LookupItemId::ImplItem(ImplItemId::Constant(_))
+    )
+}
+
+/// Checks if the definition belongs to an item, member or variant marked with `#[deprecated]`.
+fn is_deprecated<'db>(db: &'db AnalysisDatabase, definition: SyntaxNode<'db>) -> bool {
+    // The closest ancestor with attributes is the one being defined.
+    definition
+        .ancestors(db)
+        .find_map(|node| {
+            node.get_children(db)
+                .iter()
+                .find(|child| child.kind(db) == SyntaxKind::AttributeList)
+                .map(|attributes| ast::AttributeList::from_syntax_node(db, *attributes))
+        })
+        .is_some_and(|attributes| {
+            attributes.elements(db).any(|attribute| {
+                attribute.attr(db).as_syntax_node().get_text_without_trivia(db).to_string(db)
+                    == DEPRECATED_ATTR
+            })
+        })
+}
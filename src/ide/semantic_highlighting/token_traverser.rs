@@ -8,6 +8,7 @@ use itertools::Itertools;
 use lsp_types::SemanticToken;
 
 use super::token_kind::SemanticTokenKind;
+use super::token_modifiers::SemanticTokenModifiers;
 use crate::{
     ide::markdown::COMMENT_TOKEN_PREFIX_LEN,
     ide::semantic_highlighting::encoder::{EncodedToken, TokenEncoder},
@@ -23,8 +24,15 @@ pub struct SemanticTokensTraverser {
     /// to the map, so that instead of marking it as an identifier, we will mark it
     /// as a function name.
     offset_to_kind_lookahead: UnorderedHashMap<TextOffset, SemanticTokenKind>,
+    /// Line after which the traversal stops, if only the beginning of the file is needed.
+    last_line: Option<u32>,
 }
 impl SemanticTokensTraverser {
+    /// Creates a traverser which skips everything after the given line.
+    pub fn up_to_line(last_line: u32) -> Self {
+        Self { last_line: Some(last_line), ..Self::default() }
+    }
+
     /// Gets all the SemanticTokens for the given node.
     /// Traverses the syntax tree and encodes the tokens based on their semantic kind.
     pub fn get_semantic_tokens<'db>(
@@ -50,6 +58,9 @@ impl SemanticTokensTraverser {
                 let children = node.get_children(db);
                 self.mark_future_tokens_for_node(db, node, green_node.kind);
                 for child in children.iter() {
+                    if self.last_line.is_some_and(|last_line| self.encoder.line() > last_line) {
+                        break;
+                    }
                     semantic_tokens.extend(self.get_semantic_tokens(db, *child));
                 }
                 semantic_tokens
@@ -74,16 +85,23 @@ impl SemanticTokensTraverser {
         let maybe_semantic_kind = self
             .offset_to_kind_lookahead
             .remove(&node.offset(db))
+            .map(|kind| (kind, None))
             .or_else(|| SemanticTokenKind::from_syntax_node(db, node));
 
-        if let Some(semantic_kind) = maybe_semantic_kind {
+        if let Some((semantic_kind, resolved_item)) = maybe_semantic_kind {
             let text = node.text(db).expect("Node text should be available").to_string(db);
 
             // Case where a token spans multiple lines.
             if text.contains('\n') {
                 self.get_tokens_from_multiline_syntax_node(semantic_kind, &text)
             } else {
-                vec![self.get_semantic_token(width, &semantic_kind)]
+                let modifiers = SemanticTokenModifiers::from_syntax_node(
+                    db,
+                    node,
+                    semantic_kind,
+                    resolved_item,
+                );
+                vec![self.get_semantic_token(width, &semantic_kind, modifiers)]
             }
         } else {
             self.encoder.skip(width);
@@ -123,9 +141,11 @@ impl SemanticTokensTraverser {
             }
             // Sanity check - should always be true.
             assert!(end > start, "Incorrect link range");
-            tokens.push(
-                self.get_semantic_token((end - start) as u32, &SemanticTokenKind::IntraDocLink),
-            );
+            tokens.push(self.get_semantic_token(
+                (end - start) as u32,
+                &SemanticTokenKind::IntraDocLink,
+                SemanticTokenModifiers::default(),
+            ));
             cursor = end;
         }
         // Remainder after the last link.
@@ -146,7 +166,11 @@ impl SemanticTokensTraverser {
         let mut tokens = vec![];
         // Split multiline token into multiple single line tokens.
         for line in node_text.split_inclusive('\n') {
-            tokens.push(self.get_semantic_token(line.len() as u32, &node_semantic_kind));
+            tokens.push(self.get_semantic_token(
+                line.len() as u32,
+                &node_semantic_kind,
+                SemanticTokenModifiers::default(),
+            ));
 
             if line.ends_with('\n') {
                 self.encoder.next_line();
@@ -192,11 +216,13 @@ impl SemanticTokensTraverser {
         }
     }
 
-    /// Retrieves the semantic token for the current node based on its width and assumed SemanticTokenKind.
+    /// Retrieves the semantic token for the current node based on its width, assumed SemanticTokenKind
+    /// and modifiers.
     fn get_semantic_token(
         &mut self,
         width: u32,
         assumed_semantic_kind: &SemanticTokenKind,
+        modifiers: SemanticTokenModifiers,
     ) -> SemanticToken {
         let EncodedToken { delta_line, delta_start } = self.encoder.encode(width);
 
@@ -205,7 +231,7 @@ impl SemanticTokensTraverser {
             delta_start,
             length: width,
             token_type: *assumed_semantic_kind as u32,
-            token_modifiers_bitset: 0,
+            token_modifiers_bitset: modifiers.bitset(),
         }
     }
 
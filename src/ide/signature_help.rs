@@ -0,0 +1,295 @@
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{LookupItemId, ModuleItemId, TraitItemId};
+use cairo_lang_defs::plugin::InlineMacroExprPlugin;
+use cairo_lang_doc::db::DocGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_semantic::FunctionId;
+use cairo_lang_semantic::items::functions::{FunctionsSemantic, GenericFunctionId};
+use cairo_lang_syntax::node::ast::{
+    ArgClause, ExprFunctionCall, ExprInlineMacro, TerminalIdentifier,
+};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+use itertools::Itertools;
+use lsp_types::{
+    Documentation, MarkupContent, MarkupKind, ParameterInformation, ParameterLabel, SignatureHelp,
+    SignatureHelpParams, SignatureInformation,
+};
+
+use crate::lang::calls::{is_method_call, resolve_call_function};
+use crate::lang::db::{AnalysisDatabase, LsSyntaxGroup};
+use crate::lang::defs::{SymbolDef, SymbolSearch};
+use crate::lang::lsp::{LsProtoGroup, ToCairo};
+
+/// Get signature help for the call enclosing a given text document position.
+pub fn signature_help(params: SignatureHelpParams, db: &AnalysisDatabase) -> Option<SignatureHelp> {
+    let file = db.file_for_url(&params.text_document_position_params.text_document.uri)?;
+    let position = params.text_document_position_params.position.to_cairo();
+    let cursor = position.offset_in_file(db, file)?;
+    let node = db.find_syntax_node_at_position(file, position)?;
+
+    for node in node.ancestors_with_self(db) {
+        if let Some(call) = ExprFunctionCall::cast(db, node) {
+            let arguments = call.arguments(db);
+            if is_within_delimiters(
+                db,
+                arguments.lparen(db).as_syntax_node(),
+                arguments.rparen(db).as_syntax_node(),
+                cursor,
+            ) {
+                return function_call_signature_help(db, &call, cursor);
+            }
+        }
+
+        if let Some(macro_call) = ExprInlineMacro::cast(db, node) {
+            let arguments = macro_call.arguments(db).as_syntax_node();
+            if arguments.span_without_trivia(db).start < cursor
+                && cursor <= arguments.span_without_trivia(db).end
+            {
+                return inline_macro_signature_help(db, file, &macro_call, cursor);
+            }
+        }
+    }
+
+    None
+}
+
+/// Checks if the cursor is placed between the given delimiters.
+/// A missing closing delimiter, common while typing, is treated as extending to the cursor.
+fn is_within_delimiters<'db>(
+    db: &'db AnalysisDatabase,
+    open: SyntaxNode<'db>,
+    close: SyntaxNode<'db>,
+    cursor: TextOffset,
+) -> bool {
+    let open = open.span_without_trivia(db);
+    let close = close.span_without_trivia(db);
+    let is_close_missing = close.start == close.end;
+
+    open.end <= cursor && (is_close_missing || cursor <= close.start)
+}
+
+fn function_call_signature_help<'db>(
+    db: &'db AnalysisDatabase,
+    call: &ExprFunctionCall<'db>,
+    cursor: TextOffset,
+) -> Option<SignatureHelp> {
+    let function = resolve_call_function(db, call)?;
+    let signature = db.concrete_function_signature(function).ok()?;
+
+    let lookup_item = trait_function_lookup_item(db, function);
+    // Use the same rendering as hover, so that generic params and implicits are shown alike.
+    let label = db.get_item_signature(lookup_item.into())?;
+    let parameters = parameter_offsets(&label)
+        .into_iter()
+        .map(|offsets| ParameterInformation {
+            label: ParameterLabel::LabelOffsets(offsets),
+            documentation: None,
+        })
+        .collect();
+
+    let param_names = signature.params.iter().map(|param| param.name.to_string(db)).collect_vec();
+    // The receiver is not written in the argument list of a method call.
+    let skipped_params = usize::from(is_method_call(db, call) && !param_names.is_empty());
+
+    // Arguments not matching any parameter, like misspelled named ones, have no active parameter.
+    let active_parameter = active_argument(db, call, cursor)
+        .and_then(|argument| match argument {
+            ActiveArgument::Named(name) => {
+                param_names.iter().position(|param_name| *param_name == name)
+            }
+            ActiveArgument::Positional(index) => Some(index + skipped_params),
+        })
+        .filter(|&index| index < param_names.len());
+
+    Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label,
+            documentation: db
+                .get_item_documentation(lookup_item.into())
+                .map(markdown_documentation),
+            parameters: Some(parameters),
+            active_parameter: None,
+        }],
+        active_signature: Some(0),
+        active_parameter: active_parameter.and_then(|index| index.try_into().ok()),
+    })
+}
+
+fn inline_macro_signature_help<'db>(
+    db: &'db AnalysisDatabase,
+    file: FileId<'db>,
+    macro_call: &ExprInlineMacro<'db>,
+    cursor: TextOffset,
+) -> Option<SignatureHelp> {
+    let identifier = macro_call
+        .path(db)
+        .as_syntax_node()
+        .descendants(db)
+        .filter_map(|node| TerminalIdentifier::cast(db, node))
+        .last()?;
+
+    let (label, documentation) = match SymbolSearch::find_definition(db, &identifier)?.def {
+        SymbolDef::PluginInlineMacro(macro_name) => {
+            let crate_id = db.file_modules(file).ok()?.first()?.owning_crate(db);
+            let documentation = db
+                .crate_inline_macro_plugins(crate_id)
+                .get(macro_name.as_str())
+                .and_then(|&id| id.long(db).documentation());
+
+            (format!("{macro_name}!"), documentation)
+        }
+        SymbolDef::Item(item) => (item.signature(db), item.documentation(db)),
+        _ => return None,
+    };
+
+    let arguments = macro_call.arguments(db).as_syntax_node();
+
+    Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label,
+            documentation: documentation.map(markdown_documentation),
+            parameters: None,
+            active_parameter: None,
+        }],
+        active_signature: Some(0),
+        active_parameter: count_top_level_commas(db, arguments, cursor).try_into().ok(),
+    })
+}
+
+/// An argument of a function call the cursor is placed at.
+enum ActiveArgument {
+    Named(String),
+    Positional(usize),
+}
+
+fn active_argument<'db>(
+    db: &'db AnalysisDatabase,
+    call: &ExprFunctionCall<'db>,
+    cursor: TextOffset,
+) -> Option<ActiveArgument> {
+    let arg_list = call.arguments(db).arguments(db);
+
+    let index = arg_list
+        .as_syntax_node()
+        .get_children(db)
+        .iter()
+        .filter(|child| child.kind(db) == SyntaxKind::TerminalComma)
+        .take_while(|comma| comma.span_without_trivia(db).end <= cursor)
+        .count();
+
+    let named = arg_list.elements(db).nth(index).and_then(|arg| match arg.arg_clause(db) {
+        ArgClause::Named(named) => Some(named.name(db).text(db).to_string(db)),
+        ArgClause::FieldInitShorthand(shorthand) => {
+            Some(shorthand.name(db).name(db).text(db).to_string(db))
+        }
+        ArgClause::Unnamed(_) => None,
+    });
+
+    Some(named.map_or(ActiveArgument::Positional(index), ActiveArgument::Named))
+}
+
+/// Counts commas preceding the cursor that separate arguments of an inline macro.
+///
+/// Inline macro arguments are a token tree, so commas nested in parentheses, brackets or braces
+/// and commas inside string literals are skipped.
+fn count_top_level_commas<'db>(
+    db: &'db AnalysisDatabase,
+    arguments: SyntaxNode<'db>,
+    cursor: TextOffset,
+) -> usize {
+    let text = arguments.get_text(db);
+    let cursor = (cursor - arguments.span(db).start).as_u32() as usize;
+
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut commas = 0;
+    for ch in text.get(..cursor).unwrap_or(text).chars() {
+        match ch {
+            '"' | '\'' => in_string = !in_string,
+            _ if in_string => {}
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 1 => commas += 1,
+            _ => {}
+        }
+    }
+    commas
+}
+
+/// Finds the item declaring the signature of the called function.
+///
+/// Unlike [`crate::lang::calls::function_lookup_item`], calls of impl functions map to the trait
+/// function, so that the signature is rendered as declared by the trait.
+fn trait_function_lookup_item<'db>(
+    db: &'db AnalysisDatabase,
+    function: FunctionId<'db>,
+) -> LookupItemId<'db> {
+    match function.get_concrete(db).generic_function {
+        GenericFunctionId::Free(id) => LookupItemId::ModuleItem(ModuleItemId::FreeFunction(id)),
+        GenericFunctionId::Extern(id) => LookupItemId::ModuleItem(ModuleItemId::ExternFunction(id)),
+        GenericFunctionId::Impl(id) => LookupItemId::TraitItem(TraitItemId::Function(id.function)),
+    }
+}
+
+/// Finds the parameters in the parameter list of a function signature,
+/// returning their positions as UTF-16 offsets into it.
+///
+/// Generic params preceding the parameter list and nested delimiters in types are skipped.
+fn parameter_offsets(signature: &str) -> Vec<[u32; 2]> {
+    let mut offsets = vec![];
+    let mut push = |start: usize, end: usize| {
+        let text = &signature[start..end];
+        let trimmed = text.trim();
+        if !trimmed.is_empty() {
+            let start = start + (text.len() - text.trim_start().len());
+            let start = utf16_len(&signature[..start]);
+            offsets.push([start, start + utf16_len(trimmed)]);
+        }
+    };
+
+    let mut depth = 0usize;
+    let mut param_start = None;
+    let mut previous = None;
+    for (i, ch) in signature.char_indices() {
+        match ch {
+            '(' | '[' | '{' | '<' => {
+                if depth == 0 && ch == '(' {
+                    param_start = Some(i + 1);
+                }
+                depth += 1;
+            }
+            // An arrow of a closure type.
+            '>' if previous == Some('-') => {}
+            ')' | ']' | '}' | '>' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0
+                    && ch == ')'
+                    && let Some(start) = param_start
+                {
+                    push(start, i);
+                    break;
+                }
+            }
+            ',' if depth == 1 => {
+                if let Some(start) = param_start {
+                    push(start, i);
+                    param_start = Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+        previous = Some(ch);
+    }
+    offsets
+}
+
+fn markdown_documentation(value: String) -> Documentation {
+    Documentation::MarkupContent(MarkupContent { kind: MarkupKind::Markdown, value })
+}
+
+/// Returns the length of the text in UTF-16 code units, as LSP label offsets are expressed in.
+fn utf16_len(text: &str) -> u32 {
+    text.encode_utf16().count() as u32
+}
@@ -0,0 +1,91 @@
+use cairo_lang_defs::ids::{ImplItemId, LookupItemId, ModuleItemId, TraitItemId};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::{
+    FunctionWithBodySemantic, SemanticExprLookup,
+};
+use cairo_lang_semantic::items::functions::{FunctionsSemantic, GenericFunctionId};
+use cairo_lang_semantic::items::imp::ImplLongId;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_semantic::{Expr, FunctionId, Signature};
+use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_syntax::node::ast::{BinaryOperator, ExprBinary, ExprFunctionCall};
+use cairo_language_common::CommonGroup;
+
+use crate::lang::db::{AnalysisDatabase, LsSemanticGroup};
+
+/// Checks whether the syntax-level function call is a method call, i.e. `receiver.method()`.
+pub fn is_method_call<'db>(db: &'db AnalysisDatabase, call_syntax: &ExprFunctionCall<'db>) -> bool {
+    call_syntax
+        .as_syntax_node()
+        .parent(db)
+        .and_then(|parent| ExprBinary::cast(db, parent))
+        .is_some_and(|binary| matches!(binary.op(db), BinaryOperator::Dot(_)))
+}
+
+/// Resolves a syntax-level function call to the semantic [`FunctionId`] of its callee.
+///
+/// Calls written in macro arguments are resolved through the generated code.
+pub fn resolve_call_function<'db>(
+    db: &'db AnalysisDatabase,
+    call_syntax: &ExprFunctionCall<'db>,
+) -> Option<FunctionId<'db>> {
+    let is_method_call = is_method_call(db, call_syntax);
+
+    let semantic_db: &dyn SemanticGroup = db;
+
+    db.get_node_resultants(call_syntax.as_syntax_node())?.iter().find_map(|resultant| {
+        let resultant_call = ExprFunctionCall::cast(db, *resultant)?;
+        let lookup_item = db.find_lookup_item(resultant_call.as_syntax_node())?;
+        let function_with_body = lookup_item.function_with_body()?;
+
+        let stable_ptr = if is_method_call {
+            let parent = resultant_call.as_syntax_node().parent(db)?;
+            ExprBinary::cast(db, parent)?.stable_ptr(db).into()
+        } else {
+            resultant_call.stable_ptr(db).into()
+        };
+
+        let expr_id = db.lookup_expr_by_ptr(function_with_body, stable_ptr).ok()?;
+        let Expr::FunctionCall(func_call) = semantic_db.expr_semantic(function_with_body, expr_id)
+        else {
+            return None;
+        };
+
+        Some(func_call.function)
+    })
+}
+
+/// Resolves a syntax-level function call to its semantic [`Signature`].
+pub fn resolve_call_signature<'db>(
+    db: &'db AnalysisDatabase,
+    call_syntax: &ExprFunctionCall<'db>,
+) -> Option<Signature<'db>> {
+    let function = resolve_call_function(db, call_syntax)?;
+    db.concrete_function_signature(function).ok().cloned()
+}
+
+/// Finds the item defining the called function.
+///
+/// Calls of trait functions resolve to the implementing function when the impl is known.
+pub fn function_lookup_item<'db>(
+    db: &'db AnalysisDatabase,
+    function: FunctionId<'db>,
+) -> LookupItemId<'db> {
+    match function.get_concrete(db).generic_function {
+        GenericFunctionId::Free(id) => LookupItemId::ModuleItem(ModuleItemId::FreeFunction(id)),
+        GenericFunctionId::Extern(id) => LookupItemId::ModuleItem(ModuleItemId::ExternFunction(id)),
+        GenericFunctionId::Impl(id) => {
+            let impl_function = match id.impl_id.long(db) {
+                ImplLongId::Concrete(concrete_impl) => {
+                    concrete_impl.get_impl_function(db, id.function).ok().flatten()
+                }
+                _ => None,
+            };
+
+            match impl_function {
+                Some(impl_function) => LookupItemId::ImplItem(ImplItemId::Function(impl_function)),
+                None => LookupItemId::TraitItem(TraitItemId::Function(id.function)),
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{ModuleId, NamedLanguageElementId};
+use cairo_lang_filesystem::db::{CrateConfiguration, FilesGroup};
+use cairo_lang_filesystem::ids::Directory;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use lsp_types::{CreateFilesParams, FileCreate, Position, Range, TextEdit, Url, WorkspaceEdit};
+
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::identifiers::is_identifier;
+use crate::lang::lsp::LsProtoGroup;
+use crate::server::is_cairo_file_path;
+
+/// Declares newly created Cairo files as submodules of their parent modules.
+pub fn create_files(db: &AnalysisDatabase, params: CreateFilesParams) -> Option<WorkspaceEdit> {
+    let mut changes: HashMap<Url, Vec<TextEdit>> = Default::default();
+
+    for create in params.files {
+        handle_create(db, create, &mut changes);
+    }
+
+    Some(WorkspaceEdit { changes: Some(changes), ..Default::default() })
+}
+
+fn handle_create(
+    db: &AnalysisDatabase,
+    create: FileCreate,
+    changes: &mut HashMap<Url, Vec<TextEdit>>,
+) -> Option<()> {
+    let uri = Url::parse(&create.uri).ok()?;
+    if !is_cairo_file_path(&uri) {
+        return None;
+    }
+
+    let path = uri.to_file_path().ok()?;
+    let mod_name = path.file_stem()?.to_str()?;
+    if !is_identifier(db, mod_name) {
+        return None;
+    }
+
+    let parent = parent_module(db, &path)?;
+    let submodules = db.module_submodules_ids(parent).ok()?;
+    if submodules.iter().any(|submodule| submodule.name(db).to_string(db) == mod_name) {
+        return None;
+    }
+
+    let parent_file = db.module_main_file(parent).ok()?;
+
+    // Put the new declaration right after the last submodule declared in the parent file.
+    let line = submodules
+        .iter()
+        .map(|submodule| submodule.stable_ptr(db).lookup(db).as_syntax_node())
+        .filter(|node| node.stable_ptr(db).file_id(db) == parent_file)
+        .map(|node| node.span_without_trivia(db))
+        .max_by_key(|span| span.end)
+        .and_then(|span| span.position_in_file(db, parent_file))
+        .map_or(0, |position| position.end.line as u32 + 1);
+
+    let position = Position { line, character: 0 };
+    changes.entry(db.url_for_file(parent_file)?).or_default().push(TextEdit {
+        range: Range { start: position, end: position },
+        new_text: format!("mod {mod_name};\n"),
+    });
+
+    Some(())
+}
+
+/// Finds the module which should declare the file at the given path as its submodule.
+///
+/// Files placed directly in a crate root directory belong to the crate root,
+/// and files in `foo/` belong to the module defined in `foo.cairo`.
+fn parent_module<'db>(db: &'db AnalysisDatabase, path: &Path) -> Option<ModuleId<'db>> {
+    let dir = path.parent()?;
+
+    let crate_root = db.crates().iter().copied().find(|&crate_id| {
+        matches!(
+            db.crate_config(crate_id),
+            Some(CrateConfiguration { root: Directory::Real(root), .. }) if root == dir
+        )
+    });
+    if let Some(crate_id) = crate_root {
+        return Some(ModuleId::CrateRoot(crate_id));
+    }
+
+    let parent_file = db.file_for_url(&Url::from_file_path(dir.with_extension("cairo")).ok()?)?;
+    db.file_modules(parent_file)
+        .ok()?
+        .iter()
+        .copied()
+        .find(|module| matches!(module, ModuleId::Submodule(_)))
+}
@@ -59,6 +59,11 @@ impl<'db> ItemDef<'db> {
         })
     }
 
+    /// Gets the [`LookupItemId`] of the item.
+    pub fn lookup_item_id(&self) -> LookupItemId<'db> {
+        self.lookup_item_id
+    }
+
     /// Gets the stable pointer to the syntax node which defines this symbol.
     pub fn definition_stable_ptr(&self) -> SyntaxStablePtrId<'db> {
         self.definition_stable_ptr
@@ -1,7 +1,7 @@
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::{LanguageElementId, MacroCallId, ModuleId};
 use cairo_lang_filesystem::db::get_originating_location;
-use cairo_lang_filesystem::ids::SpanInFile;
+use cairo_lang_filesystem::ids::{CrateId, SpanInFile};
 use cairo_lang_semantic::diagnostic::{NotFoundItemType, SemanticDiagnostics};
 use cairo_lang_semantic::expr::inference::InferenceId;
 use cairo_lang_semantic::resolve::{
@@ -94,7 +94,15 @@ impl<'db> SymbolSearch<'db> {
         Self::from_resolved_item(db, resolved_item, resolver_data)
     }
 
-    fn from_resolved_item(
+    /// Creates a search result for the crate itself, i.e. its root module.
+    pub fn for_crate(db: &'db AnalysisDatabase, crate_id: CrateId<'db>) -> Option<Self> {
+        let resolved_item =
+            ResolvedItem::Generic(ResolvedGenericItem::Module(ModuleId::CrateRoot(crate_id)));
+        Self::from_resolved_item(db, resolved_item, None)
+    }
+
+    /// Creates a search result for an item resolved beforehand, e.g. by a semantic lookup.
+    pub fn from_resolved_item(
         db: &'db AnalysisDatabase,
         resolved_item: ResolvedItem<'db>,
         resolver_data: Option<ResolverData<'db>>,
@@ -1,5 +1,5 @@
 use cairo_lang_defs::ids::{ImportableId, VarId};
-use cairo_lang_semantic::{Binding, Mutability};
+use cairo_lang_semantic::{Binding, Mutability, TypeId};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::{SyntaxNode, Terminal, TypedStablePtr, TypedSyntaxNode, ast};
@@ -78,4 +78,23 @@ impl<'db> VariableDef<'db> {
     pub fn name(&self, db: &'db AnalysisDatabase) -> String {
         self.identifier.text(db).to_string(db)
     }
+
+    /// Gets the semantic type of this variable.
+    pub fn ty(&self, db: &'db AnalysisDatabase) -> Option<TypeId<'db>> {
+        db.lookup_binding(self.var_id).map(|binding| binding.ty())
+    }
+
+    /// Checks if this variable is bound with `mut` or is a `ref` parameter.
+    pub fn is_mutable(&self, db: &'db AnalysisDatabase) -> bool {
+        match db.lookup_binding(self.var_id) {
+            Some(Binding::LocalVar(local)) => local.is_mut,
+            Some(Binding::Param(param)) => !matches!(param.mutability, Mutability::Immutable),
+            _ => false,
+        }
+    }
+
+    /// Checks if this is a constant defined inside a function body.
+    pub fn is_constant(&self, db: &'db AnalysisDatabase) -> bool {
+        matches!(db.lookup_binding(self.var_id), Some(Binding::LocalItem(_)))
+    }
 }
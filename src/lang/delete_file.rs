@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{ModuleId, NamedLanguageElementId};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextSpan, TextWidth};
+use cairo_lang_syntax::node::ast::TerminalIdentifier;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{TypedStablePtr, TypedSyntaxNode};
+use cairo_language_common::CommonGroup;
+use itertools::Itertools;
+use lsp_types::notification::ShowMessage;
+use lsp_types::{
+    DeleteFilesParams, FileDelete, MessageType, Position, Range, ShowMessageParams, TextEdit, Url,
+    WorkspaceEdit,
+};
+
+use crate::lang::db::AnalysisDatabase;
+use crate::lang::defs::SymbolSearch;
+use crate::lang::lsp::{LsProtoGroup, ToLsp};
+use crate::server::client::Notifier;
+use crate::server::is_cairo_file_path;
+
+/// Removes `mod` declarations of deleted Cairo files.
+///
+/// `use` statements which would be left dangling by the deletion are reported to the user.
+pub fn delete_files(
+    db: &AnalysisDatabase,
+    params: DeleteFilesParams,
+    notifier: &Notifier,
+) -> Option<WorkspaceEdit> {
+    let mut changes: HashMap<Url, Vec<TextEdit>> = Default::default();
+
+    for delete in params.files {
+        handle_delete(db, delete, &mut changes, notifier);
+    }
+
+    Some(WorkspaceEdit { changes: Some(changes), ..Default::default() })
+}
+
+fn handle_delete(
+    db: &AnalysisDatabase,
+    delete: FileDelete,
+    changes: &mut HashMap<Url, Vec<TextEdit>>,
+    notifier: &Notifier,
+) -> Option<()> {
+    let uri = Url::parse(&delete.uri).ok()?;
+    if !is_cairo_file_path(&uri) {
+        return None;
+    }
+
+    let file = db.file_for_url(&uri)?;
+
+    let submodule = match *db.file_modules(file).ok()?.first()? {
+        ModuleId::CrateRoot(_) | ModuleId::MacroCall { .. } => {
+            // There is no declaration of the crate root to remove.
+            return None;
+        }
+        ModuleId::Submodule(submodule) => submodule,
+    };
+
+    let dangling_uses = dangling_uses(db, file, &submodule.name_identifier(db));
+    if !dangling_uses.is_empty() {
+        notifier.notify::<ShowMessage>(ShowMessageParams {
+            typ: MessageType::WARNING,
+            message: format!(
+                "Deleting `{}` leaves dangling `use` statements:\n{}",
+                submodule.name(db).to_string(db),
+                dangling_uses.join("\n")
+            ),
+        });
+    }
+
+    // Remove the declaration, including its attributes, leaving other items on its lines intact.
+    let node = submodule.stable_ptr(db).lookup(db).as_syntax_node();
+    let declaration_file = node.stable_ptr(db).file_id(db);
+    let content = db.file_content(declaration_file)?;
+    let span = node.span_without_trivia(db);
+    let position = span.position_in_file(db, declaration_file)?;
+
+    let (start, end) = (span.start.as_u32() as usize, span.end.as_u32() as usize);
+    let line_start = content[..start].rfind('\n').map_or(0, |i| i + 1);
+    let rest_of_line = content[end..].split('\n').next().unwrap_or_default();
+
+    let range = if content[line_start..start].trim().is_empty() && rest_of_line.trim().is_empty() {
+        Range {
+            start: Position { line: position.start.line as u32, character: 0 },
+            end: Position { line: position.end.line as u32 + 1, character: 0 },
+        }
+    } else {
+        let whitespace = &rest_of_line[..rest_of_line.len() - rest_of_line.trim_start().len()];
+        TextSpan::new(span.start, span.end.add_width(TextWidth::from_str(whitespace)))
+            .position_in_file(db, declaration_file)?
+            .to_lsp()
+    };
+
+    changes
+        .entry(db.url_for_file(declaration_file)?)
+        .or_default()
+        .push(TextEdit { range, new_text: String::new() });
+
+    Some(())
+}
+
+/// Finds `use` statements outside the deleted file which refer to the deleted module,
+/// formatted as `path:line`.
+fn dangling_uses<'db>(
+    db: &'db AnalysisDatabase,
+    deleted_file: FileId<'db>,
+    mod_name: &TerminalIdentifier<'db>,
+) -> Vec<String> {
+    let Some(search) = SymbolSearch::find_definition(db, mod_name) else {
+        return vec![];
+    };
+
+    search
+        .usages(db)
+        .collect()
+        .into_iter()
+        .map(|usage| usage.location())
+        .filter(|location| location.file_id != deleted_file)
+        .filter(|location| {
+            db.find_syntax_node_at_offset(location.file_id, location.span.start)
+                .and_then(|node| node.ancestor_of_kind(db, SyntaxKind::ItemUse))
+                .is_some()
+        })
+        .filter_map(|location| db.lsp_location(location))
+        .map(|location| {
+            let path = location
+                .uri
+                .to_file_path()
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|_| location.uri.to_string());
+            format!("{path}:{}", location.range.start.line + 1)
+        })
+        .unique()
+        .collect()
+}
@@ -3,7 +3,8 @@ use std::num::NonZero;
 
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_filesystem::db::FilesGroup;
-use cairo_lang_filesystem::ids::{FileId, FileLongId};
+use cairo_lang_filesystem::ids::{CrateId, FileId, FileLongId};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use lsp_types::Url;
 
 use crate::lang::db::AnalysisDatabase;
@@ -36,15 +37,23 @@ pub fn find_secondary_files<'db>(
 ) -> Vec<FileId<'db>> {
     let mut result = HashSet::new();
     for crate_id in db.crates() {
-        for module_id in db.crate_modules(*crate_id).iter() {
-            // Schedule only on disk module main files for refreshing.
-            // All other related files will be refreshed along with it in a single job.
-            if let Ok(file) = db.module_main_file(*module_id)
-                && matches!(file.long(db), FileLongId::OnDisk(_))
-                && !primary_files.contains(&file)
-            {
-                result.insert(file);
-            }
+        result.extend(
+            crate_files(db, *crate_id).into_iter().filter(|file| !primary_files.contains(file)),
+        );
+    }
+    result.into_iter().collect()
+}
+
+/// Finds all analyzable on disk files of a crate.
+pub fn crate_files<'db>(db: &'db AnalysisDatabase, crate_id: CrateId<'db>) -> Vec<FileId<'db>> {
+    let mut result = OrderedHashSet::default();
+    for module_id in db.crate_modules(crate_id).iter() {
+        // Schedule only on disk module main files for refreshing.
+        // All other related files will be refreshed along with it in a single job.
+        if let Ok(file) = db.module_main_file(*module_id)
+            && matches!(file.long(db), FileLongId::OnDisk(_))
+        {
+            result.insert(file);
         }
     }
     result.into_iter().collect()
@@ -12,6 +12,7 @@ use salsa::plumbing::current_revision;
 use tracing::{error, trace};
 
 use self::project_diagnostics::ProjectDiagnostics;
+pub use self::pull::{document_diagnostic, workspace_diagnostic};
 use self::refresh::{clear_old_diagnostics, refresh_diagnostics};
 use crate::config::Config;
 use crate::ide::analysis_progress::AnalysisProgressController;
@@ -33,6 +34,7 @@ mod file_batches;
 mod file_diagnostics;
 mod lsp;
 mod project_diagnostics;
+mod pull;
 mod refresh;
 
 type ScarbManifestDiagnostics = HashMap<Url, HashMap<Url, Vec<Diagnostic>>>;
@@ -67,10 +69,14 @@ impl Drop for DiagnosticsController {
 
 impl DiagnosticsController {
     /// Creates a new diagnostics controller.
+    ///
+    /// If `pull_diagnostics` is set, the client requests diagnostics on its own, and the controller
+    /// only drives the analysis without publishing its results.
     pub fn new(
         notifier: Notifier,
         analysis_progress_tracker: AnalysisProgressController,
         scarb_toolchain: ScarbToolchain,
+        pull_diagnostics: bool,
     ) -> Self {
         let (generate_code_complete_sender, generate_code_complete_receiver) =
             crossbeam::channel::bounded(1);
@@ -83,6 +89,7 @@ impl DiagnosticsController {
             analysis_progress_tracker,
             active_diagnostics_db.clone(),
             scarb_toolchain,
+            pull_diagnostics,
         );
         Self {
             trigger,
@@ -245,6 +252,7 @@ struct DiagnosticsControllerThread {
     active_diagnostics_db: Arc<Mutex<Option<AnalysisDatabase>>>,
     worker_handles: Vec<TaskHandle>,
     scarb_toolchain: ScarbToolchain,
+    pull_diagnostics: bool,
 }
 
 impl DiagnosticsControllerThread {
@@ -257,6 +265,7 @@ impl DiagnosticsControllerThread {
         analysis_progress_controller: AnalysisProgressController,
         active_diagnostics_db: Arc<Mutex<Option<AnalysisDatabase>>>,
         scarb_toolchain: ScarbToolchain,
+        pull_diagnostics: bool,
     ) -> (JoinHandle, NonZero<usize>) {
         let mut this = Self {
             receiver,
@@ -270,6 +279,7 @@ impl DiagnosticsControllerThread {
             active_diagnostics_db,
             worker_handles: Vec::new(),
             scarb_toolchain,
+            pull_diagnostics,
         };
 
         let parallelism = this.pool.parallelism();
@@ -319,6 +329,11 @@ impl DiagnosticsControllerThread {
         // This is true because `find_primary_files`/`find_secondary_files` calls `db.file_modules()` and it does all generate_code() calls.
         let _ = self.generate_code_complete_sender.send(());
 
+        // Clients pulling diagnostics compute them on demand.
+        if self.pull_diagnostics {
+            return;
+        }
+
         let primary: Vec<_> = primary_set.iter().copied().collect();
         self.spawn_refresh_workers(&primary, input);
         self.spawn_refresh_workers(&secondary, input);
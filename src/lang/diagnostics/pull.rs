@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use cairo_lang_filesystem::db::FilesGroup;
+use lsp_types::notification::Notification;
+use lsp_types::{
+    Diagnostic, DocumentDiagnosticParams, DocumentDiagnosticReport, DocumentDiagnosticReportKind,
+    DocumentDiagnosticReportResult, FullDocumentDiagnosticReport, ProgressToken,
+    RelatedFullDocumentDiagnosticReport, RelatedUnchangedDocumentDiagnosticReport,
+    UnchangedDocumentDiagnosticReport, Url, WorkspaceDiagnosticParams, WorkspaceDiagnosticReport,
+    WorkspaceDiagnosticReportPartialResult, WorkspaceDiagnosticReportResult,
+    WorkspaceDocumentDiagnosticReport, WorkspaceFullDocumentDiagnosticReport,
+    WorkspaceUnchangedDocumentDiagnosticReport,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::lang::diagnostics::file_batches::crate_files;
+use crate::lang::diagnostics::refresh::collect_file_diagnostics;
+use crate::lang::lsp::LsProtoGroup;
+use crate::server::client::Notifier;
+use crate::state::StateSnapshot;
+
+/// `$/progress` notification carrying a partial result of a `workspace/diagnostic` request.
+struct WorkspaceDiagnosticPartialResult;
+
+#[derive(Serialize, Deserialize)]
+struct WorkspaceDiagnosticPartialResultParams {
+    token: ProgressToken,
+    value: WorkspaceDiagnosticReportPartialResult,
+}
+
+impl Notification for WorkspaceDiagnosticPartialResult {
+    type Params = WorkspaceDiagnosticPartialResultParams;
+    const METHOD: &'static str = "$/progress";
+}
+
+/// Computes diagnostics of a single document for the `textDocument/diagnostic` request.
+///
+/// Diagnostics of virtual files originating from the document are reported as related documents.
+pub fn document_diagnostic(
+    params: DocumentDiagnosticParams,
+    snapshot: &StateSnapshot,
+) -> DocumentDiagnosticReportResult {
+    let db = &snapshot.db;
+    let uri = params.text_document.uri;
+
+    let mut diagnostics = db
+        .file_for_url(&uri)
+        .and_then(|file| {
+            collect_file_diagnostics(
+                db,
+                &snapshot.config,
+                &snapshot.configs_registry,
+                &snapshot.scarb_toolchain,
+                file,
+            )
+        })
+        .map(|(_, diagnostics)| diagnostics)
+        .unwrap_or_default();
+
+    let items = diagnostics.remove(&uri).unwrap_or_default();
+    let related_documents = (!diagnostics.is_empty()).then(|| {
+        diagnostics
+            .into_iter()
+            .map(|(url, items)| (url, report_kind(items, None)))
+            .collect::<HashMap<_, _>>()
+    });
+
+    let report = match report_kind(items, params.previous_result_id.as_deref()) {
+        DocumentDiagnosticReportKind::Full(full_document_diagnostic_report) => {
+            DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
+                related_documents,
+                full_document_diagnostic_report,
+            })
+        }
+        DocumentDiagnosticReportKind::Unchanged(unchanged_document_diagnostic_report) => {
+            DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+                related_documents,
+                unchanged_document_diagnostic_report,
+            })
+        }
+    };
+
+    DocumentDiagnosticReportResult::Report(report)
+}
+
+/// Computes diagnostics of all files in the project for the `workspace/diagnostic` request.
+///
+/// If the client provided a partial result token, reports are streamed crate by crate
+/// and the final response is empty.
+pub fn workspace_diagnostic(
+    params: WorkspaceDiagnosticParams,
+    snapshot: &StateSnapshot,
+    notifier: &Notifier,
+) -> WorkspaceDiagnosticReportResult {
+    let db = &snapshot.db;
+    let previous_result_ids: HashMap<Url, String> =
+        params.previous_result_ids.into_iter().map(|id| (id.uri, id.value)).collect();
+    let partial_result_token = params.partial_result_params.partial_result_token;
+
+    let mut items = vec![];
+    for crate_id in db.crates() {
+        let crate_items: Vec<_> = crate_files(db, *crate_id)
+            .into_iter()
+            .filter_map(|file| {
+                collect_file_diagnostics(
+                    db,
+                    &snapshot.config,
+                    &snapshot.configs_registry,
+                    &snapshot.scarb_toolchain,
+                    file,
+                )
+            })
+            .flat_map(|(_, diagnostics)| diagnostics)
+            .map(|(uri, diagnostics)| {
+                let previous_result_id = previous_result_ids.get(&uri).map(String::as_str);
+                workspace_report(uri, report_kind(diagnostics, previous_result_id))
+            })
+            .collect();
+
+        match &partial_result_token {
+            Some(token) if !crate_items.is_empty() => {
+                notifier.notify::<WorkspaceDiagnosticPartialResult>(
+                    WorkspaceDiagnosticPartialResultParams {
+                        token: token.clone(),
+                        value: WorkspaceDiagnosticReportPartialResult { items: crate_items },
+                    },
+                );
+            }
+            Some(_) => {}
+            None => items.extend(crate_items),
+        }
+    }
+
+    WorkspaceDiagnosticReportResult::Report(WorkspaceDiagnosticReport { items })
+}
+
+/// Builds a report for a file, which is [`DocumentDiagnosticReportKind::Unchanged`]
+/// if diagnostics match the ones identified by `previous_result_id`.
+fn report_kind(
+    items: Vec<Diagnostic>,
+    previous_result_id: Option<&str>,
+) -> DocumentDiagnosticReportKind {
+    let result_id = result_id(&items);
+    if previous_result_id == Some(result_id.as_str()) {
+        DocumentDiagnosticReportKind::Unchanged(UnchangedDocumentDiagnosticReport { result_id })
+    } else {
+        DocumentDiagnosticReportKind::Full(FullDocumentDiagnosticReport {
+            result_id: Some(result_id),
+            items,
+        })
+    }
+}
+
+fn workspace_report(
+    uri: Url,
+    kind: DocumentDiagnosticReportKind,
+) -> WorkspaceDocumentDiagnosticReport {
+    match kind {
+        DocumentDiagnosticReportKind::Full(full_document_diagnostic_report) => {
+            WorkspaceDocumentDiagnosticReport::Full(WorkspaceFullDocumentDiagnosticReport {
+                uri,
+                version: None,
+                full_document_diagnostic_report,
+            })
+        }
+        DocumentDiagnosticReportKind::Unchanged(unchanged_document_diagnostic_report) => {
+            WorkspaceDocumentDiagnosticReport::Unchanged(
+                WorkspaceUnchangedDocumentDiagnosticReport {
+                    uri,
+                    version: None,
+                    unchanged_document_diagnostic_report,
+                },
+            )
+        }
+    }
+}
+
+/// Identifies a set of diagnostics by their content, so that unchanged results
+/// are recognized across database revisions.
+fn result_id(diagnostics: &[Diagnostic]) -> String {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_string(diagnostics).unwrap_or_default().hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
@@ -1,10 +1,10 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 
 use cairo_lang_filesystem::db::{FilesGroup, ext_as_virtual};
 use cairo_lang_filesystem::ids::{FileId, FileLongId};
 use lsp_types::notification::PublishDiagnostics;
-use lsp_types::{DiagnosticSeverity, PublishDiagnosticsParams, Url};
+use lsp_types::{Diagnostic, DiagnosticSeverity, PublishDiagnosticsParams, Url};
 
 use crate::config::Config;
 use crate::lang::db::AnalysisDatabase;
@@ -54,14 +54,37 @@ fn refresh_file_diagnostics<'db>(
     notifier: &Notifier,
     scarb_toolchain: &ScarbToolchain,
 ) {
-    let Some(new_files_diagnostics) =
-        FilesDiagnostics::collect(db, config, config_registry, scarb_toolchain, root_on_disk_file)
+    // IMPORTANT: DO NOT change the order of operations here. Collecting may panic, so it has to
+    // come before `update`. It is to make sure that if `update` succeeds, `notify` executes as well.
+    let Some((root_on_disk_file_url, new_diags)) =
+        collect_file_diagnostics(db, config, config_registry, scarb_toolchain, root_on_disk_file)
     else {
         return;
     };
 
-    // IMPORTANT: DO NOT change the order of operations here. `to_lsp` may panic, so it has to come
-    // before `update`. It is to make sure that if `update` succeeds, `notify` executes as well.
+    let diags_to_send = project_diagnostics.update(root_on_disk_file_url, new_diags);
+    for (url, diagnostics) in diags_to_send {
+        notifier.notify::<PublishDiagnostics>(PublishDiagnosticsParams {
+            uri: url,
+            diagnostics,
+            version: None,
+        });
+    }
+}
+
+/// Collects diagnostics of an on disk file and virtual files originating from it, mapped by URL.
+///
+/// Returns the URL of the on disk file along with the diagnostics.
+pub fn collect_file_diagnostics<'db>(
+    db: &'db AnalysisDatabase,
+    config: &Config,
+    config_registry: &ConfigsRegistry,
+    scarb_toolchain: &ScarbToolchain,
+    root_on_disk_file: FileId<'db>,
+) -> Option<(Url, HashMap<Url, Vec<Diagnostic>>)> {
+    let new_files_diagnostics =
+        FilesDiagnostics::collect(db, config, config_registry, scarb_toolchain, root_on_disk_file)?;
+
     let (root_on_disk_file_url, new_diags) =
         new_files_diagnostics.to_lsp(db, config.trace_macro_diagnostics);
 
@@ -88,14 +111,7 @@ fn refresh_file_diagnostics<'db>(
         })
         .collect();
 
-    let diags_to_send = project_diagnostics.update(root_on_disk_file_url, new_diags);
-    for (url, diagnostics) in diags_to_send {
-        notifier.notify::<PublishDiagnostics>(PublishDiagnosticsParams {
-            uri: url,
-            diagnostics,
-            version: None,
-        });
-    }
+    Some((root_on_disk_file_url, new_diags))
 }
 
 /// For an on disk file - returns a path to it.
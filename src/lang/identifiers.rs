@@ -0,0 +1,26 @@
+use cairo_lang_parser::lexer::Lexer;
+use cairo_lang_semantic::keyword::{CRATE_KW, SELF_PARAM_KW, SELF_TYPE_KW, SUPER_KW};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use salsa::Database;
+
+/// Checks if the text is a single identifier that can name a symbol.
+///
+/// The text is lexed by the parser, so keywords are rejected as they are not lexed as
+/// identifiers. Keywords usable only in paths are lexed as identifiers, so they are rejected
+/// explicitly.
+pub fn is_identifier(db: &dyn Database, text: &str) -> bool {
+    if [CRATE_KW, SELF_PARAM_KW, SELF_TYPE_KW, SUPER_KW].contains(&text) {
+        return false;
+    }
+
+    let mut terminals = Lexer::from_text(db, text);
+    match (terminals.next(), terminals.next()) {
+        (Some(identifier), Some(end)) => {
+            identifier.kind == SyntaxKind::TerminalIdentifier
+                && identifier.leading_trivia.is_empty()
+                && identifier.trailing_trivia.is_empty()
+                && end.kind == SyntaxKind::TerminalEndOfFile
+        }
+        _ => false,
+    }
+}
@@ -20,9 +20,18 @@ pub fn import_edit_for_trait_if_needed<'db>(
     ctx: &AnalysisContext<'db>,
     trait_id: cairo_lang_defs::ids::TraitId<'db>,
 ) -> Option<TextEdit> {
+    new_import_edit(db, ctx, trait_import_path_if_needed(db, ctx, trait_id)?)
+}
+
+/// Returns a path to import the given trait with if it is not already in scope.
+pub fn trait_import_path_if_needed<'db>(
+    db: &'db AnalysisDatabase,
+    ctx: &AnalysisContext<'db>,
+    trait_id: cairo_lang_defs::ids::TraitId<'db>,
+) -> Option<String> {
     let trait_path = db.visible_traits_from_module(ctx.module_id)?.get(&trait_id)?.clone();
     // If the path contains '::', it means it is not currently in scope and needs an import.
-    if trait_path.contains("::") { new_import_edit(db, ctx, trait_path) } else { None }
+    trait_path.contains("::").then_some(trait_path)
 }
 
 pub fn new_import_edit<'db>(
@@ -30,7 +39,7 @@ pub fn new_import_edit<'db>(
     ctx: &AnalysisContext<'db>,
     import_path: impl Display,
 ) -> Option<TextEdit> {
-    let use_position = use_position(db, ctx)?;
+    let use_position = use_position(db, ctx.module_id)?;
 
     let mut new_text = format!("use {import_path};\n");
 
@@ -41,11 +50,16 @@ pub fn new_import_edit<'db>(
     Some(TextEdit { range: use_position.range(), new_text })
 }
 
-fn use_position<'db>(db: &'db AnalysisDatabase, ctx: &AnalysisContext<'db>) -> Option<UsePosition> {
-    db.module_uses_ids(ctx.module_id)
+/// Finds the position where `use` items of the module belong: the first existing `use` item
+/// or, if there are none, the first item of the module.
+pub fn use_position<'db>(
+    db: &'db AnalysisDatabase,
+    module_id: ModuleId<'db>,
+) -> Option<UsePosition> {
+    db.module_uses_ids(module_id)
         .ok()
         .and_then(|uses| {
-            let module_main_file = db.module_main_file(ctx.module_id).ok()?;
+            let module_main_file = db.module_main_file(module_id).ok()?;
 
             uses.iter()
                 .find(|use_statement| {
@@ -66,7 +80,7 @@ fn use_position<'db>(db: &'db AnalysisDatabase, ctx: &AnalysisContext<'db>) -> O
                 .map(|stable_ptr| UsePosition::new(db, stable_ptr, true))
                 .map(Some)
                 .unwrap_or_else(|| {
-                    ctx.module_id
+                    module_id
                         .module_data(db)
                         .ok()?
                         .items(db)
@@ -75,15 +89,15 @@ fn use_position<'db>(db: &'db AnalysisDatabase, ctx: &AnalysisContext<'db>) -> O
                 })
         })
         .unwrap_or_else(|| {
-            let ModuleId::Submodule(submodule) = ctx.module_id else { unreachable!() };
+            let ModuleId::Submodule(submodule) = module_id else { unreachable!() };
 
             UsePosition::new(db, submodule.untyped_stable_ptr(db), false)
         })
 }
 
 #[derive(Debug)]
-struct UsePosition {
-    position: Position,
+pub struct UsePosition {
+    pub position: Position,
     pub is_sticking: bool,
 }
 
@@ -1,7 +1,8 @@
 use std::num::NonZeroU32;
 
-use cairo_lang_filesystem::db::ext_as_virtual;
+use cairo_lang_filesystem::db::{ext_as_virtual, get_originating_location};
 use cairo_lang_filesystem::ids::{FileId, FileLongId, SpanInFile};
+use cairo_lang_syntax::node::SyntaxNode;
 use cairo_lang_utils::Intern;
 use lsp_types::{Location, Url};
 use salsa::{Database, Id};
@@ -68,6 +69,19 @@ pub trait LsProtoGroup: Database {
         let location = Location { uri: found_uri, range };
         Some(location)
     }
+
+    /// Gets the [`Location`] of user code the node originates from, skipping macro expansions.
+    fn originating_location<'db>(&'db self, node: SyntaxNode<'db>) -> Option<Location> {
+        let db = self.as_dyn_database();
+        self.lsp_location(get_originating_location(
+            db,
+            SpanInFile {
+                file_id: node.stable_ptr(db).file_id(db),
+                span: node.span_without_trivia(db),
+            },
+            None,
+        ))
+    }
 }
 
 impl<T: Database + ?Sized> LsProtoGroup for T {}
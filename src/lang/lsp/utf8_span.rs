@@ -3,11 +3,15 @@ use std::ops::Range as StdRange;
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_filesystem::span::{TextOffset, TextSpan, TextWidth};
-use lsp_types::Range;
+use lsp_types::{Position, Range};
 
 use crate::lang::db::AnalysisDatabase;
 use crate::lang::lsp::ToLsp;
 
+#[cfg(test)]
+#[path = "utf8_span_test.rs"]
+mod test;
+
 /// A span expressed as UTF-8 byte offsets within a file.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Utf8Span {
@@ -32,6 +36,40 @@ impl Utf8Span {
 
         span.position_in_file(db, file_id).map(|span| span.to_lsp())
     }
+
+    /// Converts an LSP range, with columns counted in UTF-16 code units, into byte offsets
+    /// within the text.
+    ///
+    /// Positions past the end of a line or the text are clamped to it, as the LSP spec requires.
+    pub fn from_lsp_range(text: &str, range: Range) -> Self {
+        let start = utf8_offset(text, range.start);
+        let end = utf8_offset(text, range.end).max(start);
+        Self::new(start, end)
+    }
+}
+
+fn utf8_offset(text: &str, position: Position) -> usize {
+    let Some(line_start) = line_start(text, position.line as usize) else {
+        return text.len();
+    };
+    let line = &text[line_start..];
+    let line = &line[..line.find(['\r', '\n']).unwrap_or(line.len())];
+
+    let mut utf16_column = 0;
+    for (offset, ch) in line.char_indices() {
+        if utf16_column >= position.character as usize {
+            return line_start + offset;
+        }
+        utf16_column += ch.len_utf16();
+    }
+    line_start + line.len()
+}
+
+fn line_start(text: &str, line: usize) -> Option<usize> {
+    if line == 0 {
+        return Some(0);
+    }
+    text.match_indices('\n').nth(line - 1).map(|(offset, _)| offset + 1)
 }
 
 impl From<StdRange<usize>> for Utf8Span {
@@ -0,0 +1,39 @@
+use lsp_types::{Position, Range};
+
+use crate::lang::lsp::Utf8Span;
+
+fn range(start: (u32, u32), end: (u32, u32)) -> Range {
+    Range {
+        start: Position { line: start.0, character: start.1 },
+        end: Position { line: end.0, character: end.1 },
+    }
+}
+
+#[test]
+fn ascii_range() {
+    let text = "fn main() {\n    let x = 1;\n}\n";
+    assert_eq!(Utf8Span::from_lsp_range(text, range((1, 8), (1, 9))), Utf8Span::new(20, 21));
+}
+
+#[test]
+fn multi_byte_characters() {
+    // `ą` takes two bytes in UTF-8 and one code unit in UTF-16,
+    // `🦀` takes four bytes in UTF-8 and two code units in UTF-16.
+    let text = "// ą🦀x\nlet y = 2;";
+    assert_eq!(Utf8Span::from_lsp_range(text, range((0, 3), (0, 4))), Utf8Span::new(3, 5));
+    assert_eq!(Utf8Span::from_lsp_range(text, range((0, 4), (0, 6))), Utf8Span::new(5, 9));
+    assert_eq!(Utf8Span::from_lsp_range(text, range((0, 6), (1, 3))), Utf8Span::new(9, 14));
+}
+
+#[test]
+fn crlf_line_endings() {
+    let text = "let x = 1;\r\nlet y = 2;\r\n";
+    assert_eq!(Utf8Span::from_lsp_range(text, range((0, 10), (1, 0))), Utf8Span::new(10, 12));
+}
+
+#[test]
+fn positions_past_the_end_are_clamped() {
+    let text = "let x = 1;\nlet y = 2;";
+    assert_eq!(Utf8Span::from_lsp_range(text, range((0, 42), (0, 43))), Utf8Span::new(10, 10));
+    assert_eq!(Utf8Span::from_lsp_range(text, range((1, 4), (7, 0))), Utf8Span::new(15, 21));
+}
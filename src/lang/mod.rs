@@ -1,7 +1,11 @@
 pub mod analysis_context;
+pub mod calls;
+pub mod create_file;
 pub mod db;
 pub mod defs;
+pub mod delete_file;
 pub mod diagnostics;
+pub mod identifiers;
 pub mod importable;
 pub mod importer;
 pub mod lsp;
@@ -10,5 +14,6 @@ pub mod methods;
 pub mod proc_macros;
 pub mod rename_file;
 pub mod text_matching;
+pub mod types;
 pub mod usages;
 pub mod visibility;
@@ -0,0 +1,42 @@
+use cairo_lang_semantic::TypeId;
+use cairo_lang_semantic::items::function_with_body::{
+    FunctionWithBodySemantic, SemanticExprLookup,
+};
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_syntax::node::ast::{Expr, ExprInlineMacro};
+use cairo_lang_syntax::node::{SyntaxNode, TypedSyntaxNode};
+use itertools::Itertools;
+
+use crate::lang::db::{AnalysisDatabase, LsSemanticGroup};
+
+/// Finds the innermost expression containing the given node which has a semantic model and
+/// returns its syntax node together with its inferred type.
+pub fn innermost_expr_type<'db>(
+    db: &'db AnalysisDatabase,
+    node: SyntaxNode<'db>,
+) -> Option<(SyntaxNode<'db>, TypeId<'db>)> {
+    node.ancestors_with_self(db)
+        // Walk up to and including any enclosing inline macro. For nodes already in an expansion
+        // file there is no ExprInlineMacro ancestor; for nodes in the source file inside a macro
+        // argument token tree this prevents surfacing types of unrelated outer expressions.
+        .take_while_inclusive(|n| ExprInlineMacro::cast(db, *n).is_none())
+        .filter_map(|n| Expr::cast(db, n))
+        .find_map(|expr| {
+            let node = expr.as_syntax_node();
+            find_expr_type(db, node).map(|ty| (node, ty))
+        })
+}
+
+/// Finds the inferred type of the expression represented by the given syntax node.
+pub fn find_expr_type<'db>(
+    db: &'db AnalysisDatabase,
+    node: SyntaxNode<'db>,
+) -> Option<TypeId<'db>> {
+    let function_id = db
+        .collect_lookup_items_with_parent_files(node)
+        .and_then(|nodes| nodes.iter().find_map(|id| id.function_with_body()))?;
+    let expr = Expr::from_syntax_node(db, node);
+    db.lookup_expr_by_ptr(function_id, expr.stable_ptr(db))
+        .ok()
+        .map(|expr_id| db.expr_semantic(function_id, expr_id).ty())
+}
@@ -24,7 +24,7 @@ use cairo_lang_filesystem::ids::FileLongId;
 use crossbeam::channel::{self, Receiver, select_biased};
 use lsp_server::Message;
 use lsp_types::RegistrationParams;
-use lsp_types::request::SemanticTokensRefresh;
+use lsp_types::request::{SemanticTokensRefresh, WorkspaceDiagnosticRefresh};
 use mimalloc::MiMalloc;
 use tracing::{debug, error, info};
 
@@ -445,6 +445,14 @@ impl Backend {
         {
             error!("semantic tokens refresh failed: {err:#?}");
         }
+
+        if state.client_capabilities.diagnostic_pull_support()
+            && state.client_capabilities.workspace_diagnostic_refresh_support()
+            && let Err(err) =
+                requester.request::<WorkspaceDiagnosticRefresh>((), |_| Task::nothing())
+        {
+            error!("diagnostics refresh failed: {err:#?}");
+        }
     }
 
     fn register_mutation_in_swapper(
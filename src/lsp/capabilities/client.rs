@@ -22,6 +22,9 @@ pub trait ClientCapabilitiesExt {
     /// The client supports `workspace/codeLens/refresh` requests.
     fn workspace_code_lens_refresh_support(&self) -> bool;
 
+    /// The client supports `workspace/diagnostic/refresh` requests.
+    fn workspace_diagnostic_refresh_support(&self) -> bool;
+
     /// The client supports renaming files and directories as a part of `WorkspaceEdit` requests.
     fn workspace_edit_rename_resource_support(&self) -> bool;
 
@@ -31,9 +34,15 @@ pub trait ClientCapabilitiesExt {
     /// The client supports dynamic registration for completion capabilities.
     fn completion_dynamic_registration(&self) -> bool;
 
+    /// The client supports resolving additional text edits of completion items lazily.
+    fn completion_resolve_additional_text_edits_support(&self) -> bool;
+
     /// The client supports dynamic registration for execute command capabilities.
     fn execute_command_dynamic_registration(&self) -> bool;
 
+    /// The client supports dynamic registration for workspace symbol capabilities.
+    fn workspace_symbol_dynamic_registration(&self) -> bool;
+
     /// The client supports dynamic registration for semantic tokens capabilities.
     fn semantic_tokens_dynamic_registration(&self) -> bool;
 
@@ -55,18 +64,66 @@ pub trait ClientCapabilitiesExt {
     /// The client supports dynamic registration for rename provider capabilities.
     fn rename_provider_dynamic_registration(&self) -> bool;
 
+    /// The client supports `textDocument/prepareRename` requests.
+    fn rename_prepare_support(&self) -> bool;
+
     /// The client supports dynamic registration for document highlight provider capabilities.
     fn document_highlight_provider_dynamic_registration(&self) -> bool;
 
+    /// The client supports dynamic registration for document symbol provider capabilities.
+    fn document_symbol_provider_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for folding range provider capabilities.
+    fn folding_range_provider_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for selection range provider capabilities.
+    fn selection_range_provider_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for call hierarchy provider capabilities.
+    fn call_hierarchy_provider_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for type hierarchy provider capabilities.
+    fn type_hierarchy_provider_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for implementation provider capabilities.
+    fn implementation_provider_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for type definition provider capabilities.
+    fn type_definition_provider_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for declaration provider capabilities.
+    fn declaration_provider_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for range formatting capabilities.
+    fn range_formatting_dynamic_registration(&self) -> bool;
+
+    /// The client supports dynamic registration for on type formatting capabilities.
+    fn on_type_formatting_dynamic_registration(&self) -> bool;
+
     /// The client supports dynamic registration for code lens provider capabilities.
     fn code_lens_provider_dynamic_registration(&self) -> bool;
 
     /// The client supports sending `workspace/willRenameFiles` requests.
     fn workspace_will_rename_files_support(&self) -> bool;
 
+    /// The client supports sending `workspace/willCreateFiles` requests.
+    fn workspace_will_create_files_support(&self) -> bool;
+
+    /// The client supports sending `workspace/willDeleteFiles` requests.
+    fn workspace_will_delete_files_support(&self) -> bool;
+
     /// The client supports dynamic registration for inlay hint provider capabilities.
     fn text_document_inlay_hints_dynamic_registration(&self) -> bool;
 
+    /// The client supports dynamic registration for signature help provider capabilities.
+    fn signature_help_provider_dynamic_registration(&self) -> bool;
+
+    /// The client supports pulling diagnostics with `textDocument/diagnostic` requests.
+    fn diagnostic_pull_support(&self) -> bool;
+
+    /// The client supports dynamic registration for pull diagnostics capabilities.
+    fn diagnostic_dynamic_registration(&self) -> bool;
+
     /// The client supports [`crate::lsp::ext::ExecuteInTerminal`] notifications.
     fn execute_in_terminal_support(&self) -> bool;
 
@@ -95,6 +152,10 @@ impl ClientCapabilitiesExt for ClientCapabilities {
         try_or_default!(self.workspace.as_ref()?.code_lens.as_ref()?.refresh_support?)
     }
 
+    fn workspace_diagnostic_refresh_support(&self) -> bool {
+        try_or_default!(self.workspace.as_ref()?.diagnostic.as_ref()?.refresh_support?)
+    }
+
     fn workspace_edit_rename_resource_support(&self) -> bool {
         try_or_default! {
             self.workspace.as_ref()?.workspace_edit.as_ref()?
@@ -112,10 +173,21 @@ impl ClientCapabilitiesExt for ClientCapabilities {
         try_or_default!(self.text_document.as_ref()?.completion.as_ref()?.dynamic_registration?)
     }
 
+    fn completion_resolve_additional_text_edits_support(&self) -> bool {
+        try_or_default! {
+            self.text_document.as_ref()?.completion.as_ref()?.completion_item.as_ref()?
+            .resolve_support.as_ref()?.properties.iter().any(|p| p == "additionalTextEdits")
+        }
+    }
+
     fn execute_command_dynamic_registration(&self) -> bool {
         try_or_default!(self.workspace.as_ref()?.execute_command.as_ref()?.dynamic_registration?)
     }
 
+    fn workspace_symbol_dynamic_registration(&self) -> bool {
+        try_or_default!(self.workspace.as_ref()?.symbol.as_ref()?.dynamic_registration?)
+    }
+
     fn semantic_tokens_dynamic_registration(&self) -> bool {
         try_or_default!(
             self.text_document.as_ref()?.semantic_tokens.as_ref()?.dynamic_registration?
@@ -146,6 +218,10 @@ impl ClientCapabilitiesExt for ClientCapabilities {
         try_or_default!(self.text_document.as_ref()?.rename.as_ref()?.dynamic_registration?)
     }
 
+    fn rename_prepare_support(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.rename.as_ref()?.prepare_support?)
+    }
+
     fn document_highlight_provider_dynamic_registration(&self) -> bool {
         try_or_default!(
             self.text_document.as_ref()?.document_highlight.as_ref()?.dynamic_registration?
@@ -160,10 +236,80 @@ impl ClientCapabilitiesExt for ClientCapabilities {
         try_or_default!(self.workspace.as_ref()?.file_operations.as_ref()?.will_rename?)
     }
 
+    fn workspace_will_create_files_support(&self) -> bool {
+        try_or_default!(self.workspace.as_ref()?.file_operations.as_ref()?.will_create?)
+    }
+
+    fn workspace_will_delete_files_support(&self) -> bool {
+        try_or_default!(self.workspace.as_ref()?.file_operations.as_ref()?.will_delete?)
+    }
+
     fn text_document_inlay_hints_dynamic_registration(&self) -> bool {
         try_or_default!(self.text_document.as_ref()?.inlay_hint.as_ref()?.dynamic_registration?)
     }
 
+    fn document_symbol_provider_dynamic_registration(&self) -> bool {
+        try_or_default!(
+            self.text_document.as_ref()?.document_symbol.as_ref()?.dynamic_registration?
+        )
+    }
+
+    fn folding_range_provider_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.folding_range.as_ref()?.dynamic_registration?)
+    }
+
+    fn selection_range_provider_dynamic_registration(&self) -> bool {
+        try_or_default!(
+            self.text_document.as_ref()?.selection_range.as_ref()?.dynamic_registration?
+        )
+    }
+
+    fn call_hierarchy_provider_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.call_hierarchy.as_ref()?.dynamic_registration?)
+    }
+
+    fn type_hierarchy_provider_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.type_hierarchy.as_ref()?.dynamic_registration?)
+    }
+
+    fn implementation_provider_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.implementation.as_ref()?.dynamic_registration?)
+    }
+
+    fn type_definition_provider_dynamic_registration(&self) -> bool {
+        try_or_default!(
+            self.text_document.as_ref()?.type_definition.as_ref()?.dynamic_registration?
+        )
+    }
+
+    fn declaration_provider_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.declaration.as_ref()?.dynamic_registration?)
+    }
+
+    fn range_formatting_dynamic_registration(&self) -> bool {
+        try_or_default!(
+            self.text_document.as_ref()?.range_formatting.as_ref()?.dynamic_registration?
+        )
+    }
+
+    fn on_type_formatting_dynamic_registration(&self) -> bool {
+        try_or_default!(
+            self.text_document.as_ref()?.on_type_formatting.as_ref()?.dynamic_registration?
+        )
+    }
+
+    fn signature_help_provider_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.signature_help.as_ref()?.dynamic_registration?)
+    }
+
+    fn diagnostic_pull_support(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.diagnostic.is_some())
+    }
+
+    fn diagnostic_dynamic_registration(&self) -> bool {
+        try_or_default!(self.text_document.as_ref()?.diagnostic.as_ref()?.dynamic_registration?)
+    }
+
     fn execute_in_terminal_support(&self) -> bool {
         try_or_default!(
             serde_json::from_value::<ExperimentalCapabilities>(self.experimental.clone()?)
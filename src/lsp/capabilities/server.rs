@@ -19,32 +19,41 @@ use lsp_types::notification::{
     DidSaveTextDocument, Notification,
 };
 use lsp_types::request::{
-    CodeActionRequest, CodeLensRequest, Completion, DocumentHighlightRequest, ExecuteCommand,
-    Formatting, GotoDefinition, HoverRequest, InlayHintRequest, References, Rename, Request,
+    CallHierarchyPrepare, CodeActionRequest, CodeLensRequest, Completion,
+    DocumentDiagnosticRequest, DocumentHighlightRequest, DocumentSymbolRequest, ExecuteCommand,
+    FoldingRangeRequest, Formatting, GotoDeclaration, GotoDefinition, GotoImplementation,
+    GotoTypeDefinition, HoverRequest, InlayHintRequest, OnTypeFormatting, RangeFormatting,
+    References, Rename, Request, SelectionRangeRequest, SignatureHelpRequest, TypeHierarchyPrepare,
+    WorkspaceSymbolRequest,
 };
 use lsp_types::{
-    ClientCapabilities, CodeActionProviderCapability, CodeLensOptions, CompletionOptions,
-    CompletionRegistrationOptions, DefinitionOptions, DidChangeWatchedFilesRegistrationOptions,
-    DocumentFilter, DocumentHighlightOptions, ExecuteCommandOptions,
+    CallHierarchyServerCapability, ClientCapabilities, CodeActionProviderCapability,
+    CodeLensOptions, CompletionOptions, CompletionRegistrationOptions, DeclarationCapability,
+    DefinitionOptions, DiagnosticOptions, DiagnosticRegistrationOptions,
+    DiagnosticServerCapabilities, DidChangeWatchedFilesRegistrationOptions, DocumentFilter,
+    DocumentHighlightOptions, DocumentOnTypeFormattingOptions,
+    DocumentOnTypeFormattingRegistrationOptions, DocumentSymbolOptions, ExecuteCommandOptions,
     ExecuteCommandRegistrationOptions, FileOperationFilter, FileOperationPattern,
-    FileOperationPatternKind, FileOperationRegistrationOptions, FileSystemWatcher, GlobPattern,
-    HoverProviderCapability, HoverRegistrationOptions, InlayHintOptions,
-    InlayHintRegistrationOptions, OneOf, ReferencesOptions, Registration, RenameOptions,
-    SaveOptions, SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions,
-    SemanticTokensRegistrationOptions, ServerCapabilities, TextDocumentChangeRegistrationOptions,
-    TextDocumentRegistrationOptions, TextDocumentSaveRegistrationOptions,
-    TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions,
-    TextDocumentSyncSaveOptions, WorkspaceFileOperationsServerCapabilities,
-    WorkspaceServerCapabilities,
+    FileOperationPatternKind, FileOperationRegistrationOptions, FileSystemWatcher,
+    FoldingRangeProviderCapability, GlobPattern, HoverProviderCapability, HoverRegistrationOptions,
+    ImplementationProviderCapability, InlayHintOptions, InlayHintRegistrationOptions, OneOf,
+    ReferencesOptions, Registration, RenameOptions, SaveOptions, SelectionRangeProviderCapability,
+    SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions,
+    SemanticTokensRegistrationOptions, ServerCapabilities, SignatureHelpOptions,
+    TextDocumentChangeRegistrationOptions, TextDocumentRegistrationOptions,
+    TextDocumentSaveRegistrationOptions, TextDocumentSyncCapability, TextDocumentSyncKind,
+    TextDocumentSyncOptions, TextDocumentSyncSaveOptions, TypeDefinitionProviderCapability,
+    WorkspaceFileOperationsServerCapabilities, WorkspaceServerCapabilities, WorkspaceSymbolOptions,
 };
 use missing_lsp_types::{
     CodeActionRegistrationOptions, CodeLensRegistrationOptions, DefinitionRegistrationOptions,
     DocumentFormattingRegistrationOptions, DocumentHighlightRegistrationOptions,
-    ReferencesRegistrationOptions, RenameRegistrationOptions,
+    DocumentSymbolRegistrationOptions, ReferencesRegistrationOptions, RenameRegistrationOptions,
+    SignatureHelpRegistrationOptions,
 };
 use serde::Serialize;
 
-use crate::ide::semantic_highlighting::SemanticTokenKind;
+use crate::ide::semantic_highlighting::{SemanticTokenKind, SemanticTokenModifiers};
 use crate::lsp::capabilities::client::ClientCapabilitiesExt;
 use crate::lsp::ext::ViewSyntaxTree;
 use crate::server::commands::ServerCommand;
@@ -52,31 +61,9 @@ use crate::server::commands::ServerCommand;
 /// Returns capabilities the server wants to register statically.
 pub fn collect_server_capabilities(client_capabilities: &ClientCapabilities) -> ServerCapabilities {
     ServerCapabilities {
-        workspace: client_capabilities.workspace_will_rename_files_support().then(|| {
+        workspace: file_operations_options(client_capabilities).map(|file_operations| {
             WorkspaceServerCapabilities {
-                file_operations: Some(WorkspaceFileOperationsServerCapabilities {
-                    will_rename: Some(FileOperationRegistrationOptions {
-                        filters: vec![
-                            FileOperationFilter {
-                                scheme: Some(String::from("file")),
-                                pattern: FileOperationPattern {
-                                    glob: String::from("**/*.cairo"),
-                                    matches: Some(FileOperationPatternKind::File),
-                                    options: None,
-                                },
-                            },
-                            FileOperationFilter {
-                                scheme: Some(String::from("file")),
-                                pattern: FileOperationPattern {
-                                    glob: String::from("**"),
-                                    matches: Some(FileOperationPatternKind::Folder),
-                                    options: None,
-                                },
-                            },
-                        ],
-                    }),
-                    ..Default::default()
-                }),
+                file_operations: Some(file_operations),
                 ..Default::default()
             }
         }),
@@ -85,7 +72,7 @@ pub fn collect_server_capabilities(client_capabilities: &ClientCapabilities) ->
             .not()
             .then_some(TextDocumentSyncCapability::Options(TextDocumentSyncOptions {
                 open_close: Some(true),
-                change: Some(TextDocumentSyncKind::FULL),
+                change: Some(TextDocumentSyncKind::INCREMENTAL),
                 will_save: Some(false),
                 will_save_wait_until: Some(false),
                 save: Some(TextDocumentSyncSaveOptions::SaveOptions(SaveOptions {
@@ -94,7 +81,7 @@ pub fn collect_server_capabilities(client_capabilities: &ClientCapabilities) ->
             })),
         completion_provider: client_capabilities.completion_dynamic_registration().not().then(
             || CompletionOptions {
-                resolve_provider: Some(false),
+                resolve_provider: Some(true),
                 trigger_characters: Some(vec![".".to_string(), ":".to_string()]),
                 all_commit_characters: None,
                 work_done_progress_options: Default::default(),
@@ -111,6 +98,10 @@ pub fn collect_server_capabilities(client_capabilities: &ClientCapabilities) ->
                 ],
                 work_done_progress_options: Default::default(),
             }),
+        workspace_symbol_provider: client_capabilities
+            .workspace_symbol_dynamic_registration()
+            .not()
+            .then_some(OneOf::Left(true)),
         semantic_tokens_provider: client_capabilities
             .semantic_tokens_dynamic_registration()
             .not()
@@ -118,9 +109,10 @@ pub fn collect_server_capabilities(client_capabilities: &ClientCapabilities) ->
                 SemanticTokensOptions {
                     legend: SemanticTokensLegend {
                         token_types: SemanticTokenKind::legend(),
-                        token_modifiers: vec![],
+                        token_modifiers: SemanticTokenModifiers::legend(),
                     },
-                    full: Some(SemanticTokensFullOptions::Bool(true)),
+                    full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
+                    range: Some(true),
                     ..SemanticTokensOptions::default()
                 }
                 .into()
@@ -148,11 +140,47 @@ pub fn collect_server_capabilities(client_capabilities: &ClientCapabilities) ->
         rename_provider: client_capabilities
             .rename_provider_dynamic_registration()
             .not()
-            .then_some(OneOf::Left(true)),
+            .then(|| OneOf::Right(rename_options(client_capabilities))),
         document_highlight_provider: client_capabilities
             .document_highlight_provider_dynamic_registration()
             .not()
             .then_some(OneOf::Left(true)),
+        document_symbol_provider: client_capabilities
+            .document_symbol_provider_dynamic_registration()
+            .not()
+            .then_some(OneOf::Left(true)),
+        folding_range_provider: client_capabilities
+            .folding_range_provider_dynamic_registration()
+            .not()
+            .then_some(FoldingRangeProviderCapability::Simple(true)),
+        selection_range_provider: client_capabilities
+            .selection_range_provider_dynamic_registration()
+            .not()
+            .then_some(SelectionRangeProviderCapability::Simple(true)),
+        call_hierarchy_provider: client_capabilities
+            .call_hierarchy_provider_dynamic_registration()
+            .not()
+            .then_some(CallHierarchyServerCapability::Simple(true)),
+        implementation_provider: client_capabilities
+            .implementation_provider_dynamic_registration()
+            .not()
+            .then_some(ImplementationProviderCapability::Simple(true)),
+        type_definition_provider: client_capabilities
+            .type_definition_provider_dynamic_registration()
+            .not()
+            .then_some(TypeDefinitionProviderCapability::Simple(true)),
+        declaration_provider: client_capabilities
+            .declaration_provider_dynamic_registration()
+            .not()
+            .then_some(DeclarationCapability::Simple(true)),
+        document_range_formatting_provider: client_capabilities
+            .range_formatting_dynamic_registration()
+            .not()
+            .then_some(OneOf::Left(true)),
+        document_on_type_formatting_provider: client_capabilities
+            .on_type_formatting_dynamic_registration()
+            .not()
+            .then_some(on_type_formatting_options()),
         code_lens_provider: client_capabilities
             .code_lens_provider_dynamic_registration()
             .not()
@@ -161,6 +189,13 @@ pub fn collect_server_capabilities(client_capabilities: &ClientCapabilities) ->
             .text_document_inlay_hints_dynamic_registration()
             .not()
             .then_some(OneOf::Left(true)),
+        signature_help_provider: client_capabilities
+            .signature_help_provider_dynamic_registration()
+            .not()
+            .then(signature_help_options),
+        diagnostic_provider: (client_capabilities.diagnostic_pull_support()
+            && !client_capabilities.diagnostic_dynamic_registration())
+        .then(|| DiagnosticServerCapabilities::Options(diagnostic_options())),
         ..ServerCapabilities::default()
     }
 }
@@ -232,7 +267,7 @@ pub fn collect_dynamic_registrations(
             DidChangeTextDocument::METHOD,
             TextDocumentChangeRegistrationOptions {
                 document_selector: Some(cairo_files_filters.clone()),
-                sync_kind: 1, // TextDocumentSyncKind::FULL
+                sync_kind: 2, // TextDocumentSyncKind::INCREMENTAL
             },
         ));
 
@@ -254,7 +289,7 @@ pub fn collect_dynamic_registrations(
         let registration_options = CompletionRegistrationOptions {
             text_document_registration_options: text_document_registration_options.clone(),
             completion_options: CompletionOptions {
-                resolve_provider: Some(false),
+                resolve_provider: Some(true),
                 trigger_characters: Some(vec![".".to_string(), ":".to_string()]),
                 all_commit_characters: None,
                 work_done_progress_options: Default::default(),
@@ -283,15 +318,26 @@ pub fn collect_dynamic_registrations(
         registrations.push(create_registration(ExecuteCommand::METHOD, registration_options));
     }
 
+    if client_capabilities.workspace_symbol_dynamic_registration() {
+        registrations.push(create_registration(
+            WorkspaceSymbolRequest::METHOD,
+            WorkspaceSymbolOptions {
+                resolve_provider: Some(false),
+                work_done_progress_options: Default::default(),
+            },
+        ));
+    }
+
     if client_capabilities.semantic_tokens_dynamic_registration() {
         let registration_options = SemanticTokensRegistrationOptions {
             text_document_registration_options: text_document_registration_options.clone(),
             semantic_tokens_options: SemanticTokensOptions {
                 legend: SemanticTokensLegend {
                     token_types: SemanticTokenKind::legend(),
-                    token_modifiers: vec![],
+                    token_modifiers: SemanticTokenModifiers::legend(),
                 },
-                full: Some(SemanticTokensFullOptions::Bool(true)),
+                full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
+                range: Some(true),
                 ..SemanticTokensOptions::default()
             },
             static_registration_options: Default::default(),
@@ -365,10 +411,7 @@ pub fn collect_dynamic_registrations(
             Rename::METHOD,
             RenameRegistrationOptions {
                 text_document_registration_options: text_document_registration_options.clone(),
-                rename_options: RenameOptions {
-                    prepare_provider: Some(false),
-                    work_done_progress_options: Default::default(),
-                },
+                rename_options: rename_options(client_capabilities),
             },
         ));
     }
@@ -385,6 +428,90 @@ pub fn collect_dynamic_registrations(
         ));
     }
 
+    if client_capabilities.document_symbol_provider_dynamic_registration() {
+        registrations.push(create_registration(
+            DocumentSymbolRequest::METHOD,
+            DocumentSymbolRegistrationOptions {
+                text_document_registration_options: text_document_registration_options.clone(),
+                document_symbol_options: DocumentSymbolOptions {
+                    label: None,
+                    work_done_progress_options: Default::default(),
+                },
+            },
+        ));
+    }
+
+    if client_capabilities.folding_range_provider_dynamic_registration() {
+        registrations.push(create_registration(
+            FoldingRangeRequest::METHOD,
+            &text_document_registration_options,
+        ));
+    }
+
+    if client_capabilities.selection_range_provider_dynamic_registration() {
+        registrations.push(create_registration(
+            SelectionRangeRequest::METHOD,
+            &text_document_registration_options,
+        ));
+    }
+
+    if client_capabilities.call_hierarchy_provider_dynamic_registration() {
+        registrations.push(create_registration(
+            CallHierarchyPrepare::METHOD,
+            &text_document_registration_options,
+        ));
+    }
+
+    // `ServerCapabilities` from `lsp_types` lacks the `typeHierarchyProvider` field,
+    // so type hierarchy can only be provided to clients supporting dynamic registration.
+    if client_capabilities.type_hierarchy_provider_dynamic_registration() {
+        registrations.push(create_registration(
+            TypeHierarchyPrepare::METHOD,
+            &text_document_registration_options,
+        ));
+    }
+
+    if client_capabilities.implementation_provider_dynamic_registration() {
+        registrations.push(create_registration(
+            GotoImplementation::METHOD,
+            &text_document_registration_options,
+        ));
+    }
+
+    if client_capabilities.type_definition_provider_dynamic_registration() {
+        registrations.push(create_registration(
+            GotoTypeDefinition::METHOD,
+            &text_document_registration_options,
+        ));
+    }
+
+    if client_capabilities.declaration_provider_dynamic_registration() {
+        registrations.push(create_registration(
+            GotoDeclaration::METHOD,
+            &text_document_registration_options,
+        ));
+    }
+
+    if client_capabilities.range_formatting_dynamic_registration() {
+        registrations.push(create_registration(
+            RangeFormatting::METHOD,
+            &text_document_registration_options,
+        ));
+    }
+
+    if client_capabilities.on_type_formatting_dynamic_registration() {
+        let DocumentOnTypeFormattingOptions { first_trigger_character, more_trigger_character } =
+            on_type_formatting_options();
+        registrations.push(create_registration(
+            OnTypeFormatting::METHOD,
+            DocumentOnTypeFormattingRegistrationOptions {
+                text_document_registration_options: text_document_registration_options.clone(),
+                first_trigger_character,
+                more_trigger_character,
+            },
+        ));
+    }
+
     if client_capabilities.code_lens_provider_dynamic_registration() {
         registrations.push(create_registration(
             CodeLensRequest::METHOD,
@@ -409,11 +536,108 @@ pub fn collect_dynamic_registrations(
         ));
     }
 
+    if client_capabilities.signature_help_provider_dynamic_registration() {
+        registrations.push(create_registration(
+            SignatureHelpRequest::METHOD,
+            SignatureHelpRegistrationOptions {
+                text_document_registration_options: text_document_registration_options.clone(),
+                signature_help_options: signature_help_options(),
+            },
+        ));
+    }
+
+    if client_capabilities.diagnostic_pull_support()
+        && client_capabilities.diagnostic_dynamic_registration()
+    {
+        registrations.push(create_registration(
+            DocumentDiagnosticRequest::METHOD,
+            DiagnosticRegistrationOptions {
+                text_document_registration_options: text_document_registration_options.clone(),
+                diagnostic_options: diagnostic_options(),
+                static_registration_options: Default::default(),
+            },
+        ));
+    }
+
     registrations.push(create_registration(ViewSyntaxTree::METHOD, ()));
 
     registrations
 }
 
+/// File operations on Cairo files which the server wants to be notified about,
+/// or [`None`] if the client supports none of them.
+fn file_operations_options(
+    client_capabilities: &ClientCapabilities,
+) -> Option<WorkspaceFileOperationsServerCapabilities> {
+    let cairo_files = FileOperationFilter {
+        scheme: Some(String::from("file")),
+        pattern: FileOperationPattern {
+            glob: String::from("**/*.cairo"),
+            matches: Some(FileOperationPatternKind::File),
+            options: None,
+        },
+    };
+    let folders = FileOperationFilter {
+        scheme: Some(String::from("file")),
+        pattern: FileOperationPattern {
+            glob: String::from("**"),
+            matches: Some(FileOperationPatternKind::Folder),
+            options: None,
+        },
+    };
+
+    let will_rename = client_capabilities
+        .workspace_will_rename_files_support()
+        .then(|| FileOperationRegistrationOptions { filters: vec![cairo_files.clone(), folders] });
+    let will_create = client_capabilities
+        .workspace_will_create_files_support()
+        .then(|| FileOperationRegistrationOptions { filters: vec![cairo_files.clone()] });
+    let will_delete = client_capabilities
+        .workspace_will_delete_files_support()
+        .then(|| FileOperationRegistrationOptions { filters: vec![cairo_files] });
+
+    (will_rename.is_some() || will_create.is_some() || will_delete.is_some()).then_some(
+        WorkspaceFileOperationsServerCapabilities {
+            will_rename,
+            will_create,
+            will_delete,
+            ..Default::default()
+        },
+    )
+}
+
+fn signature_help_options() -> SignatureHelpOptions {
+    SignatureHelpOptions {
+        trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+        retrigger_characters: None,
+        work_done_progress_options: Default::default(),
+    }
+}
+
+fn on_type_formatting_options() -> DocumentOnTypeFormattingOptions {
+    DocumentOnTypeFormattingOptions {
+        first_trigger_character: "}".to_string(),
+        more_trigger_character: Some(vec![";".to_string(), "\n".to_string()]),
+    }
+}
+
+fn rename_options(client_capabilities: &ClientCapabilities) -> RenameOptions {
+    RenameOptions {
+        // The server may only advertise `prepareProvider` if the client supports it.
+        prepare_provider: Some(client_capabilities.rename_prepare_support()),
+        work_done_progress_options: Default::default(),
+    }
+}
+
+fn diagnostic_options() -> DiagnosticOptions {
+    DiagnosticOptions {
+        identifier: Some("cairo".to_string()),
+        inter_file_dependencies: true,
+        workspace_diagnostics: true,
+        work_done_progress_options: Default::default(),
+    }
+}
+
 fn create_registration(method: &str, registration_options: impl Serialize) -> Registration {
     Registration {
         id: method.to_string(),
@@ -425,8 +649,8 @@ fn create_registration(method: &str, registration_options: impl Serialize) -> Re
 mod missing_lsp_types {
     use lsp_types::{
         CodeActionOptions, CodeLensOptions, DefinitionOptions, DocumentFormattingOptions,
-        DocumentHighlightOptions, ReferencesOptions, RenameOptions,
-        TextDocumentRegistrationOptions,
+        DocumentHighlightOptions, DocumentSymbolOptions, ReferencesOptions, RenameOptions,
+        SignatureHelpOptions, TextDocumentRegistrationOptions,
     };
     use serde::{Deserialize, Serialize};
 
@@ -499,4 +723,24 @@ mod missing_lsp_types {
         #[serde(flatten)]
         pub code_lens_options: CodeLensOptions,
     }
+
+    #[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct SignatureHelpRegistrationOptions {
+        #[serde(flatten)]
+        pub text_document_registration_options: TextDocumentRegistrationOptions,
+
+        #[serde(flatten)]
+        pub signature_help_options: SignatureHelpOptions,
+    }
+
+    #[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DocumentSymbolRegistrationOptions {
+        #[serde(flatten)]
+        pub text_document_registration_options: TextDocumentRegistrationOptions,
+
+        #[serde(flatten)]
+        pub document_symbol_options: DocumentSymbolOptions,
+    }
 }
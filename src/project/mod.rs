@@ -128,12 +128,13 @@ impl ProjectController {
                 state
                     .proc_macro_controller
                     .request_defined_macros(db, workspace_manifest_path.clone());
-                state.project_controller.model.load_workspace(
+                let changed_crates = state.project_controller.model.load_workspace(
                     db,
                     crates,
                     workspace_dir,
                     &state.proc_macro_controller,
                 );
+                state.workspace_symbol_index.on_crates_loaded(changed_crates);
                 state.diagnostics_controller.publish_scarb_manifest_diagnostics(
                     &workspace_manifest_path,
                     diagnostics,
@@ -25,6 +25,11 @@ impl ConfigsRegistry {
         self.entry_for_file(path).map(|(_, config)| config.compiler_config_correct_for_debugging)
     }
 
+    /// Returns paths to manifests of all known packages.
+    pub fn manifest_paths(&self) -> impl Iterator<Item = &Path> {
+        self.packages_configs.keys().map(PathBuf::as_path)
+    }
+
     pub fn clear(&mut self) {
         self.packages_configs.clear();
     }
@@ -61,13 +61,16 @@ impl ProjectModel {
         self.remove_crates_from_db_on_next_update = true;
     }
 
+    /// Loads crates of the workspace into the database.
+    ///
+    /// Returns inputs of crates which were added or changed by this workspace.
     pub fn load_workspace(
         &mut self,
         db: &mut AnalysisDatabase,
         workspace_crates: Vec<CrateInfo>,
         workspace_dir: PathBuf,
         proc_macro_controller: &ProcMacroClientController,
-    ) {
+    ) -> Vec<CrateInput> {
         if self.remove_crates_from_db_on_next_update {
             self.remove_crates_from_db_on_next_update = false;
 
@@ -85,13 +88,17 @@ impl ProjectModel {
 
                 (cr_info.cr.input(), cr_info.cr)
             })
-            .collect();
+            .collect::<HashMap<_, _>>();
+
+        let mut changed_crates = workspace_crates.keys().cloned().collect::<Vec<_>>();
 
         if let Some(old_crates) = self.loaded_workspaces.get(&workspace_dir) {
             if old_crates == &workspace_crates {
-                return;
+                return vec![];
             }
 
+            changed_crates.retain(|input| old_crates.get(input) != workspace_crates.get(input));
+
             // Static because the borrow checker.
             ProjectModel::remove_crates(&mut self.loaded_crates, &workspace_dir, old_crates);
         };
@@ -99,6 +106,8 @@ impl ProjectModel {
         self.add_crates(workspace_crates, &workspace_dir);
 
         self.apply_changes_to_db(db, proc_macro_controller);
+
+        changed_crates
     }
 
     pub fn apply_changes_to_db(
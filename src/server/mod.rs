@@ -6,4 +6,4 @@ pub mod schedule;
 pub mod trigger;
 
 mod routing;
-pub use routing::{is_cairo_file_path, notification, request};
+pub use routing::{is_cairo_file_path, is_scarb_manifest, notification, request};
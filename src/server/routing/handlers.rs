@@ -11,29 +11,48 @@ use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::override_file_content;
 use lsp_types::notification::{
     DidChangeConfiguration, DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument,
-    DidOpenTextDocument, DidSaveTextDocument, Notification,
+    DidOpenTextDocument, DidSaveTextDocument, Notification, ShowMessage,
 };
 use lsp_types::request::{
-    CodeActionRequest, CodeLensRequest, Completion, DocumentHighlightRequest, ExecuteCommand,
-    Formatting, GotoDefinition, HoverRequest, InlayHintRequest, References, Rename, Request,
-    SemanticTokensFullRequest, WillRenameFiles,
+    CallHierarchyIncomingCalls, CallHierarchyOutgoingCalls, CallHierarchyPrepare,
+    CodeActionRequest, CodeLensRequest, Completion, DocumentDiagnosticRequest,
+    DocumentHighlightRequest, DocumentSymbolRequest, ExecuteCommand, FoldingRangeRequest,
+    Formatting, GotoDeclaration, GotoDeclarationParams, GotoDeclarationResponse, GotoDefinition,
+    GotoImplementation, GotoImplementationParams, GotoImplementationResponse, GotoTypeDefinition,
+    GotoTypeDefinitionParams, GotoTypeDefinitionResponse, HoverRequest, InlayHintRequest,
+    OnTypeFormatting, PrepareRenameRequest, RangeFormatting, References, Rename, Request,
+    ResolveCompletionItem, SelectionRangeRequest, SemanticTokensFullDeltaRequest,
+    SemanticTokensFullRequest, SemanticTokensRangeRequest, SignatureHelpRequest,
+    TypeHierarchyPrepare, TypeHierarchySubtypes, TypeHierarchySupertypes, WillCreateFiles,
+    WillDeleteFiles, WillRenameFiles, WorkspaceDiagnosticRequest, WorkspaceSymbolRequest,
 };
 use lsp_types::{
-    CodeActionParams, CodeActionResponse, CodeLens, CodeLensParams, CompletionParams,
-    CompletionResponse, DidChangeConfigurationParams, DidChangeTextDocumentParams,
-    DidChangeWatchedFilesParams, DidCloseTextDocumentParams, DidOpenTextDocumentParams,
-    DidSaveTextDocumentParams, DocumentFormattingParams, DocumentHighlight,
-    DocumentHighlightParams, ExecuteCommandParams, FileChangeType, GotoDefinitionParams,
-    GotoDefinitionResponse, Hover, HoverParams, InlayHint, InlayHintParams, ReferenceParams,
-    RenameFilesParams, RenameParams, SemanticTokensParams, SemanticTokensResult,
-    TextDocumentContentChangeEvent, TextDocumentPositionParams, TextEdit, Url, WorkspaceEdit,
+    CallHierarchyIncomingCall, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCall, CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams,
+    CodeActionParams, CodeActionResponse, CodeLens, CodeLensParams, CompletionItem,
+    CompletionParams, CompletionResponse, CreateFilesParams, DeleteFilesParams,
+    DidChangeConfigurationParams, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
+    DocumentDiagnosticParams, DocumentDiagnosticReport, DocumentDiagnosticReportResult,
+    DocumentFormattingParams, DocumentHighlight, DocumentHighlightParams,
+    DocumentOnTypeFormattingParams, DocumentRangeFormattingParams, DocumentSymbolParams,
+    DocumentSymbolResponse, ExecuteCommandParams, FileChangeType, FoldingRange, FoldingRangeParams,
+    GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverParams, InlayHint, InlayHintParams,
+    MessageType, PrepareRenameResponse, ReferenceParams, RenameFilesParams, RenameParams,
+    SelectionRange, SelectionRangeParams, SemanticTokensDeltaParams, SemanticTokensFullDeltaResult,
+    SemanticTokensParams, SemanticTokensRangeParams, SemanticTokensRangeResult,
+    SemanticTokensResult, ShowMessageParams, SignatureHelp, SignatureHelpParams,
+    TextDocumentContentChangeEvent, TextDocumentPositionParams, TextEdit, TypeHierarchyItem,
+    TypeHierarchyPrepareParams, TypeHierarchySubtypesParams, TypeHierarchySupertypesParams, Url,
+    WorkspaceDiagnosticParams, WorkspaceDiagnosticReportResult, WorkspaceEdit,
+    WorkspaceSymbolParams, WorkspaceSymbolResponse,
 };
 use salsa::{Database, IngredientInfo};
 use serde_json::{Value, json};
 use tracing::{error, trace};
 
 use crate::ide::code_lens::{CodeLensController, FileChange};
-use crate::lang::lsp::LsProtoGroup;
+use crate::lang::lsp::{LsProtoGroup, Utf8Span};
 use crate::lsp::ext::{
     ExpandMacro, ProvideVirtualFile, ProvideVirtualFileRequest, ProvideVirtualFileResponse,
     ShowMemoryUsage, ToolchainInfo, ToolchainInfoResponse, ViewAnalyzedCrates, ViewSyntaxTree,
@@ -186,6 +205,34 @@ impl BackgroundDocumentRequestHandler for Formatting {
     }
 }
 
+impl BackgroundDocumentRequestHandler for RangeFormatting {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "textDocument/rangeFormatting", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: DocumentRangeFormattingParams,
+    ) -> LSPResult<Option<Vec<TextEdit>>> {
+        Ok(ide::format::ranges::format_range(params, snapshot))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for OnTypeFormatting {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "textDocument/onTypeFormatting", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: DocumentOnTypeFormattingParams,
+    ) -> LSPResult<Option<Vec<TextEdit>>> {
+        Ok(ide::format::ranges::format_on_type(params, snapshot))
+    }
+}
+
 impl SyncNotificationHandler for DidChangeTextDocument {
     #[tracing::instrument(
         name = "textDocument/didChange",
@@ -194,23 +241,64 @@ impl SyncNotificationHandler for DidChangeTextDocument {
     )]
     fn run(
         state: &mut State,
-        _notifier: Notifier,
+        notifier: Notifier,
         _requester: &mut Requester<'_>,
         params: DidChangeTextDocumentParams,
     ) -> LSPResult<()> {
-        let text = if let Ok([TextDocumentContentChangeEvent { text, .. }]) =
-            TryInto::<[_; 1]>::try_into(params.content_changes)
-        {
-            text
-        } else {
-            error!("unexpected format of document change");
+        let uri = &params.text_document.uri;
+        let version = params.text_document.version;
+        let db = &mut state.db;
+        let Some(file) = db.file_for_url(uri) else {
             return Ok(());
         };
 
-        let db = &mut state.db;
-        if let Some(file) = db.file_for_url(&params.text_document.uri) {
-            override_file_content!(db, file, Some(text.into()));
-        };
+        // Range edits can only be applied on top of the content the client has edited,
+        // which is not the case if an edit was missed or the edits came out of order.
+        let was_out_of_sync = state.out_of_sync_documents.contains(uri);
+        let in_sync = !was_out_of_sync
+            && state.document_versions.get(uri).is_none_or(|last_version| version > *last_version);
+        let mut text =
+            in_sync.then(|| db.file_content(file).map(|text| text.to_string())).flatten();
+
+        for TextDocumentContentChangeEvent { range, text: new_text, .. } in params.content_changes {
+            match range {
+                Some(range) => {
+                    if let Some(text) = &mut text {
+                        let span = Utf8Span::from_lsp_range(text, range);
+                        text.replace_range(span.start..span.end, &new_text);
+                    }
+                }
+                // The full content of a document always brings it back in sync.
+                None => text = Some(new_text),
+            }
+        }
+
+        match text {
+            Some(text) => {
+                override_file_content!(db, file, Some(text.into()));
+                state.document_versions.insert(uri.clone(), version);
+                state.out_of_sync_documents.remove(uri);
+            }
+            // Range edits are ignored until the client sends the full content or saves the file.
+            None if was_out_of_sync => {}
+            // The content the client has cannot be reconstructed, so fall back to the one on disk
+            // until the document is brought back in sync.
+            None => {
+                error!("document out of sync at version {version}: {uri}");
+                override_file_content!(db, file, None);
+                state.document_versions.remove(uri);
+                state.out_of_sync_documents.insert(uri.clone());
+                notifier.notify::<ShowMessage>(ShowMessageParams {
+                    typ: MessageType::WARNING,
+                    message: format!(
+                        "Cairo language server lost track of changes in {uri} and reloaded it \
+                         from disk. Save the file to analyze its unsaved changes."
+                    ),
+                });
+            }
+        }
+
+        state.workspace_symbol_index.on_file_changed(params.text_document.uri.clone());
 
         state.code_lens_controller.on_did_change(
             state.db.clone(),
@@ -251,6 +339,7 @@ impl SyncNotificationHandler for DidChangeWatchedFiles {
                 let Some(_file) = state.db.file_for_url(&change.uri) else { continue };
                 // In perfect scenario we would do this only for `file` but there is no way to make it more granulary.
                 state.db.cancel_all();
+                state.workspace_symbol_index.on_file_changed(change.uri.clone());
             }
         }
 
@@ -300,6 +389,8 @@ impl SyncNotificationHandler for DidCloseTextDocument {
     ) -> LSPResult<()> {
         let db = &mut state.db;
         state.open_files.remove(&params.text_document.uri);
+        state.document_versions.remove(&params.text_document.uri);
+        state.out_of_sync_documents.remove(&params.text_document.uri);
         if let Some(file) = db.file_for_url(&params.text_document.uri)
             && db.file_overrides().contains_key(&file)
         {
@@ -333,6 +424,8 @@ impl SyncNotificationHandler for DidOpenTextDocument {
         let db = &mut state.db;
         if let Some(file_id) = db.file_for_url(&uri) {
             state.open_files.insert(uri.clone());
+            state.document_versions.insert(uri.clone(), params.text_document.version);
+            state.out_of_sync_documents.remove(&uri);
             if let Some(content) = db.file_content(file_id)
                 && content != params.text_document.text.as_str()
             {
@@ -367,6 +460,8 @@ impl SyncNotificationHandler for DidSaveTextDocument {
         let db = &mut state.db;
         if let Some(file) = db.file_for_url(&params.text_document.uri) {
             override_file_content!(db, file, None);
+            // The saved content is the one the client has, so range edits apply on top of it.
+            state.out_of_sync_documents.remove(&params.text_document.uri);
 
             // In perfect scenario we would do this only for `file` but there is no way to make it more granulary.
             state.db.cancel_all();
@@ -411,14 +506,39 @@ impl BackgroundDocumentRequestHandler for Completion {
         _notifier: Notifier,
         params: CompletionParams,
     ) -> LSPResult<Option<CompletionResponse>> {
-        Ok(catch_unwind(AssertUnwindSafe(|| ide::completion::complete(params, &snapshot.db)))
-            .unwrap_or_else(|err| {
-                if is_cancelled(err.as_ref()) {
-                    resume_unwind(err);
-                }
-                error!("Completion handler panicked");
-                None
-            }))
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::completion::complete(params, &snapshot.db, &snapshot.client_capabilities)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("Completion handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for ResolveCompletionItem {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "completionItem/resolve", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: CompletionItem,
+    ) -> LSPResult<CompletionItem> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::completion::resolve_completion_item(params.clone(), &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("ResolveCompletionItem handler panicked");
+            params
+        }))
     }
 }
 
@@ -445,6 +565,104 @@ impl BackgroundDocumentRequestHandler for SemanticTokensFullRequest {
     }
 }
 
+impl BackgroundDocumentRequestHandler for SemanticTokensFullDeltaRequest {
+    const RETRY: bool = true;
+
+    #[tracing::instrument(name = "textDocument/semanticTokens/full/delta", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        meta_state: MetaState,
+        _notifier: Notifier,
+        params: SemanticTokensDeltaParams,
+    ) -> LSPResult<Option<SemanticTokensFullDeltaResult>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::semantic_highlighting::semantic_highlight_full_delta(
+                params,
+                &snapshot.db,
+                meta_state,
+            )
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("SemanticTokensFullDeltaRequest handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for SemanticTokensRangeRequest {
+    const RETRY: bool = true;
+
+    #[tracing::instrument(name = "textDocument/semanticTokens/range", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: SemanticTokensRangeParams,
+    ) -> LSPResult<Option<SemanticTokensRangeResult>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::semantic_highlighting::semantic_highlight_range(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("SemanticTokensRangeRequest handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for DocumentDiagnosticRequest {
+    const RETRY: bool = true;
+
+    #[tracing::instrument(name = "textDocument/diagnostic", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: DocumentDiagnosticParams,
+    ) -> LSPResult<DocumentDiagnosticReportResult> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            lang::diagnostics::document_diagnostic(params, &snapshot)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("DocumentDiagnosticRequest handler panicked");
+            DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(
+                Default::default(),
+            ))
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for WorkspaceDiagnosticRequest {
+    const RETRY: bool = true;
+
+    #[tracing::instrument(name = "workspace/diagnostic", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        notifier: Notifier,
+        params: WorkspaceDiagnosticParams,
+    ) -> LSPResult<WorkspaceDiagnosticReportResult> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            lang::diagnostics::workspace_diagnostic(params, &snapshot, &notifier)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("WorkspaceDiagnosticRequest handler panicked");
+            WorkspaceDiagnosticReportResult::Report(Default::default())
+        }))
+    }
+}
+
 impl BackgroundDocumentRequestHandler for ProvideVirtualFile {
     const RETRY: bool = false;
 
@@ -595,6 +813,337 @@ impl BackgroundDocumentRequestHandler for DocumentHighlightRequest {
     }
 }
 
+impl BackgroundDocumentRequestHandler for DocumentSymbolRequest {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "textDocument/documentSymbol", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: DocumentSymbolParams,
+    ) -> LSPResult<Option<DocumentSymbolResponse>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::navigation::document_symbols::document_symbols(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("DocumentSymbolRequest handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for FoldingRangeRequest {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "textDocument/foldingRange", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: FoldingRangeParams,
+    ) -> LSPResult<Option<Vec<FoldingRange>>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::folding_ranges::folding_ranges(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("FoldingRangeRequest handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for SelectionRangeRequest {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "textDocument/selectionRange", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: SelectionRangeParams,
+    ) -> LSPResult<Option<Vec<SelectionRange>>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::selection_range::selection_ranges(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("SelectionRangeRequest handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for CallHierarchyPrepare {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "textDocument/prepareCallHierarchy", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: CallHierarchyPrepareParams,
+    ) -> LSPResult<Option<Vec<CallHierarchyItem>>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::navigation::call_hierarchy::prepare_call_hierarchy(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("CallHierarchyPrepare handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for CallHierarchyIncomingCalls {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "callHierarchy/incomingCalls", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> LSPResult<Option<Vec<CallHierarchyIncomingCall>>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::navigation::call_hierarchy::incoming_calls(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("CallHierarchyIncomingCalls handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for CallHierarchyOutgoingCalls {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "callHierarchy/outgoingCalls", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> LSPResult<Option<Vec<CallHierarchyOutgoingCall>>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::navigation::call_hierarchy::outgoing_calls(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("CallHierarchyOutgoingCalls handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for TypeHierarchyPrepare {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "textDocument/prepareTypeHierarchy", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: TypeHierarchyPrepareParams,
+    ) -> LSPResult<Option<Vec<TypeHierarchyItem>>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::navigation::type_hierarchy::prepare_type_hierarchy(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("TypeHierarchyPrepare handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for TypeHierarchySupertypes {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "typeHierarchy/supertypes", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: TypeHierarchySupertypesParams,
+    ) -> LSPResult<Option<Vec<TypeHierarchyItem>>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::navigation::type_hierarchy::supertypes(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("TypeHierarchySupertypes handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for TypeHierarchySubtypes {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "typeHierarchy/subtypes", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: TypeHierarchySubtypesParams,
+    ) -> LSPResult<Option<Vec<TypeHierarchyItem>>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::navigation::type_hierarchy::subtypes(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("TypeHierarchySubtypes handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for GotoImplementation {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "textDocument/implementation", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: GotoImplementationParams,
+    ) -> LSPResult<Option<GotoImplementationResponse>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::navigation::implementation::goto_implementation(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("GotoImplementation handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for GotoTypeDefinition {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "textDocument/typeDefinition", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: GotoTypeDefinitionParams,
+    ) -> LSPResult<Option<GotoTypeDefinitionResponse>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::navigation::type_definition::goto_type_definition(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("GotoTypeDefinition handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for GotoDeclaration {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "textDocument/declaration", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: GotoDeclarationParams,
+    ) -> LSPResult<Option<GotoDeclarationResponse>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::navigation::declaration::goto_declaration(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("GotoDeclaration handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for WorkspaceSymbolRequest {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "workspace/symbol", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: WorkspaceSymbolParams,
+    ) -> LSPResult<Option<WorkspaceSymbolResponse>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::navigation::workspace_symbols::workspace_symbols(
+                params,
+                &snapshot.db,
+                &snapshot.workspace_symbol_index,
+            )
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("WorkspaceSymbolRequest handler panicked");
+            None
+        }))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for PrepareRenameRequest {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "textDocument/prepareRename", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: TextDocumentPositionParams,
+    ) -> LSPResult<Option<PrepareRenameResponse>> {
+        catch_unwind(AssertUnwindSafe(|| {
+            ide::navigation::rename::prepare_rename(
+                params,
+                &snapshot.db,
+                &snapshot.configs_registry,
+                &snapshot.scarb_toolchain,
+            )
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("PrepareRenameRequest handler panicked");
+            Ok(None)
+        })
+    }
+}
+
 impl BackgroundDocumentRequestHandler for Rename {
     const RETRY: bool = false;
 
@@ -606,7 +1155,13 @@ impl BackgroundDocumentRequestHandler for Rename {
         params: RenameParams,
     ) -> LSPResult<Option<WorkspaceEdit>> {
         catch_unwind(AssertUnwindSafe(|| {
-            ide::navigation::rename::rename(params, &snapshot.db, &snapshot.client_capabilities)
+            ide::navigation::rename::rename(
+                params,
+                &snapshot.db,
+                &snapshot.client_capabilities,
+                &snapshot.configs_registry,
+                &snapshot.scarb_toolchain,
+            )
         }))
         .unwrap_or_else(|err| {
             if is_cancelled(err.as_ref()) {
@@ -618,6 +1173,29 @@ impl BackgroundDocumentRequestHandler for Rename {
     }
 }
 
+impl BackgroundDocumentRequestHandler for SignatureHelpRequest {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "textDocument/signatureHelp", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: SignatureHelpParams,
+    ) -> LSPResult<Option<SignatureHelp>> {
+        Ok(catch_unwind(AssertUnwindSafe(|| {
+            ide::signature_help::signature_help(params, &snapshot.db)
+        }))
+        .unwrap_or_else(|err| {
+            if is_cancelled(err.as_ref()) {
+                resume_unwind(err);
+            }
+            error!("SignatureHelpRequest handler panicked");
+            None
+        }))
+    }
+}
+
 impl BackgroundDocumentRequestHandler for ViewSyntaxTree {
     const RETRY: bool = false;
 
@@ -677,6 +1255,34 @@ impl BackgroundDocumentRequestHandler for WillRenameFiles {
     }
 }
 
+impl BackgroundDocumentRequestHandler for WillCreateFiles {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "workspace/willCreateFiles", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        _notifier: Notifier,
+        params: CreateFilesParams,
+    ) -> LSPResult<Option<WorkspaceEdit>> {
+        Ok(lang::create_file::create_files(&snapshot.db, params))
+    }
+}
+
+impl BackgroundDocumentRequestHandler for WillDeleteFiles {
+    const RETRY: bool = false;
+
+    #[tracing::instrument(name = "workspace/willDeleteFiles", skip_all)]
+    fn run_with_snapshot(
+        snapshot: StateSnapshot,
+        _meta_state: MetaState,
+        notifier: Notifier,
+        params: DeleteFilesParams,
+    ) -> LSPResult<Option<WorkspaceEdit>> {
+        Ok(lang::delete_file::delete_files(&snapshot.db, params, &notifier))
+    }
+}
+
 impl BackgroundDocumentRequestHandler for InlayHintRequest {
     const RETRY: bool = false;
 
@@ -9,7 +9,7 @@ use std::panic::{AssertUnwindSafe, catch_unwind};
 
 use anyhow::anyhow;
 use crossbeam::channel::Sender;
-pub use handlers::is_cairo_file_path;
+pub use handlers::{is_cairo_file_path, is_scarb_manifest};
 use lsp_server::{ErrorCode, ExtractError, Notification, Request, RequestId};
 use lsp_types::notification::{
     Cancel, DidChangeConfiguration, DidChangeTextDocument, DidChangeWatchedFiles,
@@ -17,10 +17,18 @@ use lsp_types::notification::{
     Notification as NotificationTrait, SetTrace,
 };
 use lsp_types::request::{
-    CodeActionRequest, CodeLensRequest, Completion, DocumentHighlightRequest, ExecuteCommand,
-    Formatting, GotoDefinition, HoverRequest, InlayHintRequest, References, Rename,
-    Request as RequestTrait, SemanticTokensFullRequest, WillRenameFiles,
+    CallHierarchyIncomingCalls, CallHierarchyOutgoingCalls, CallHierarchyPrepare,
+    CodeActionRequest, CodeLensRequest, Completion, DocumentDiagnosticRequest,
+    DocumentHighlightRequest, DocumentSymbolRequest, ExecuteCommand, FoldingRangeRequest,
+    Formatting, GotoDeclaration, GotoDefinition, GotoImplementation, GotoTypeDefinition,
+    HoverRequest, InlayHintRequest, OnTypeFormatting, PrepareRenameRequest, RangeFormatting,
+    References, Rename, Request as RequestTrait, ResolveCompletionItem, SelectionRangeRequest,
+    SemanticTokensFullDeltaRequest, SemanticTokensFullRequest, SemanticTokensRangeRequest,
+    SignatureHelpRequest, TypeHierarchyPrepare, TypeHierarchySubtypes, TypeHierarchySupertypes,
+    WillCreateFiles, WillDeleteFiles, WillRenameFiles, WorkspaceDiagnosticRequest,
+    WorkspaceSymbolRequest,
 };
+use salsa::Database;
 use tracing::{error, trace, warn};
 
 use super::client::{Notifier, Responder};
@@ -59,6 +67,8 @@ pub fn request<'a>(
             retry_sender,
         ),
         Formatting::METHOD => background_fmt_task::<Formatting>(request, retry_sender),
+        RangeFormatting::METHOD => background_fmt_task::<RangeFormatting>(request, retry_sender),
+        OnTypeFormatting::METHOD => background_fmt_task::<OnTypeFormatting>(request, retry_sender),
         GotoDefinition::METHOD => background_request_task::<GotoDefinition>(
             request,
             BackgroundSchedule::LatencySensitive,
@@ -84,11 +94,90 @@ pub fn request<'a>(
             BackgroundSchedule::LatencySensitive,
             retry_sender,
         ),
+        DocumentSymbolRequest::METHOD => background_request_task::<DocumentSymbolRequest>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
+        FoldingRangeRequest::METHOD => background_request_task::<FoldingRangeRequest>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
+        ResolveCompletionItem::METHOD => background_request_task::<ResolveCompletionItem>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
+        SelectionRangeRequest::METHOD => background_request_task::<SelectionRangeRequest>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
+        CallHierarchyPrepare::METHOD => background_request_task::<CallHierarchyPrepare>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
+        CallHierarchyIncomingCalls::METHOD => {
+            background_request_task::<CallHierarchyIncomingCalls>(
+                request,
+                BackgroundSchedule::LatencySensitive,
+                retry_sender,
+            )
+        }
+        CallHierarchyOutgoingCalls::METHOD => {
+            background_request_task::<CallHierarchyOutgoingCalls>(
+                request,
+                BackgroundSchedule::LatencySensitive,
+                retry_sender,
+            )
+        }
+        TypeHierarchyPrepare::METHOD => background_request_task::<TypeHierarchyPrepare>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
+        TypeHierarchySupertypes::METHOD => background_request_task::<TypeHierarchySupertypes>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
+        TypeHierarchySubtypes::METHOD => background_request_task::<TypeHierarchySubtypes>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
+        GotoImplementation::METHOD => background_request_task::<GotoImplementation>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
+        GotoTypeDefinition::METHOD => background_request_task::<GotoTypeDefinition>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
+        GotoDeclaration::METHOD => background_request_task::<GotoDeclaration>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
+        WorkspaceSymbolRequest::METHOD => background_request_task::<WorkspaceSymbolRequest>(
+            request,
+            BackgroundSchedule::Worker,
+            retry_sender,
+        ),
         CodeLensRequest::METHOD => background_request_task::<CodeLensRequest>(
             request,
             BackgroundSchedule::LatencySensitive,
             retry_sender,
         ),
+        PrepareRenameRequest::METHOD => background_request_task::<PrepareRenameRequest>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
         Rename::METHOD => background_request_task::<Rename>(
             request,
             BackgroundSchedule::LatencySensitive,
@@ -99,6 +188,37 @@ pub fn request<'a>(
             BackgroundSchedule::Worker,
             retry_sender,
         ),
+        SemanticTokensFullDeltaRequest::METHOD => {
+            background_request_task::<SemanticTokensFullDeltaRequest>(
+                request,
+                BackgroundSchedule::Worker,
+                retry_sender,
+            )
+        }
+        SemanticTokensRangeRequest::METHOD => {
+            background_request_task::<SemanticTokensRangeRequest>(
+                request,
+                BackgroundSchedule::LatencySensitive,
+                retry_sender,
+            )
+        }
+        DocumentDiagnosticRequest::METHOD => background_request_task::<DocumentDiagnosticRequest>(
+            request,
+            BackgroundSchedule::Worker,
+            retry_sender,
+        ),
+        WorkspaceDiagnosticRequest::METHOD => {
+            background_request_task::<WorkspaceDiagnosticRequest>(
+                request,
+                BackgroundSchedule::Worker,
+                retry_sender,
+            )
+        }
+        SignatureHelpRequest::METHOD => background_request_task::<SignatureHelpRequest>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
         ViewSyntaxTree::METHOD => background_request_task::<ViewSyntaxTree>(
             request,
             BackgroundSchedule::Worker,
@@ -124,6 +244,16 @@ pub fn request<'a>(
             BackgroundSchedule::LatencySensitive,
             retry_sender,
         ),
+        WillCreateFiles::METHOD => background_request_task::<WillCreateFiles>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
+        WillDeleteFiles::METHOD => background_request_task::<WillDeleteFiles>(
+            request,
+            BackgroundSchedule::LatencySensitive,
+            retry_sender,
+        ),
         InlayHintRequest::METHOD => background_request_task::<InlayHintRequest>(
             request,
             BackgroundSchedule::LatencySensitive,
@@ -159,9 +289,18 @@ pub fn notification<'a>(notification: Notification) -> Task<'a> {
         DidOpenTextDocument::METHOD => local_notification_task::<DidOpenTextDocument>(notification),
         DidSaveTextDocument::METHOD => local_notification_task::<DidSaveTextDocument>(notification),
 
-        // Ignoring $/cancelRequest because CairoLS does cancellation inside-out when the state is
-        // mutated, and we allow ourselves to ignore the corner case of user hitting ESC manually.
-        Cancel::METHOD => Ok(Task::nothing()),
+        // Only background requests can be cancelled, the rest is answered before this
+        // notification is processed anyway.
+        Cancel::METHOD => cast_notification::<Cancel>(notification).map(|(_, params)| {
+            Task::local(move |_, meta_state, _, _, _| {
+                let in_flight_requests = meta_state
+                    .lock()
+                    .expect("should be able to acquire the MetaState")
+                    .in_flight_requests
+                    .clone();
+                in_flight_requests.cancel(params.id);
+            })
+        }),
 
         // Ignoring $/setTrace because CairoLS never emits $/logTrace notifications anyway.
         SetTrace::METHOD => Ok(Task::nothing()),
@@ -220,38 +359,61 @@ fn create_background_fn_handler_raw<R: handlers::BackgroundDocumentRequestHandle
     retry_info: RetryTaskInfo,
     retry_sender: Sender<(RetryTaskInfo, Box<dyn Handler>)>,
 ) -> impl Handler {
-    move |state_snapshot, meta_state, notifier, responder| match catch_unwind(AssertUnwindSafe(
-        || {
+    move |state_snapshot, meta_state, notifier, responder| {
+        let in_flight_requests = meta_state
+            .lock()
+            .expect("should be able to acquire the MetaState")
+            .in_flight_requests
+            .clone();
+
+        // Retried requests run on a fresh snapshot, so the cancellation token has to be renewed.
+        if !in_flight_requests.track(id.clone(), state_snapshot.db.cancellation_token()) {
+            in_flight_requests.finish(&id);
+            respond::<R>(id, Err(request_cancelled()), &responder);
+            return;
+        }
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
             R::run_with_snapshot(
                 state_snapshot,
                 meta_state,
                 notifier,
                 serde_json::from_value(params.clone()).unwrap(),
             )
-        },
-    )) {
-        Ok(result) => respond::<R>(id, result, &responder),
-        Err(err) => {
-            if let Ok(err) = cancelled_anyhow(err, "LSP worker thread was cancelled") {
-                if R::RETRY {
-                    let handler = create_background_fn_handler_raw::<R>(
-                        id,
-                        params,
-                        retry_info,
-                        retry_sender.clone(),
-                    );
+        }));
 
-                    let _ = retry_sender.send((retry_info, Box::new(handler)));
+        match result {
+            Ok(result) => {
+                in_flight_requests.finish(&id);
+                respond::<R>(id, result, &responder)
+            }
+            Err(err) => {
+                if let Ok(err) = cancelled_anyhow(err, "LSP worker thread was cancelled") {
+                    if in_flight_requests.is_cancelled(&id) {
+                        in_flight_requests.finish(&id);
+                        respond::<R>(id, Err(request_cancelled()), &responder)
+                    } else if R::RETRY {
+                        let handler = create_background_fn_handler_raw::<R>(
+                            id,
+                            params,
+                            retry_info,
+                            retry_sender.clone(),
+                        );
+
+                        let _ = retry_sender.send((retry_info, Box::new(handler)));
+                    } else {
+                        in_flight_requests.finish(&id);
+                        let err = LSPError::new(err, ErrorCode::ServerCancelled);
+                        respond::<R>(id, Err(err), &responder)
+                    }
                 } else {
-                    let err = LSPError::new(err, ErrorCode::ServerCancelled);
+                    in_flight_requests.finish(&id);
+                    let err = LSPError::new(
+                        anyhow!("caught panic in LSP worker thread"),
+                        ErrorCode::InternalError,
+                    );
                     respond::<R>(id, Err(err), &responder)
                 }
-            } else {
-                let err = LSPError::new(
-                    anyhow!("caught panic in LSP worker thread"),
-                    ErrorCode::InternalError,
-                );
-                respond::<R>(id, Err(err), &responder)
             }
         }
     }
@@ -266,10 +428,20 @@ fn create_background_fn_builder<R: handlers::BackgroundDocumentRequestHandler>(
     // Clone version of params.
     let params_json = serde_json::to_value(&params).unwrap();
 
-    let handler = create_background_fn_handler_raw::<R>(id, params_json, retry_info, retry_sender);
+    let handler =
+        create_background_fn_handler_raw::<R>(id.clone(), params_json, retry_info, retry_sender);
 
     move |state: &State, meta_state: MetaState| {
         let state_snapshot = state.snapshot();
+
+        // Start tracking the request before it is sent to the thread pool, so that it can be
+        // cancelled even before a worker picks it up.
+        meta_state
+            .lock()
+            .expect("should be able to acquire the MetaState")
+            .in_flight_requests
+            .track(id.clone(), state_snapshot.db.cancellation_token());
+
         Box::new(move |notifier, responder| {
             handler(state_snapshot, meta_state, notifier, responder);
         })
@@ -308,11 +480,18 @@ fn cast_request<R: RequestTrait>(request: Request) -> Result<(RequestId, R::Para
         .with_failure_code(ErrorCode::InternalError)
 }
 
+/// Error answering requests which were cancelled by the client with `$/cancelRequest`.
+fn request_cancelled() -> LSPError {
+    LSPError::new(anyhow!("request was cancelled by the client"), ErrorCode::RequestCanceled)
+}
+
 /// Sends back a response to the lsp_server using a [`Responder`].
 fn respond<R: RequestTrait>(id: RequestId, result: LSPResult<R::Result>, responder: &Responder) {
     if let Err(err) = &result {
         match err.code {
-            ErrorCode::ServerCancelled => trace!("request {id} was cancelled: {err:?}"),
+            ErrorCode::ServerCancelled | ErrorCode::RequestCanceled => {
+                trace!("request {id} was cancelled: {err:?}")
+            }
             _ => error!("request {id} errored: {err:?}"),
         }
     }
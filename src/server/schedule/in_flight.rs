@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use lsp_server::RequestId;
+use lsp_types::NumberOrString;
+use salsa::CancellationToken;
+
+#[cfg(test)]
+#[path = "in_flight_test.rs"]
+mod test;
+
+/// Registry of background requests which are currently being handled.
+///
+/// Each request is tracked along with a cancellation token of the database snapshot it runs on,
+/// so that a `$/cancelRequest` from the client aborts it at the next Salsa cancellation
+/// checkpoint instead of letting it run to completion.
+#[derive(Clone, Default)]
+pub struct InFlightRequests(Arc<Mutex<HashMap<RequestId, InFlightRequest>>>);
+
+struct InFlightRequest {
+    token: CancellationToken,
+    cancelled: bool,
+}
+
+impl InFlightRequests {
+    /// Starts tracking the request, or replaces the token if it is already tracked,
+    /// e.g. when the request is retried on a new snapshot.
+    ///
+    /// Returns `false` if the client has already cancelled the request.
+    pub fn track(&self, id: RequestId, token: CancellationToken) -> bool {
+        match self.lock().entry(id) {
+            Entry::Occupied(mut entry) => {
+                let request = entry.get_mut();
+                request.token = token;
+                !request.cancelled
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(InFlightRequest { token, cancelled: false });
+                true
+            }
+        }
+    }
+
+    /// Checks whether the client has cancelled the request.
+    pub fn is_cancelled(&self, id: &RequestId) -> bool {
+        self.lock().get(id).is_some_and(|request| request.cancelled)
+    }
+
+    /// Stops tracking the request once it has been answered.
+    pub fn finish(&self, id: &RequestId) {
+        self.lock().remove(id);
+    }
+
+    /// Handles `$/cancelRequest` by cancelling the database snapshot of the request.
+    ///
+    /// Requests which are not tracked have either finished already or are not handled
+    /// in the background, so the notification is ignored for them.
+    pub fn cancel(&self, id: NumberOrString) {
+        let id = match id {
+            NumberOrString::Number(id) => RequestId::from(id),
+            NumberOrString::String(id) => RequestId::from(id),
+        };
+
+        if let Some(request) = self.lock().get_mut(&id) {
+            request.cancelled = true;
+            request.token.cancel();
+        }
+    }
+
+    #[cfg(test)]
+    fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<RequestId, InFlightRequest>> {
+        self.0.lock().expect("in-flight requests lock should not be poisoned")
+    }
+}
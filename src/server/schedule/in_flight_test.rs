@@ -0,0 +1,75 @@
+use lsp_server::RequestId;
+use lsp_types::NumberOrString;
+use salsa::Database;
+
+use super::InFlightRequests;
+use crate::lang::db::AnalysisDatabase;
+
+#[test]
+fn cancel_tracked_request() {
+    let db = AnalysisDatabase::new();
+    let requests = InFlightRequests::default();
+    let token = db.cancellation_token();
+
+    assert!(requests.track(RequestId::from(1), token.clone()));
+    assert!(!requests.is_cancelled(&RequestId::from(1)));
+
+    requests.cancel(NumberOrString::Number(1));
+    assert!(requests.is_cancelled(&RequestId::from(1)));
+    assert!(token.is_cancelled());
+}
+
+#[test]
+fn cancel_request_with_string_id() {
+    let db = AnalysisDatabase::new();
+    let requests = InFlightRequests::default();
+
+    requests.track(RequestId::from("foo".to_owned()), db.cancellation_token());
+    requests.cancel(NumberOrString::String("foo".to_owned()));
+
+    assert!(requests.is_cancelled(&RequestId::from("foo".to_owned())));
+}
+
+#[test]
+fn cancel_untracked_request() {
+    let db = AnalysisDatabase::new();
+    let requests = InFlightRequests::default();
+
+    requests.track(RequestId::from(1), db.cancellation_token());
+    requests.cancel(NumberOrString::Number(2));
+
+    assert!(!requests.is_cancelled(&RequestId::from(1)));
+    assert!(!requests.is_cancelled(&RequestId::from(2)));
+}
+
+#[test]
+fn retrying_cancelled_request() {
+    let db = AnalysisDatabase::new();
+    let requests = InFlightRequests::default();
+
+    requests.track(RequestId::from(1), db.cancellation_token());
+    requests.cancel(NumberOrString::Number(1));
+
+    // A retry on a new snapshot must not resurrect a request cancelled by the client.
+    assert!(!requests.track(RequestId::from(1), db.cancellation_token()));
+    assert!(requests.is_cancelled(&RequestId::from(1)));
+}
+
+#[test]
+fn finished_request_is_untracked() {
+    let db = AnalysisDatabase::new();
+    let requests = InFlightRequests::default();
+    let token = db.cancellation_token();
+
+    requests.track(RequestId::from(1), token.clone());
+    requests.finish(&RequestId::from(1));
+    assert!(requests.is_empty());
+
+    // Cancelling a request which has already been answered is a no-op.
+    requests.cancel(NumberOrString::Number(1));
+    assert!(!requests.is_cancelled(&RequestId::from(1)));
+    assert!(!token.is_cancelled());
+
+    // The id may be reused by the client once the request is answered.
+    assert!(requests.track(RequestId::from(1), db.cancellation_token()));
+}
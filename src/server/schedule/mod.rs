@@ -18,9 +18,11 @@ use crate::server::connection::ClientSender;
 use crate::server::schedule::task::BackgroundFnBuilder;
 use crate::state::{MetaState, MetaStateInner, State};
 
+mod in_flight;
 mod task;
 pub mod thread;
 
+pub use self::in_flight::InFlightRequests;
 pub(super) use self::task::BackgroundSchedule;
 pub use self::task::{Handler, RetryTaskInfo, SyncMutTask, Task};
 use crate::server::schedule::task::SyncTask;
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::default::Default;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
@@ -10,18 +10,27 @@ use lsp_types::{ClientCapabilities, Url};
 use crate::config::Config;
 use crate::ide::analysis_progress::{AnalysisEvent, AnalysisProgressController};
 use crate::ide::code_lens::CodeLensController;
+use crate::ide::navigation::workspace_symbols::WorkspaceSymbolIndex;
+use crate::ide::semantic_highlighting::SemanticTokensCache;
 use crate::lang::db::{AnalysisDatabase, AnalysisDatabaseSwapper, InactivitySwapMonitor};
 use crate::lang::diagnostics::DiagnosticsController;
 use crate::lang::proc_macros::controller::ProcMacroClientController;
+use crate::lsp::capabilities::client::ClientCapabilitiesExt;
 use crate::project::{ConfigsRegistry, ProjectController};
 use crate::server::client::Client;
 use crate::server::connection::ClientSender;
+use crate::server::schedule::InFlightRequests;
 use crate::toolchain::scarb::ScarbToolchain;
 
 /// State of Language server.
 pub struct State {
     pub db: AnalysisDatabase,
     pub open_files: Owned<HashSet<Url>>,
+    /// Versions of open documents as of the last applied change.
+    pub document_versions: HashMap<Url, i32>,
+    /// Open documents whose content the server has lost track of, range edits cannot be applied
+    /// to them until the client sends their full content again.
+    pub out_of_sync_documents: HashSet<Url>,
     pub config: Owned<Config>,
     pub client_capabilities: Owned<ClientCapabilities>,
     pub scarb_toolchain: ScarbToolchain,
@@ -30,6 +39,7 @@ pub struct State {
     pub project_controller: ProjectController,
     pub analysis_progress_controller: AnalysisProgressController,
     pub code_lens_controller: CodeLensController,
+    pub workspace_symbol_index: WorkspaceSymbolIndex,
 }
 
 impl State {
@@ -47,6 +57,7 @@ impl State {
             notifier.clone(),
             analysis_progress_controller.clone(),
             scarb_toolchain.clone(),
+            client_capabilities.diagnostic_pull_support(),
         );
 
         let proc_macro_controller = ProcMacroClientController::new(
@@ -60,6 +71,8 @@ impl State {
         Self {
             db: AnalysisDatabase::new(),
             open_files: Default::default(),
+            document_versions: Default::default(),
+            out_of_sync_documents: Default::default(),
             config: Default::default(),
             client_capabilities: Owned::new(client_capabilities.into()),
             scarb_toolchain: scarb_toolchain.clone(),
@@ -68,6 +81,7 @@ impl State {
             proc_macro_controller,
             project_controller: ProjectController::initialize(scarb_toolchain, notifier),
             code_lens_controller: CodeLensController::new(),
+            workspace_symbol_index: Default::default(),
         }
     }
 
@@ -79,6 +93,7 @@ impl State {
             client_capabilities: self.client_capabilities.snapshot(),
             configs_registry: self.project_controller.configs_registry(),
             code_lens_controller: self.code_lens_controller.clone(),
+            workspace_symbol_index: self.workspace_symbol_index.clone(),
         }
     }
 }
@@ -90,13 +105,22 @@ pub struct MetaStateInner {
     /// Using it also does not affect the analysis. Thus, it's safe to place it here and access via interior mutability.
     pub db_swapper: AnalysisDatabaseSwapper,
     pub inactivity_monitor: InactivitySwapMonitor,
+    /// Semantic tokens last sent to the client, used to compute deltas.
+    pub semantic_tokens_cache: SemanticTokensCache,
+    /// Background requests which can be cancelled by the client.
+    pub in_flight_requests: InFlightRequests,
 }
 
 impl MetaStateInner {
     pub fn new(analysis_event_sender: Sender<AnalysisEvent>) -> Self {
         let db_swapper = AnalysisDatabaseSwapper::new(analysis_event_sender);
         let inactivity_monitor = InactivitySwapMonitor::new();
-        Self { db_swapper, inactivity_monitor }
+        Self {
+            db_swapper,
+            inactivity_monitor,
+            semantic_tokens_cache: Default::default(),
+            in_flight_requests: Default::default(),
+        }
     }
 }
 
@@ -113,6 +137,7 @@ pub struct StateSnapshot {
     pub client_capabilities: Snapshot<ClientCapabilities>,
     pub configs_registry: Snapshot<ConfigsRegistry>,
     pub code_lens_controller: CodeLensController,
+    pub workspace_symbol_index: WorkspaceSymbolIndex,
 }
 
 impl std::panic::UnwindSafe for StateSnapshot {}
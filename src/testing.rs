@@ -4,6 +4,7 @@ use anyhow::Result;
 
 use crate::Backend;
 pub use crate::ide::semantic_highlighting::token_kind::SemanticTokenKind;
+pub use crate::ide::semantic_highlighting::token_modifiers::SemanticTokenModifiers;
 use crate::server::connection::ConnectionInitializer;
 use crate::server::schedule::thread::JoinHandle;
 
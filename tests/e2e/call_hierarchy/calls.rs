@@ -0,0 +1,65 @@
+use lsp_types::CallHierarchyItem;
+
+use crate::support::insta::test_transform_plain;
+
+#[test]
+fn outgoing_calls_resolve_trait_methods_to_impls() {
+    test_transform_plain!(CallHierarchyItem, r#"
+    trait Greeter<T> {
+        fn greet(self: @T) -> u32;
+    }
+
+    impl U32Greeter of Greeter<u32> {
+        fn greet(self: @u32) -> u32 {
+            helper(*self)
+        }
+    }
+
+    fn helper(x: u32) -> u32 {
+        x + 1
+    }
+
+    fn ma<caret>in() {
+        let a = helper(1);
+        let b = 2_u32.greet();
+        let c = helper(a + b);
+    }
+    "#, @r"
+    main (hello) @ 14
+    incoming:
+    outgoing:
+        greet (hello::U32Greeter) @ 5 from 16:18
+        helper (hello) @ 10 from 15:12, 17:12
+    ");
+}
+
+#[test]
+fn incoming_calls_from_functions_and_methods() {
+    test_transform_plain!(CallHierarchyItem, r#"
+    trait Greeter<T> {
+        fn greet(self: @T) -> u32;
+    }
+
+    impl U32Greeter of Greeter<u32> {
+        fn greet(self: @u32) -> u32 {
+            helper(*self)
+        }
+    }
+
+    fn helper(x: u32) -> u32 {
+        x + 1
+    }
+
+    fn main() {
+        let a = hel<caret>per(1);
+        let b = 2_u32.greet();
+        let c = helper(a + b);
+    }
+    "#, @r"
+    helper (hello) @ 10
+    incoming:
+        greet (hello::U32Greeter) @ 5 from 6:8
+        main (hello) @ 14 from 15:12, 17:12
+    outgoing:
+    ");
+}
@@ -0,0 +1,85 @@
+use lsp_types::{
+    CallHierarchyClientCapabilities, CallHierarchyIncomingCallsParams, CallHierarchyItem,
+    CallHierarchyOutgoingCallsParams, CallHierarchyPrepareParams, ClientCapabilities, Range,
+    TextDocumentClientCapabilities, lsp_request,
+};
+
+use crate::support::hierarchy::{prepare, render_item, render_section};
+use crate::support::transform::Transformer;
+
+mod calls;
+
+impl Transformer for CallHierarchyItem {
+    fn capabilities(base: ClientCapabilities) -> ClientCapabilities {
+        ClientCapabilities {
+            text_document: base.text_document.or_else(Default::default).map(|it| {
+                TextDocumentClientCapabilities {
+                    call_hierarchy: Some(CallHierarchyClientCapabilities {
+                        dynamic_registration: Some(false),
+                    }),
+                    ..it
+                }
+            }),
+            ..base
+        }
+    }
+
+    fn transform(
+        mut ls: crate::support::MockClient,
+        cursors: crate::support::cursor::Cursors,
+        _config: Option<serde_json::Value>,
+    ) -> String {
+        let position = cursors.assert_single_caret();
+
+        let Some(item) = prepare::<lsp_request!("textDocument/prepareCallHierarchy"), _>(
+            &mut ls,
+            position,
+            |text_document_position_params| CallHierarchyPrepareParams {
+                text_document_position_params,
+                work_done_progress_params: Default::default(),
+            },
+        ) else {
+            return "none response".to_string();
+        };
+
+        let incoming = ls
+            .send_request::<lsp_request!("callHierarchy/incomingCalls")>(
+                CallHierarchyIncomingCallsParams {
+                    item: item.clone(),
+                    work_done_progress_params: Default::default(),
+                    partial_result_params: Default::default(),
+                },
+            )
+            .unwrap_or_default()
+            .into_iter()
+            .map(|call| render_call(&call.from, &call.from_ranges));
+
+        let outgoing = ls
+            .send_request::<lsp_request!("callHierarchy/outgoingCalls")>(
+                CallHierarchyOutgoingCallsParams {
+                    item: item.clone(),
+                    work_done_progress_params: Default::default(),
+                    partial_result_params: Default::default(),
+                },
+            )
+            .unwrap_or_default()
+            .into_iter()
+            .map(|call| render_call(&call.to, &call.from_ranges));
+
+        format!(
+            "{}\n{}{}",
+            render_item(&item),
+            render_section("incoming", incoming),
+            render_section("outgoing", outgoing)
+        )
+    }
+}
+
+fn render_call(item: &CallHierarchyItem, from_ranges: &[Range]) -> String {
+    let from_ranges = from_ranges
+        .iter()
+        .map(|range| format!("{}:{}", range.start.line, range.start.character))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{} from {from_ranges}", render_item(item))
+}
@@ -0,0 +1,102 @@
+use indoc::formatdoc;
+use lsp_server::{ErrorCode, RequestId};
+use lsp_types::notification::Cancel;
+use lsp_types::{
+    CancelParams, ClientCapabilities, DiagnosticClientCapabilities, DocumentDiagnosticParams,
+    NumberOrString, TextDocumentClientCapabilities, lsp_request,
+};
+
+use crate::support::cairo_project_toml::CAIRO_PROJECT_TOML_2025_12;
+use crate::support::{MockClient, sandbox};
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: base.text_document.or_else(Default::default).map(|it| {
+            TextDocumentClientCapabilities {
+                diagnostic: Some(DiagnosticClientCapabilities {
+                    dynamic_registration: Some(false),
+                    related_document_support: Some(false),
+                }),
+                ..it
+            }
+        }),
+        ..base
+    }
+}
+
+/// Sets up a project which takes a while to analyze,
+/// so that a diagnostics request for it is still pending when cancelled.
+fn project() -> MockClient {
+    let functions = (0..300)
+        .map(|i| {
+            formatdoc!(
+                r#"
+                fn function_{i}(a: u256, b: Array<felt252>) -> Option<u256> {{
+                    let mut span = b.span();
+                    let first = *span.pop_front()?;
+                    let sum: u256 = first.into() + a * {i};
+                    if sum > 0 {{ Some(sum) }} else {{ None }}
+                }}
+                "#
+            )
+        })
+        .collect::<String>();
+
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => functions,
+        }
+        client_capabilities = caps;
+    };
+    ls.open_all_cairo_files_and_wait_for_project_update();
+    ls
+}
+
+fn start_diagnostics_request(ls: &mut MockClient) -> RequestId {
+    let params = DocumentDiagnosticParams {
+        text_document: ls.doc_id("src/lib.cairo"),
+        identifier: None,
+        previous_result_id: None,
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    ls.start_request::<lsp_request!("textDocument/diagnostic")>(params)
+}
+
+fn cancel(ls: &mut MockClient, id: &RequestId) {
+    let id: NumberOrString = serde_json::from_value(serde_json::to_value(id).unwrap()).unwrap();
+    ls.send_notification::<Cancel>(CancelParams { id });
+}
+
+#[test]
+fn cancels_pending_request() {
+    let mut ls = project();
+
+    let id = start_diagnostics_request(&mut ls);
+    cancel(&mut ls, &id);
+
+    let error = ls.wait_for_response(&id).error.expect("expected the request to be cancelled");
+    assert_eq!(error.code, ErrorCode::RequestCanceled as i32);
+
+    // Cancellation affects only the cancelled request.
+    let id = start_diagnostics_request(&mut ls);
+    let response = ls.wait_for_response(&id);
+    assert!(response.error.is_none(), "unexpected error: {:?}", response.error);
+}
+
+#[test]
+fn ignores_cancellation_of_finished_request() {
+    let mut ls = project();
+
+    let id = start_diagnostics_request(&mut ls);
+    let response = ls.wait_for_response(&id);
+    assert!(response.error.is_none(), "unexpected error: {:?}", response.error);
+
+    // The request is no longer tracked, so cancelling it must not affect the following ones.
+    cancel(&mut ls, &id);
+
+    let id = start_diagnostics_request(&mut ls);
+    let response = ls.wait_for_response(&id);
+    assert!(response.error.is_none(), "unexpected error: {:?}", response.error);
+}
@@ -0,0 +1,147 @@
+use crate::code_actions::quick_fix;
+use crate::support::insta::test_transform;
+
+#[test]
+fn param_and_returned_variable() {
+    test_transform!(quick_fix, "
+    fn main() -> felt252 {
+        let a = 1;
+        <sel>let b = a + 2;
+        let c = b * 2;</sel>
+        c + a
+    }
+    ", @r#"
+    Title: Extract into function
+    Add new text: "let c = extracted(a);"
+    At: Range { start: Position { line: 2, character: 4 }, end: Position { line: 3, character: 18 } }
+    Add new text: "
+
+    fn extracted(a: felt252) -> felt252 {
+        let b = a + 2;
+        let c = b * 2;
+        c
+    }"
+    At: Range { start: Position { line: 5, character: 1 }, end: Position { line: 5, character: 1 } }
+    "#);
+}
+
+#[test]
+fn mutated_variable() {
+    test_transform!(quick_fix, "
+    fn main() -> felt252 {
+        let mut total = 0;
+        <sel>total += 5;</sel>
+        total
+    }
+    ", @r#"
+    Title: Extract into function
+    Add new text: "extracted(ref total);"
+    At: Range { start: Position { line: 2, character: 4 }, end: Position { line: 2, character: 15 } }
+    Add new text: "
+
+    fn extracted(ref total: felt252) {
+        total += 5;
+    }"
+    At: Range { start: Position { line: 4, character: 1 }, end: Position { line: 4, character: 1 } }
+    "#);
+}
+
+#[test]
+fn generic_params() {
+    test_transform!(quick_fix, "
+    fn wrap<T, U, +Drop<T>, +Drop<U>>(value: T) -> Array<T> {
+        let mut result = array![];
+        <sel>result.append(value);</sel>
+        result
+    }
+    ", @r#"
+    Title: Extract into function
+    Add new text: "extracted(ref result, value);"
+    At: Range { start: Position { line: 2, character: 4 }, end: Position { line: 2, character: 25 } }
+    Add new text: "
+
+    fn extracted<T, +Drop<T>>(ref result: Array<T>, value: T) {
+        result.append(value);
+    }"
+    At: Range { start: Position { line: 4, character: 1 }, end: Position { line: 4, character: 1 } }
+    "#);
+}
+
+#[test]
+fn generic_param_name_in_literal() {
+    test_transform!(quick_fix, "
+    fn wrap<T, U, +Drop<T>, +Drop<U>>(value: T, other: U) -> Array<(T, felt252)> {
+        let mut result = array![];
+        <sel>result.append((value, 'U'));</sel>
+        result
+    }
+    ", @r#"
+    Title: Extract into function
+    Add new text: "extracted(ref result, value);"
+    At: Range { start: Position { line: 2, character: 4 }, end: Position { line: 2, character: 32 } }
+    Add new text: "
+
+    fn extracted<T, +Drop<T>>(ref result: Array<(T, felt252)>, value: T) {
+        result.append((value, 'U'));
+    }"
+    At: Range { start: Position { line: 4, character: 1 }, end: Position { line: 4, character: 1 } }
+    "#);
+}
+
+#[test]
+fn generic_bounds_of_used_params() {
+    test_transform!(quick_fix, "
+    fn compare<T, U, +Drop<T>, +Drop<U>, +PartialEq<U>>(a: T, b: U, c: U) -> bool {
+        <sel>let same = b == c;</sel>
+        same
+    }
+    ", @r#"
+    Title: Extract into function
+    Add new text: "let same = extracted(b, c);"
+    At: Range { start: Position { line: 1, character: 4 }, end: Position { line: 1, character: 22 } }
+    Add new text: "
+
+    fn extracted<U, +Drop<U>, +PartialEq<U>>(b: U, c: U) -> bool {
+        let same = b == c;
+        same
+    }"
+    At: Range { start: Position { line: 3, character: 1 }, end: Position { line: 3, character: 1 } }
+    "#);
+}
+
+#[test]
+fn method() {
+    test_transform!(quick_fix, "
+    #[derive(Drop)]
+    struct Counter { value: u32 }
+
+    #[generate_trait]
+    impl CounterImpl of CounterTrait {
+        fn bump(ref self: Counter, step: u32) {
+            <sel>self.value += step;</sel>
+        }
+    }
+    ", @r#"
+    Title: Extract into method
+    Add new text: "self.extracted(step);"
+    At: Range { start: Position { line: 6, character: 8 }, end: Position { line: 6, character: 27 } }
+    Add new text: "
+
+        fn extracted(ref self: Counter, step: u32) {
+            self.value += step;
+        }"
+    At: Range { start: Position { line: 7, character: 5 }, end: Position { line: 7, character: 5 } }
+    "#);
+}
+
+#[test]
+fn early_return() {
+    test_transform!(quick_fix, "
+    fn main() -> felt252 {
+        <sel>if true {
+            return 1;
+        }</sel>
+        2
+    }
+    ", @"No code actions.");
+}
@@ -0,0 +1,203 @@
+use crate::code_actions::quick_fix;
+use crate::support::insta::test_transform;
+
+#[test]
+fn function_call() {
+    test_transform!(quick_fix, "
+    fn compute() -> felt252 { 1 }
+
+    fn main() -> felt252 {
+        <sel>compute()</sel> + 2
+    }
+    ", @r#"
+    Title: Extract into variable
+    Add new text: "let compute_1 = compute();
+        "
+    At: Range { start: Position { line: 3, character: 4 }, end: Position { line: 3, character: 4 } }
+    Add new text: "compute_1"
+    At: Range { start: Position { line: 3, character: 4 }, end: Position { line: 3, character: 13 } }
+    "#);
+}
+
+#[test]
+fn copyable_with_occurrences() {
+    test_transform!(quick_fix, "
+    #[derive(Copy, Drop)]
+    struct Point { x: felt252, y: felt252 }
+
+    fn main() -> felt252 {
+        let a = <sel>Point { x: 1, y: 2 }</sel>;
+        let b = Point { x: 1, y: 2 };
+        a.x + b.y
+    }
+    ", @r#"
+    Title: Extract into variable
+    Add new text: "let point = Point { x: 1, y: 2 };
+        "
+    At: Range { start: Position { line: 4, character: 4 }, end: Position { line: 4, character: 4 } }
+    Add new text: "point"
+    At: Range { start: Position { line: 4, character: 12 }, end: Position { line: 4, character: 32 } }
+    Title: Extract into variable, replacing all 2 occurrences
+    Add new text: "let point = Point { x: 1, y: 2 };
+        "
+    At: Range { start: Position { line: 4, character: 4 }, end: Position { line: 4, character: 4 } }
+    Add new text: "point"
+    At: Range { start: Position { line: 4, character: 12 }, end: Position { line: 4, character: 32 } }
+    Add new text: "point"
+    At: Range { start: Position { line: 5, character: 12 }, end: Position { line: 5, character: 32 } }
+    "#);
+}
+
+#[test]
+fn non_copyable_with_occurrences() {
+    test_transform!(quick_fix, "
+    #[derive(Drop)]
+    struct Wallet { balance: felt252 }
+
+    fn main() -> felt252 {
+        let a = <sel>Wallet { balance: 1 }</sel>;
+        let b = Wallet { balance: 1 };
+        a.balance + b.balance
+    }
+    ", @r#"
+    Title: Extract into variable
+    Add new text: "let wallet = Wallet { balance: 1 };
+        "
+    At: Range { start: Position { line: 4, character: 4 }, end: Position { line: 4, character: 4 } }
+    Add new text: "wallet"
+    At: Range { start: Position { line: 4, character: 12 }, end: Position { line: 4, character: 33 } }
+    "#);
+}
+
+#[test]
+fn not_an_expression() {
+    test_transform!(quick_fix, "
+    fn main() -> felt252 {
+        <sel>let a = 1;</sel>
+        a
+    }
+    ", @"No code actions.");
+}
+
+#[test]
+fn function_call_with_occurrences() {
+    test_transform!(quick_fix, "
+    fn compute() -> felt252 { 1 }
+
+    fn main() -> felt252 {
+        let a = <sel>compute()</sel>;
+        a + compute()
+    }
+    ", @r#"
+    Title: Extract into variable
+    Add new text: "let compute_1 = compute();
+        "
+    At: Range { start: Position { line: 3, character: 4 }, end: Position { line: 3, character: 4 } }
+    Add new text: "compute_1"
+    At: Range { start: Position { line: 3, character: 12 }, end: Position { line: 3, character: 21 } }
+    "#);
+}
+
+#[test]
+fn occurrences_in_nested_and_sibling_blocks() {
+    test_transform!(quick_fix, "
+    fn main(a: felt252, c: bool) -> felt252 {
+        let b = if c {
+            <sel>a * 2</sel>
+        } else {
+            a * 2 + 1
+        };
+        b + a * 2
+    }
+    ", @r#"
+    Title: Extract into variable
+    Add new text: "let var = a * 2;
+            "
+    At: Range { start: Position { line: 2, character: 8 }, end: Position { line: 2, character: 8 } }
+    Add new text: "var"
+    At: Range { start: Position { line: 2, character: 8 }, end: Position { line: 2, character: 13 } }
+    Title: Extract into variable, replacing all 3 occurrences
+    Add new text: "let var = a * 2;
+        "
+    At: Range { start: Position { line: 1, character: 4 }, end: Position { line: 1, character: 4 } }
+    Add new text: "var"
+    At: Range { start: Position { line: 2, character: 8 }, end: Position { line: 2, character: 13 } }
+    Add new text: "var"
+    At: Range { start: Position { line: 4, character: 8 }, end: Position { line: 4, character: 13 } }
+    Add new text: "var"
+    At: Range { start: Position { line: 6, character: 8 }, end: Position { line: 6, character: 13 } }
+    "#);
+}
+
+#[test]
+fn occurrence_with_shadowed_variable() {
+    test_transform!(quick_fix, "
+    fn main(a: felt252) -> felt252 {
+        let x = <sel>a + 1</sel>;
+        let a = 5;
+        x + (a + 1)
+    }
+    ", @r#"
+    Title: Extract into variable
+    Add new text: "let var = a + 1;
+        "
+    At: Range { start: Position { line: 1, character: 4 }, end: Position { line: 1, character: 4 } }
+    Add new text: "var"
+    At: Range { start: Position { line: 1, character: 12 }, end: Position { line: 1, character: 17 } }
+    "#);
+}
+
+#[test]
+fn name_taken_in_function() {
+    test_transform!(quick_fix, "
+    fn main(a: felt252) -> felt252 {
+        let var = 1;
+        let var_1 = 2;
+        let x = <sel>a * 2</sel>;
+        x + var + var_1
+    }
+    ", @r#"
+    Title: Extract into variable
+    Add new text: "let var_2 = a * 2;
+        "
+    At: Range { start: Position { line: 3, character: 4 }, end: Position { line: 3, character: 4 } }
+    Add new text: "var_2"
+    At: Range { start: Position { line: 3, character: 12 }, end: Position { line: 3, character: 17 } }
+    "#);
+}
+
+#[test]
+fn while_condition() {
+    test_transform!(quick_fix, "
+    fn main() {
+        let mut i: u32 = 0;
+        while <sel>i * 2</sel> < 10 {
+            i += 1;
+        }
+    }
+    ", @"No code actions.");
+}
+
+#[test]
+fn else_if_condition() {
+    test_transform!(quick_fix, "
+    fn main(a: u32) -> u32 {
+        if a == 0 {
+            1
+        } else if <sel>a / 2</sel> == 1 {
+            2
+        } else {
+            3
+        }
+    }
+    ", @"No code actions.");
+}
+
+#[test]
+fn right_hand_side_of_lazy_operator() {
+    test_transform!(quick_fix, "
+    fn main(a: u32) -> bool {
+        a != 0 && <sel>10 / a</sel> > 1
+    }
+    ", @"No code actions.");
+}
@@ -0,0 +1,211 @@
+use crate::code_actions::quick_fix;
+use crate::support::insta::test_transform;
+
+#[test]
+fn simple_arguments() {
+    test_transform!(quick_fix, "
+    fn add(a: felt252, b: felt252) -> felt252 {
+        a + b
+    }
+
+    fn main() -> felt252 {
+        let x = 1;
+        ad<caret>d(x, 2) * 3
+    }
+    ", @r#"
+    Title: Inline function `add`
+    Add new text: "(x + 2)"
+    At: Range { start: Position { line: 6, character: 4 }, end: Position { line: 6, character: 13 } }
+    "#);
+}
+
+#[test]
+fn name_collision() {
+    test_transform!(quick_fix, "
+    fn scale(value: felt252, factor: felt252) -> felt252 {
+        let result = value * factor;
+        result + 1
+    }
+
+    fn main() -> felt252 {
+        let result = 3;
+        sc<caret>ale(result + 1, result)
+    }
+    ", @r#"
+    Title: Inline function `scale`
+    Add new text: "{
+            let value = result + 1;
+            let result_1 = value * result;
+            result_1 + 1
+        }"
+    At: Range { start: Position { line: 7, character: 4 }, end: Position { line: 7, character: 29 } }
+    "#);
+}
+
+#[test]
+fn method_with_snapshot_self() {
+    test_transform!(quick_fix, "
+    #[derive(Drop)]
+    struct Counter { value: u32 }
+
+    #[generate_trait]
+    impl CounterImpl of CounterTrait {
+        fn doubled(self: @Counter) -> u32 {
+            *self.value * 2
+        }
+    }
+
+    fn main() -> u32 {
+        let counter = Counter { value: 1 };
+        counter.doub<caret>led()
+    }
+    ", @r#"
+    Title: Inline function `doubled`
+    Add new text: "{
+            let this = @counter;
+            *this.value * 2
+        }"
+    At: Range { start: Position { line: 12, character: 4 }, end: Position { line: 12, character: 21 } }
+    "#);
+}
+
+#[test]
+fn all_call_sites() {
+    test_transform!(quick_fix, "
+    fn dou<caret>ble(x: felt252) -> felt252 {
+        x * 2
+    }
+
+    fn main() -> felt252 {
+        let a = double(1);
+        double(a)
+    }
+    ", @r#"
+    Title: Inline all calls of `double`
+    Add new text: ""
+    At: Range { start: Position { line: 0, character: 0 }, end: Position { line: 4, character: 0 } }
+    Add new text: "1 * 2"
+    At: Range { start: Position { line: 5, character: 12 }, end: Position { line: 5, character: 21 } }
+    Add new text: "a * 2"
+    At: Range { start: Position { line: 6, character: 4 }, end: Position { line: 6, character: 13 } }
+    "#);
+}
+
+#[test]
+fn early_return() {
+    test_transform!(quick_fix, "
+    fn check(x: felt252) -> felt252 {
+        if x == 0 {
+            return 1;
+        }
+        x
+    }
+
+    fn main() -> felt252 {
+        che<caret>ck(5)
+    }
+    ", @r#"
+    Title: Inline function `check`
+    Add new text: "loop {
+            if 5 == 0 {
+                break 1;
+            }
+            break 5;
+        }"
+    At: Range { start: Position { line: 8, character: 4 }, end: Position { line: 8, character: 12 } }
+    "#);
+}
+
+#[test]
+fn early_return_with_bindings() {
+    test_transform!(quick_fix, "
+    fn clamp(mut x: u32, max: u32) {
+        if x > max {
+            return;
+        }
+        x += 1;
+    }
+
+    fn main() {
+        cla<caret>mp(1 + 2, 10);
+    }
+    ", @r#"
+    Title: Inline function `clamp`
+    Add new text: "{
+            let mut x = 1 + 2;
+            loop {
+                if x > 10 {
+                    break;
+                }
+                x += 1;
+                break;
+            }
+        }"
+    At: Range { start: Position { line: 8, character: 4 }, end: Position { line: 8, character: 20 } }
+    "#);
+}
+
+#[test]
+fn return_from_loop() {
+    test_transform!(quick_fix, "
+    fn find(x: felt252) -> felt252 {
+        loop {
+            if x == 0 {
+                return 1;
+            }
+            break;
+        }
+        x
+    }
+
+    fn main() -> felt252 {
+        fi<caret>nd(5)
+    }
+    ", @"No code actions.");
+}
+
+#[test]
+fn other_module() {
+    test_transform!(quick_fix, "
+    mod math {
+        fn one() -> felt252 {
+            1
+        }
+
+        pub fn add_one(x: felt252) -> felt252 {
+            x + one()
+        }
+    }
+
+    fn main() -> felt252 {
+        math::add_<caret>one(2)
+    }
+    ", @"No code actions.");
+}
+
+#[test]
+fn recursive() {
+    test_transform!(quick_fix, "
+    fn fact(n: felt252) -> felt252 {
+        if n == 0 { 1 } else { n * fact(n - 1) }
+    }
+
+    fn main() -> felt252 {
+        fa<caret>ct(3)
+    }
+    ", @"No code actions.");
+}
+
+#[test]
+fn inline_never() {
+    test_transform!(quick_fix, "
+    #[inline(never)]
+    fn one() -> felt252 {
+        1
+    }
+
+    fn main() -> felt252 {
+        o<caret>ne()
+    }
+    ", @"No code actions.");
+}
@@ -0,0 +1,136 @@
+use crate::code_actions::quick_fix;
+use crate::support::insta::test_transform;
+
+#[test]
+fn into_operator() {
+    test_transform!(quick_fix, "
+    fn main() -> felt252 {
+        let <caret>a = 1 + 2;
+        a * 3
+    }
+    ", @r#"
+    Title: Inline variable `a`
+    Add new text: ""
+    At: Range { start: Position { line: 1, character: 0 }, end: Position { line: 2, character: 0 } }
+    Add new text: "(1 + 2)"
+    At: Range { start: Position { line: 2, character: 4 }, end: Position { line: 2, character: 5 } }
+    "#);
+}
+
+#[test]
+fn copyable_used_twice() {
+    test_transform!(quick_fix, "
+    fn main() -> felt252 {
+        let <caret>a = 5;
+        a + a
+    }
+    ", @r#"
+    Title: Inline variable `a`
+    Add new text: ""
+    At: Range { start: Position { line: 1, character: 0 }, end: Position { line: 2, character: 0 } }
+    Add new text: "5"
+    At: Range { start: Position { line: 2, character: 4 }, end: Position { line: 2, character: 5 } }
+    Add new text: "5"
+    At: Range { start: Position { line: 2, character: 8 }, end: Position { line: 2, character: 9 } }
+    "#);
+}
+
+#[test]
+fn non_copyable_used_once() {
+    test_transform!(quick_fix, "
+    #[derive(Drop)]
+    struct Wallet { balance: felt252 }
+
+    fn consume(w: Wallet) {}
+
+    fn main() {
+        let <caret>w = Wallet { balance: 1 };
+        consume(w);
+    }
+    ", @r#"
+    Title: Inline variable `w`
+    Add new text: ""
+    At: Range { start: Position { line: 6, character: 0 }, end: Position { line: 7, character: 0 } }
+    Add new text: "Wallet { balance: 1 }"
+    At: Range { start: Position { line: 7, character: 12 }, end: Position { line: 7, character: 13 } }
+    "#);
+}
+
+#[test]
+fn non_copyable_used_twice() {
+    test_transform!(quick_fix, "
+    #[derive(Drop)]
+    struct Wallet { balance: felt252 }
+
+    fn consume(w: Wallet) {}
+
+    fn main() -> felt252 {
+        let <caret>w = Wallet { balance: 1 };
+        let balance = w.balance;
+        consume(w);
+        balance
+    }
+    ", @"No code actions.");
+}
+
+#[test]
+fn mutable() {
+    test_transform!(quick_fix, "
+    fn main() -> felt252 {
+        let mut <caret>a = 1;
+        a += 1;
+        a
+    }
+    ", @"No code actions.");
+}
+
+#[test]
+fn side_effects_used_twice() {
+    test_transform!(quick_fix, "
+    fn compute() -> felt252 { 1 }
+
+    fn main() -> felt252 {
+        let <caret>a = compute();
+        a + a
+    }
+    ", @"No code actions.");
+}
+
+#[test]
+fn side_effects_used_in_loop() {
+    test_transform!(quick_fix, "
+    fn compute() -> felt252 { 1 }
+
+    fn main() {
+        let <caret>a = compute();
+        loop {
+            let _b = a;
+            break;
+        }
+    }
+    ", @"No code actions.");
+}
+
+#[test]
+fn initializer_mutated_before_usage() {
+    test_transform!(quick_fix, "
+    fn main() -> felt252 {
+        let mut b = 1;
+        let <caret>a = b;
+        b += 1;
+        a + b
+    }
+    ", @"No code actions.");
+}
+
+#[test]
+fn initializer_shadowed_before_usage() {
+    test_transform!(quick_fix, "
+    fn main() -> felt252 {
+        let x = 1;
+        let <caret>a = x;
+        let x = 5;
+        a + x
+    }
+    ", @"No code actions.");
+}
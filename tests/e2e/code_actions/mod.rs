@@ -12,13 +12,19 @@ use crate::support::fixture::Fixture;
 use crate::support::{cursors, fixture, sandbox};
 
 mod create_module_file;
+mod extract_function;
+mod extract_variable;
 mod fill_struct_fields;
 mod fill_trait_members;
+mod inline_function;
+mod inline_variable;
 mod lint;
 mod macro_expand;
 mod make_variable_mutable;
 mod missing_import;
 mod missing_trait;
+mod organize_imports;
+mod remove_unused;
 mod rename_unused_variable;
 mod scarb_manifest;
 mod similar_identifier;
@@ -0,0 +1,106 @@
+use lsp_types::{CodeActionContext, CodeActionKind, CodeActionParams, Range, lsp_request};
+
+use crate::code_actions::render_code_actions_or_commands;
+use crate::support::cairo_project_toml::CAIRO_PROJECT_TOML_2025_12;
+use crate::support::insta::test_transform;
+use crate::support::sandbox;
+
+fn organize_imports(cairo_code: &str) -> String {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => cairo_code,
+        }
+    };
+    ls.open_and_wait_for_diagnostics("src/lib.cairo");
+
+    let code_actions = ls
+        .send_request::<lsp_request!("textDocument/codeAction")>(CodeActionParams {
+            text_document: ls.doc_id("src/lib.cairo"),
+            range: Range::default(),
+            context: CodeActionContext {
+                diagnostics: vec![],
+                only: Some(vec![CodeActionKind::SOURCE_ORGANIZE_IMPORTS]),
+                trigger_kind: None,
+            },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })
+        .expect("Code actions request failed.");
+
+    render_code_actions_or_commands(code_actions, &ls.fixture.root_path().to_string_lossy())
+}
+
+#[test]
+fn unused_and_unsorted() {
+    test_transform!(organize_imports, "
+    mod shapes {
+        pub fn circle() -> felt252 { 1 }
+        pub fn square() -> felt252 { 2 }
+        pub fn triangle() -> felt252 { 3 }
+    }
+
+    use shapes::square;
+    use shapes::triangle;
+    use shapes::circle;
+
+    fn main() -> felt252 {
+        circle() + square()
+    }
+    ", @r#"
+    Title: Organize imports
+    Add new text: "use shapes::{circle, square};"
+    At: Range { start: Position { line: 6, character: 0 }, end: Position { line: 6, character: 19 } }
+    Add new text: ""
+    At: Range { start: Position { line: 7, character: 0 }, end: Position { line: 8, character: 0 } }
+    Add new text: ""
+    At: Range { start: Position { line: 8, character: 0 }, end: Position { line: 9, character: 0 } }
+    "#);
+}
+
+#[test]
+fn nested_trees_in_inline_module() {
+    test_transform!(organize_imports, "
+    mod geometry {
+        pub mod shapes {
+            pub fn circle() -> felt252 { 1 }
+            pub fn square() -> felt252 { 2 }
+        }
+        pub mod colors {
+            pub fn red() -> felt252 { 3 }
+        }
+    }
+
+    mod canvas {
+        use super::geometry::shapes::square;
+        use super::geometry::{colors::red, shapes::circle};
+
+        pub fn draw() -> felt252 {
+            circle() + square() + red()
+        }
+    }
+    ", @r#"
+    Title: Organize imports
+    Add new text: "use super::geometry::colors::red;
+        use super::geometry::shapes::{circle, square};"
+    At: Range { start: Position { line: 11, character: 4 }, end: Position { line: 11, character: 40 } }
+    Add new text: ""
+    At: Range { start: Position { line: 12, character: 0 }, end: Position { line: 13, character: 0 } }
+    "#);
+}
+
+#[test]
+fn already_organized() {
+    test_transform!(organize_imports, "
+    mod shapes {
+        pub fn circle() -> felt252 { 1 }
+        pub fn square() -> felt252 { 2 }
+    }
+
+    use shapes::{circle, square};
+
+    fn main() -> felt252 {
+        circle() + square()
+    }
+    ", @"No code actions.");
+}
@@ -0,0 +1,145 @@
+use crate::code_actions::quick_fix;
+use crate::support::insta::test_transform;
+
+#[test]
+fn import_in_group() {
+    test_transform!(quick_fix, "
+    mod shapes {
+        pub fn circle() -> felt252 { 1 }
+        pub fn square() -> felt252 { 2 }
+    }
+
+    use shapes::{circle, squ<caret>are};
+
+    fn main() -> felt252 {
+        circle()
+    }
+    ", @r#"
+    Title: Remove unused import `square`
+    Add new text: ""
+    At: Range { start: Position { line: 5, character: 19 }, end: Position { line: 5, character: 27 } }
+    Title: Fix All
+    Add new text: ""
+    At: Range { start: Position { line: 5, character: 19 }, end: Position { line: 5, character: 27 } }
+    "#);
+}
+
+#[test]
+fn import_emptying_group() {
+    test_transform!(quick_fix, "
+    mod shapes {
+        pub mod round {
+            pub fn circle() -> felt252 { 1 }
+        }
+        pub fn square() -> felt252 { 2 }
+    }
+
+    use shapes::{round::{cir<caret>cle}, square};
+
+    fn main() -> felt252 {
+        square()
+    }
+    ", @r#"
+    Title: Remove unused import `circle`
+    Add new text: ""
+    At: Range { start: Position { line: 7, character: 13 }, end: Position { line: 7, character: 30 } }
+    Title: Fix All
+    Add new text: ""
+    At: Range { start: Position { line: 7, character: 13 }, end: Position { line: 7, character: 30 } }
+    "#);
+}
+
+#[test]
+fn whole_use_item() {
+    test_transform!(quick_fix, "
+    mod shapes {
+        pub fn circle() -> felt252 { 1 }
+    }
+
+    use shapes::cir<caret>cle;
+
+    fn main() {}
+    ", @r#"
+    Title: Remove unused import `circle`
+    Add new text: ""
+    At: Range { start: Position { line: 4, character: 0 }, end: Position { line: 5, character: 0 } }
+    Title: Fix All
+    Add new text: ""
+    At: Range { start: Position { line: 4, character: 0 }, end: Position { line: 5, character: 0 } }
+    "#);
+}
+
+#[test]
+fn unused_siblings_and_whole_group() {
+    test_transform!(quick_fix, "
+    mod shapes {
+        pub fn circle() -> felt252 { 1 }
+        pub fn square() -> felt252 { 2 }
+        pub fn triangle() -> felt252 { 3 }
+        pub mod round {
+            pub fn oval() -> felt252 { 4 }
+            pub fn ring() -> felt252 { 5 }
+        }
+    }
+
+    <sel>use shapes::{circle, square, triangle};
+    use shapes::round::{oval, ring};</sel>
+
+    fn main() -> felt252 {
+        circle()
+    }
+    ", @r#"
+    Title: Remove unused imports `square`, `triangle`
+    Add new text: ""
+    At: Range { start: Position { line: 10, character: 19 }, end: Position { line: 10, character: 37 } }
+    Title: Remove unused imports `square`, `triangle`
+    Add new text: ""
+    At: Range { start: Position { line: 10, character: 19 }, end: Position { line: 10, character: 37 } }
+    Title: Remove unused imports `oval`, `ring`
+    Add new text: ""
+    At: Range { start: Position { line: 11, character: 0 }, end: Position { line: 12, character: 0 } }
+    Title: Remove unused imports `oval`, `ring`
+    Add new text: ""
+    At: Range { start: Position { line: 11, character: 0 }, end: Position { line: 12, character: 0 } }
+    Title: Fix All
+    Add new text: ""
+    At: Range { start: Position { line: 10, character: 19 }, end: Position { line: 10, character: 37 } }
+    Add new text: ""
+    At: Range { start: Position { line: 11, character: 0 }, end: Position { line: 12, character: 0 } }
+    "#);
+}
+
+#[test]
+fn variable_with_side_effects() {
+    test_transform!(quick_fix, "
+    fn compute() -> felt252 { 1 }
+
+    fn main() {
+        let <caret>value = compute();
+    }
+    ", @r#"
+    Title: Rename to `_value`
+    Add new text: "_"
+    At: Range { start: Position { line: 3, character: 8 }, end: Position { line: 3, character: 8 } }
+    Title: Fix All
+    Add new text: "_"
+    At: Range { start: Position { line: 3, character: 8 }, end: Position { line: 3, character: 8 } }
+    "#);
+}
+
+#[test]
+fn variable_moving_value() {
+    test_transform!(quick_fix, "
+    fn main() {
+        let items: Array<felt252> = array![];
+        let <caret>moved = items;
+    }
+    ", @r#"
+    Title: Rename to `_moved`
+    Add new text: "_"
+    At: Range { start: Position { line: 2, character: 8 }, end: Position { line: 2, character: 8 } }
+    Title: Fix All
+    Add new text: "_"
+    At: Range { start: Position { line: 2, character: 8 }, end: Position { line: 2, character: 8 } }
+    "#);
+}
@@ -20,9 +20,12 @@ fn before_name() {
     Title: Rename to `_b`
     Add new text: "_"
     At: Range { start: Position { line: 1, character: 8 }, end: Position { line: 1, character: 8 } }
+    Title: Remove unused variable `b`
+    Add new text: ""
+    At: Range { start: Position { line: 1, character: 0 }, end: Position { line: 2, character: 0 } }
     Title: Fix All
-    Add new text: "_"
-    At: Range { start: Position { line: 1, character: 8 }, end: Position { line: 1, character: 8 } }
+    Add new text: ""
+    At: Range { start: Position { line: 1, character: 0 }, end: Position { line: 2, character: 0 } }
     "#);
 }
 
@@ -36,9 +39,12 @@ fn after_name() {
     Title: Rename to `_b`
     Add new text: "_"
     At: Range { start: Position { line: 1, character: 8 }, end: Position { line: 1, character: 8 } }
+    Title: Remove unused variable `b`
+    Add new text: ""
+    At: Range { start: Position { line: 1, character: 0 }, end: Position { line: 2, character: 0 } }
     Title: Fix All
-    Add new text: "_"
-    At: Range { start: Position { line: 1, character: 8 }, end: Position { line: 1, character: 8 } }
+    Add new text: ""
+    At: Range { start: Position { line: 1, character: 0 }, end: Position { line: 2, character: 0 } }
     "#);
 }
 
@@ -97,9 +103,12 @@ fn before_name_when_mut() {
     Title: Rename to `_b`
     Add new text: "_"
     At: Range { start: Position { line: 1, character: 12 }, end: Position { line: 1, character: 12 } }
+    Title: Remove unused variable `b`
+    Add new text: ""
+    At: Range { start: Position { line: 1, character: 0 }, end: Position { line: 2, character: 0 } }
     Title: Fix All
-    Add new text: "_"
-    At: Range { start: Position { line: 1, character: 12 }, end: Position { line: 1, character: 12 } }
+    Add new text: ""
+    At: Range { start: Position { line: 1, character: 0 }, end: Position { line: 2, character: 0 } }
     "#);
 }
 
@@ -113,9 +122,12 @@ fn after_name_when_mut() {
     Title: Rename to `_b`
     Add new text: "_"
     At: Range { start: Position { line: 1, character: 12 }, end: Position { line: 1, character: 12 } }
+    Title: Remove unused variable `b`
+    Add new text: ""
+    At: Range { start: Position { line: 1, character: 0 }, end: Position { line: 2, character: 0 } }
     Title: Fix All
-    Add new text: "_"
-    At: Range { start: Position { line: 1, character: 12 }, end: Position { line: 1, character: 12 } }
+    Add new text: ""
+    At: Range { start: Position { line: 1, character: 0 }, end: Position { line: 2, character: 0 } }
     "#);
 }
 
@@ -166,8 +178,11 @@ fn in_proc_macro_controlled_code() {
     Title: Rename to `_x`
     Add new text: "_"
     At: Range { start: Position { line: 2, character: 8 }, end: Position { line: 2, character: 8 } }
+    Title: Remove unused variable `x`
+    Add new text: ""
+    At: Range { start: Position { line: 2, character: 0 }, end: Position { line: 3, character: 0 } }
     Title: Fix All
-    Add new text: "_"
-    At: Range { start: Position { line: 2, character: 8 }, end: Position { line: 2, character: 8 } }
+    Add new text: ""
+    At: Range { start: Position { line: 2, character: 0 }, end: Position { line: 3, character: 0 } }
     "#)
 }
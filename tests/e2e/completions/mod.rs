@@ -22,6 +22,7 @@ mod mod_file;
 mod order;
 mod path;
 mod patterns;
+mod resolve;
 mod structs;
 mod traits;
 mod untyped;
@@ -0,0 +1,101 @@
+use indoc::indoc;
+use lsp_types::{
+    ClientCapabilities, CompletionClientCapabilities, CompletionItem, CompletionItemCapability,
+    CompletionItemCapabilityResolveSupport, CompletionParams, CompletionResponse, Documentation,
+    MarkupContent, Position, TextDocumentClientCapabilities, TextDocumentPositionParams,
+    lsp_request,
+};
+
+use crate::support::cairo_project_toml::CAIRO_PROJECT_TOML_2025_12;
+use crate::support::sandbox;
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: base.text_document.or_else(Default::default).map(|it| {
+            TextDocumentClientCapabilities {
+                completion: Some(CompletionClientCapabilities {
+                    completion_item: Some(CompletionItemCapability {
+                        resolve_support: Some(CompletionItemCapabilityResolveSupport {
+                            properties: vec![
+                                "documentation".to_string(),
+                                "additionalTextEdits".to_string(),
+                            ],
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..it
+            }
+        }),
+        ..base
+    }
+}
+
+#[test]
+fn documentation_and_import_are_resolved_lazily() {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => indoc!(r#"
+                mod helpers {
+                    /// Adds two numbers.
+                    pub fn add_numbers(a: felt252, b: felt252) -> felt252 {
+                        a + b
+                    }
+                }
+
+                fn main() {
+                    add_num
+                }
+            "#),
+        }
+        client_capabilities = caps;
+    };
+    ls.open_all_cairo_files_and_wait_for_project_update();
+
+    let completions =
+        ls.send_request::<lsp_request!("textDocument/completion")>(CompletionParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: ls.doc_id("src/lib.cairo"),
+                position: Position { line: 8, character: 11 },
+            },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+            context: None,
+        });
+    let Some(CompletionResponse::Array(items)) = completions else {
+        panic!("expected completion items");
+    };
+    let item: CompletionItem = items
+        .into_iter()
+        .find(|item| item.label == "add_numbers(...)")
+        .expect("`add_numbers` should be completed");
+
+    assert!(item.documentation.is_none());
+    assert!(item.additional_text_edits.is_none());
+    assert!(item.data.is_some());
+
+    let resolved = ls.send_request::<lsp_request!("completionItem/resolve")>(item);
+
+    let Some(Documentation::MarkupContent(MarkupContent { value: documentation, .. })) =
+        resolved.documentation
+    else {
+        panic!("expected markdown documentation");
+    };
+    insta::assert_snapshot!(documentation, @r"
+    ```cairo
+    pub fn add_numbers(a: felt252, b: felt252) -> felt252
+    ```
+    ---
+    Adds two numbers.
+    ");
+
+    let edits: Vec<_> = resolved
+        .additional_text_edits
+        .unwrap_or_default()
+        .into_iter()
+        .map(|edit| edit.new_text)
+        .collect();
+    assert_eq!(edits, vec!["use helpers::add_numbers;\n\n".to_string()]);
+}
@@ -0,0 +1,71 @@
+use lsp_types::DocumentSymbol;
+
+use crate::support::insta::test_transform_plain;
+
+#[test]
+fn module_items() {
+    test_transform_plain!(DocumentSymbol, r#"
+    const LIMIT: u32 = 10;
+
+    type Id = u32;
+
+    #[derive(Drop)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    enum Direction {
+        North,
+        South,
+    }
+
+    trait Shape<T> {
+        const SIDES: u32;
+        fn area(self: @T) -> u32;
+    }
+
+    impl PointShape of Shape<Point> {
+        const SIDES: u32 = 4;
+        fn area(self: @Point) -> u32 {
+            *self.x * *self.y
+        }
+    }
+
+    fn main() {}
+    "#, @r"
+    const LIMIT @ 0
+    type Id @ 2
+    struct Point @ 5
+        field x @ 6
+        field y @ 7
+    enum Direction @ 10
+        variant North @ 11
+        variant South @ 12
+    trait Shape @ 15
+        const SIDES @ 16
+        method area @ 17
+    impl PointShape @ 20
+        const SIDES @ 21
+        method area @ 22
+    fn main @ 27
+    ")
+}
+
+#[test]
+fn inline_modules() {
+    test_transform_plain!(DocumentSymbol, r#"
+    mod outer {
+        mod inner {
+            fn foo() {}
+        }
+
+        fn bar() {}
+    }
+    "#, @r"
+    mod outer @ 0
+        mod inner @ 1
+            fn foo @ 2
+        fn bar @ 5
+    ")
+}
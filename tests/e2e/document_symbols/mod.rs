@@ -0,0 +1,70 @@
+use lsp_types::{
+    ClientCapabilities, DocumentSymbol, DocumentSymbolClientCapabilities, DocumentSymbolParams,
+    DocumentSymbolResponse, SymbolKind, TextDocumentClientCapabilities, lsp_request,
+};
+
+use crate::support::transform::Transformer;
+
+mod items;
+
+impl Transformer for DocumentSymbol {
+    fn capabilities(base: ClientCapabilities) -> ClientCapabilities {
+        ClientCapabilities {
+            text_document: base.text_document.or_else(Default::default).map(|it| {
+                TextDocumentClientCapabilities {
+                    document_symbol: Some(DocumentSymbolClientCapabilities {
+                        dynamic_registration: Some(false),
+                        hierarchical_document_symbol_support: Some(true),
+                        ..Default::default()
+                    }),
+                    ..it
+                }
+            }),
+            ..base
+        }
+    }
+
+    fn transform(
+        mut ls: crate::support::MockClient,
+        _cursors: crate::support::cursor::Cursors,
+        _config: Option<serde_json::Value>,
+    ) -> String {
+        let params = DocumentSymbolParams {
+            text_document: ls.doc_id("src/lib.cairo"),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+
+        match ls.send_request::<lsp_request!("textDocument/documentSymbol")>(params).unwrap() {
+            Some(DocumentSymbolResponse::Nested(symbols)) => {
+                let mut output = String::new();
+                render_symbols(&mut output, &symbols, 0);
+                output
+            }
+            Some(DocumentSymbolResponse::Flat(_)) => "flat response".to_string(),
+            None => "none response".to_string(),
+        }
+    }
+}
+
+fn render_symbols(output: &mut String, symbols: &[DocumentSymbol], depth: usize) {
+    for symbol in symbols {
+        let kind = match symbol.kind {
+            SymbolKind::MODULE => "mod",
+            SymbolKind::STRUCT => "struct",
+            SymbolKind::FIELD => "field",
+            SymbolKind::ENUM => "enum",
+            SymbolKind::ENUM_MEMBER => "variant",
+            SymbolKind::INTERFACE => "trait",
+            SymbolKind::OBJECT => "impl",
+            SymbolKind::METHOD => "method",
+            SymbolKind::FUNCTION => "fn",
+            SymbolKind::CONSTANT => "const",
+            SymbolKind::TYPE_PARAMETER => "type",
+            _ => "other",
+        };
+        let line = symbol.selection_range.start.line;
+        output.push_str(&format!("{}{kind} {} @ {line}\n", "    ".repeat(depth), symbol.name));
+        render_symbols(output, symbol.children.as_deref().unwrap_or_default(), depth + 1);
+    }
+}
@@ -0,0 +1,169 @@
+use indoc::indoc;
+use lsp_types::notification::ShowMessage;
+use lsp_types::{
+    CreateFilesParams, DeleteFilesParams, FileCreate, FileDelete, WorkspaceEdit, lsp_request,
+};
+
+use crate::support::cairo_project_toml::CAIRO_PROJECT_TOML_2025_12;
+use crate::support::cursor::index_in_text;
+use crate::support::normalize::normalize;
+use crate::support::{MockClient, sandbox};
+
+fn project() -> MockClient {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => indoc!(r#"
+                mod foo;
+                mod utils;
+
+                use utils::helper;
+
+                fn main() {
+                    helper();
+                }
+            "#),
+            "src/foo.cairo" => indoc!(r#"
+                fn foo() {}
+            "#),
+            "src/utils.cairo" => indoc!(r#"
+                pub fn helper() {}
+            "#),
+        }
+    };
+    ls.open_all_cairo_files_and_wait_for_project_update();
+    ls
+}
+
+/// Applies edits of the workspace edit to the given file and returns its new content.
+fn apply_edits(ls: &MockClient, edit: WorkspaceEdit, path: &str) -> String {
+    let mut changes = edit.changes.unwrap_or_default();
+    let mut edits = changes.remove(&ls.doc_id(path).uri).unwrap_or_default();
+    assert!(changes.is_empty(), "unexpected edits in other files: {changes:?}");
+
+    let mut content = ls.fixture.read_file(path);
+    edits.sort_by_key(|edit| edit.range.start);
+    for edit in edits.into_iter().rev() {
+        let start = index_in_text(&content, edit.range.start);
+        let end = index_in_text(&content, edit.range.end);
+        content.replace_range(start..end, &edit.new_text);
+    }
+    content
+}
+
+fn create_file(ls: &mut MockClient, path: &str) -> Option<WorkspaceEdit> {
+    let uri = ls.fixture.file_url(path).to_string();
+    ls.send_request::<lsp_request!("workspace/willCreateFiles")>(CreateFilesParams {
+        files: vec![FileCreate { uri }],
+    })
+}
+
+fn delete_file(ls: &mut MockClient, path: &str) -> Option<WorkspaceEdit> {
+    let uri = ls.fixture.file_url(path).to_string();
+    ls.send_request::<lsp_request!("workspace/willDeleteFiles")>(DeleteFilesParams {
+        files: vec![FileDelete { uri }],
+    })
+}
+
+#[test]
+fn create_in_crate_root() {
+    let mut ls = project();
+
+    let edit = create_file(&mut ls, "src/bar.cairo").unwrap();
+
+    insta::assert_snapshot!(apply_edits(&ls, edit, "src/lib.cairo"), @r"
+    mod foo;
+    mod utils;
+    mod bar;
+
+    use utils::helper;
+
+    fn main() {
+        helper();
+    }
+    ");
+}
+
+#[test]
+fn create_in_submodule() {
+    let mut ls = project();
+
+    let edit = create_file(&mut ls, "src/foo/bar.cairo").unwrap();
+
+    insta::assert_snapshot!(apply_edits(&ls, edit, "src/foo.cairo"), @r"
+    mod bar;
+    fn foo() {}
+    ");
+}
+
+#[test]
+fn create_already_declared() {
+    let mut ls = project();
+
+    let edit = create_file(&mut ls, "src/utils.cairo").unwrap();
+
+    assert_eq!(edit.changes, Some(Default::default()));
+}
+
+#[test]
+fn create_with_keyword_name() {
+    let mut ls = project();
+
+    let edit = create_file(&mut ls, "src/loop.cairo").unwrap();
+    assert_eq!(edit.changes, Some(Default::default()));
+
+    let edit = create_file(&mut ls, "src/impl.cairo").unwrap();
+    assert_eq!(edit.changes, Some(Default::default()));
+}
+
+#[test]
+fn delete_with_dangling_use() {
+    let mut ls = project();
+
+    let edit = delete_file(&mut ls, "src/utils.cairo").unwrap();
+
+    insta::assert_snapshot!(apply_edits(&ls, edit, "src/lib.cairo"), @r"
+    mod foo;
+
+    use utils::helper;
+
+    fn main() {
+        helper();
+    }
+    ");
+
+    let message = ls.wait_for_notification::<ShowMessage>(|_| true).message;
+    insta::assert_snapshot!(normalize(&ls, message), @r"
+    Deleting `utils` leaves dangling `use` statements:
+    [ROOT]/src/lib.cairo:4
+    ");
+}
+
+#[test]
+fn delete_declaration_sharing_line() {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => indoc!(r#"
+                mod foo; mod bar;
+                mod baz; fn main() {}
+            "#),
+            "src/foo.cairo" => "",
+            "src/bar.cairo" => "",
+            "src/baz.cairo" => "",
+        }
+    };
+    ls.open_all_cairo_files_and_wait_for_project_update();
+
+    let edit = delete_file(&mut ls, "src/foo.cairo").unwrap();
+    insta::assert_snapshot!(apply_edits(&ls, edit, "src/lib.cairo"), @r"
+    mod bar;
+    mod baz; fn main() {}
+    ");
+
+    let edit = delete_file(&mut ls, "src/baz.cairo").unwrap();
+    insta::assert_snapshot!(apply_edits(&ls, edit, "src/lib.cairo"), @r"
+    mod foo; mod bar;
+    fn main() {}
+    ");
+}
@@ -0,0 +1,125 @@
+use lsp_types::FoldingRange;
+
+use crate::support::insta::test_transform_plain;
+
+#[test]
+fn items_blocks_imports_and_comments() {
+    test_transform_plain!(FoldingRange, r#"
+    use core::array::ArrayTrait;
+    use core::option::OptionTrait;
+
+    // Computes the sum of values.
+    // Works on arrays of any length.
+    fn sum(values: Array<u32>) -> u32 {
+        let mut total = 0;
+        for value in values {
+            total += value;
+        };
+        total
+    }
+
+    #[derive(Drop)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    fn unwrap_or_zero(value: Option<u32>) -> u32 {
+        match value {
+            Option::Some(x) => x,
+            Option::None => 0,
+        }
+    }
+    "#, @r"
+    0..1 imports
+    3..4 comment
+    5..10
+    7..8
+    14..16
+    19..23
+    20..22
+    ");
+}
+
+#[test]
+fn single_line_items_are_not_folded() {
+    test_transform_plain!(FoldingRange, r#"
+    // A lonely comment.
+    fn foo() {}
+    struct Unit {}
+    "#, @"");
+}
+
+#[test]
+fn trait_impl_and_module_bodies() {
+    test_transform_plain!(FoldingRange, r#"
+    mod shapes {
+        trait Area<T> {
+            fn area(self: @T) -> u32;
+        }
+
+        impl SquareArea of Area<u32> {
+            fn area(self: @u32) -> u32 {
+                *self * *self
+            }
+        }
+    }
+    "#, @r"
+    0..9
+    1..2
+    5..8
+    6..7
+    ");
+}
+
+#[test]
+fn attribute_stacks_and_use_trees() {
+    test_transform_plain!(FoldingRange, r#"
+    use core::{
+        array::ArrayTrait,
+        option::OptionTrait,
+    };
+
+    #[derive(Drop)]
+    #[derive(Copy)]
+    struct Unit {}
+    "#, @r"
+    0..3 imports
+    5..6
+    ");
+}
+
+#[test]
+fn match_arms_without_blocks() {
+    test_transform_plain!(FoldingRange, r#"
+    fn describe(value: Option<u32>) -> u32 {
+        match value {
+            Option::Some(x) => x
+                + 1,
+            Option::None => {
+                0
+            },
+        }
+    }
+    "#, @r"
+    0..7
+    1..6
+    2..3
+    4..5
+    ");
+}
+
+#[test]
+fn trailing_comments_are_not_folded_with_next_lines() {
+    test_transform_plain!(FoldingRange, r#"
+    fn double() -> u32 {
+        let x = 1; // The first value.
+        // Doubled below,
+        // twice.
+        x * 2
+    }
+    "#, @r"
+    0..4
+    2..3 comment
+    ");
+}
@@ -0,0 +1,57 @@
+use lsp_types::{
+    ClientCapabilities, FoldingRange, FoldingRangeClientCapabilities, FoldingRangeKind,
+    FoldingRangeParams, TextDocumentClientCapabilities, lsp_request,
+};
+
+use crate::support::transform::Transformer;
+
+mod items;
+
+impl Transformer for FoldingRange {
+    fn capabilities(base: ClientCapabilities) -> ClientCapabilities {
+        ClientCapabilities {
+            text_document: base.text_document.or_else(Default::default).map(|it| {
+                TextDocumentClientCapabilities {
+                    folding_range: Some(FoldingRangeClientCapabilities {
+                        dynamic_registration: Some(false),
+                        line_folding_only: Some(true),
+                        ..Default::default()
+                    }),
+                    ..it
+                }
+            }),
+            ..base
+        }
+    }
+
+    fn transform(
+        mut ls: crate::support::MockClient,
+        _cursors: crate::support::cursor::Cursors,
+        _config: Option<serde_json::Value>,
+    ) -> String {
+        let params = FoldingRangeParams {
+            text_document: ls.doc_id("src/lib.cairo"),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+
+        let mut ranges = ls
+            .send_request::<lsp_request!("textDocument/foldingRange")>(params)
+            .unwrap()
+            .unwrap_or_default();
+        ranges.sort_by_key(|range| (range.start_line, range.end_line));
+
+        ranges
+            .into_iter()
+            .map(|range| {
+                let kind = match range.kind {
+                    Some(FoldingRangeKind::Comment) => " comment",
+                    Some(FoldingRangeKind::Imports) => " imports",
+                    Some(FoldingRangeKind::Region) => " region",
+                    None => "",
+                };
+                format!("{}..{}{kind}\n", range.start_line, range.end_line)
+            })
+            .collect()
+    }
+}
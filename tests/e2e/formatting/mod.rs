@@ -0,0 +1,101 @@
+use lsp_types::request::{OnTypeFormatting, RangeFormatting};
+use lsp_types::{
+    ClientCapabilities, DocumentOnTypeFormattingClientCapabilities, DocumentOnTypeFormattingParams,
+    DocumentRangeFormattingClientCapabilities, DocumentRangeFormattingParams,
+    TextDocumentClientCapabilities, TextDocumentPositionParams, TextEdit, lsp_request,
+};
+
+use crate::support::MockClient;
+use crate::support::cursor::{Cursors, index_in_text};
+use crate::support::transform::Transformer;
+
+mod on_type;
+mod range;
+
+impl Transformer for RangeFormatting {
+    fn capabilities(base: ClientCapabilities) -> ClientCapabilities {
+        ClientCapabilities {
+            text_document: base.text_document.or_else(Default::default).map(|it| {
+                TextDocumentClientCapabilities {
+                    range_formatting: Some(DocumentRangeFormattingClientCapabilities {
+                        dynamic_registration: Some(false),
+                    }),
+                    ..it
+                }
+            }),
+            ..base
+        }
+    }
+
+    fn transform(
+        mut ls: MockClient,
+        cursors: Cursors,
+        _additional_data: Option<serde_json::Value>,
+    ) -> String {
+        let params = DocumentRangeFormattingParams {
+            text_document: ls.doc_id("src/lib.cairo"),
+            range: cursors.assert_single_selection(),
+            options: Default::default(),
+            work_done_progress_params: Default::default(),
+        };
+        let edits = ls.send_request::<lsp_request!("textDocument/rangeFormatting")>(params);
+
+        apply_edits(ls.fixture.read_file("src/lib.cairo"), edits)
+    }
+}
+
+impl Transformer for OnTypeFormatting {
+    fn capabilities(base: ClientCapabilities) -> ClientCapabilities {
+        ClientCapabilities {
+            text_document: base.text_document.or_else(Default::default).map(|it| {
+                TextDocumentClientCapabilities {
+                    on_type_formatting: Some(DocumentOnTypeFormattingClientCapabilities {
+                        dynamic_registration: Some(false),
+                    }),
+                    ..it
+                }
+            }),
+            ..base
+        }
+    }
+
+    fn transform(
+        mut ls: MockClient,
+        cursors: Cursors,
+        _additional_data: Option<serde_json::Value>,
+    ) -> String {
+        let cairo = ls.fixture.read_file("src/lib.cairo");
+        let position = cursors.assert_single_caret();
+        let typed = cairo[..index_in_text(&cairo, position)]
+            .chars()
+            .last()
+            .expect("caret must be placed after the typed character");
+
+        let params = DocumentOnTypeFormattingParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: ls.doc_id("src/lib.cairo"),
+                position,
+            },
+            ch: typed.to_string(),
+            options: Default::default(),
+        };
+        let edits = ls.send_request::<lsp_request!("textDocument/onTypeFormatting")>(params);
+
+        apply_edits(cairo, edits)
+    }
+}
+
+fn apply_edits(mut cairo: String, edits: Option<Vec<TextEdit>>) -> String {
+    let Some(mut edits) = edits else {
+        return "none response".to_string();
+    };
+
+    // Edits do not overlap, applying them from the end keeps positions of the remaining ones valid.
+    edits.sort_by_key(|edit| edit.range.start);
+    for edit in edits.into_iter().rev() {
+        let start = index_in_text(&cairo, edit.range.start);
+        let end = index_in_text(&cairo, edit.range.end);
+        cairo.replace_range(start..end, &edit.new_text);
+    }
+    cairo
+}
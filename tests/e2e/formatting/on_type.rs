@@ -0,0 +1,55 @@
+use lsp_types::request::OnTypeFormatting;
+
+use crate::support::insta::test_transform_plain;
+
+#[test]
+fn semicolon() {
+    test_transform_plain!(OnTypeFormatting, r#"
+    fn main() {
+        let   a=1;
+        let b   =  a+1;<caret>
+    }
+    "#, @r"
+    fn main() {
+        let   a=1;
+        let b = a + 1;
+    }
+    ");
+}
+
+#[test]
+fn closing_brace() {
+    test_transform_plain!(OnTypeFormatting, r#"
+    fn main() {
+        let   a=1;
+        if a==1 {   return;   }<caret>
+    }
+    "#, @r"
+    fn main() {
+        let   a=1;
+        if a == 1 {
+            return;
+        }
+    }
+    ");
+}
+
+#[test]
+fn newline() {
+    test_transform_plain!(OnTypeFormatting, r#"
+    fn   foo( )->u32 {
+        1
+    }
+
+    fn   bar( )->u32 {   2   }
+    <caret>
+    "#, @r"
+    fn   foo( )->u32 {
+        1
+    }
+
+    fn bar() -> u32 {
+        2
+    }
+    ");
+}
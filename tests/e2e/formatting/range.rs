@@ -0,0 +1,92 @@
+use lsp_types::request::RangeFormatting;
+
+use crate::support::insta::test_transform_plain;
+
+#[test]
+fn selected_statements() {
+    test_transform_plain!(RangeFormatting, r#"
+    fn main() {
+        let   a=1;
+        <sel>let b   =  a+1;
+        let c=b *   2;</sel>
+        let   d=c;
+    }
+    "#, @r"
+    fn main() {
+        let   a=1;
+        let b = a + 1;
+        let c = b * 2;
+        let   d=c;
+    }
+    ");
+}
+
+#[test]
+fn selection_within_statement() {
+    test_transform_plain!(RangeFormatting, r#"
+    fn main() {
+        let   a=1;
+        let b   =  <sel>a</sel>+1;
+    }
+    "#, @r"
+    fn main() {
+        let   a=1;
+        let b = a + 1;
+    }
+    ");
+}
+
+#[test]
+fn selected_items() {
+    test_transform_plain!(RangeFormatting, r#"
+    <sel>fn foo( )->u32{1}
+    fn bar()   {}</sel>
+
+    fn baz( ) {}
+    "#, @r"
+    fn foo() -> u32 {
+        1
+    }
+    fn bar() {}
+
+    fn baz( ) {}
+    ");
+}
+
+#[test]
+fn nested_item() {
+    test_transform_plain!(RangeFormatting, r#"
+    mod inner {
+        struct Point {
+            x: u32,
+            <sel>y:u32</sel>,
+        }
+
+        fn   unchanged( ) {}
+    }
+    "#, @r"
+    mod inner {
+        struct Point {
+            x: u32,
+            y: u32,
+        }
+
+        fn   unchanged( ) {}
+    }
+    ");
+}
+
+#[test]
+fn syntax_errors_are_left_untouched() {
+    test_transform_plain!(RangeFormatting, r#"
+    fn main() {
+        <sel>let a =  ;
+        let   b=1;</sel>
+    }
+    "#, @r"
+    fn main() {
+        let a =  ;
+        let b = 1;
+    }
+    ");
+}
@@ -0,0 +1,152 @@
+use lsp_types::request::GotoDeclaration;
+
+use crate::support::insta::test_transform_plain;
+
+#[test]
+fn imported_item() {
+    test_transform_plain!(GotoDeclaration, r#"
+    mod shapes {
+        pub struct Square {
+            pub side: u32,
+        }
+    }
+
+    use shapes::Square;
+
+    fn main() {
+        let _ = Squ<caret>are { side: 1 };
+    }
+    "#, @r"
+    mod shapes {
+        pub struct Square {
+            pub side: u32,
+        }
+    }
+
+    use shapes::<sel>Square</sel>;
+
+    fn main() {
+        let _ = Square { side: 1 };
+    }
+    ");
+}
+
+#[test]
+fn reexported_item() {
+    test_transform_plain!(GotoDeclaration, r#"
+    mod shapes {
+        pub use inner::Square;
+
+        mod inner {
+            pub struct Square {
+                pub side: u32,
+            }
+        }
+    }
+
+    use shapes::Square;
+
+    fn area(square: Squ<caret>are) -> u32 {
+        square.side * square.side
+    }
+    "#, @r"
+    mod shapes {
+        pub use inner::Square;
+
+        mod inner {
+            pub struct Square {
+                pub side: u32,
+            }
+        }
+    }
+
+    use shapes::<sel>Square</sel>;
+
+    fn area(square: Square) -> u32 {
+        square.side * square.side
+    }
+    ");
+}
+
+#[test]
+fn aliased_import() {
+    test_transform_plain!(GotoDeclaration, r#"
+    mod shapes {
+        pub fn unit() -> u32 {
+            1
+        }
+    }
+
+    use shapes::unit as one;
+
+    fn main() {
+        let _ = on<caret>e();
+    }
+    "#, @r"
+    mod shapes {
+        pub fn unit() -> u32 {
+            1
+        }
+    }
+
+    use shapes::unit as <sel>one</sel>;
+
+    fn main() {
+        let _ = one();
+    }
+    ");
+}
+
+#[test]
+fn imported_module_in_path() {
+    test_transform_plain!(GotoDeclaration, r#"
+    mod geometry {
+        pub mod shapes {
+            pub fn unit() -> u32 {
+                1
+            }
+        }
+    }
+
+    use geometry::shapes;
+
+    fn main() {
+        let _ = shap<caret>es::unit();
+    }
+    "#, @r"
+    mod geometry {
+        pub mod shapes {
+            pub fn unit() -> u32 {
+                1
+            }
+        }
+    }
+
+    use geometry::<sel>shapes</sel>;
+
+    fn main() {
+        let _ = shapes::unit();
+    }
+    ");
+}
+
+#[test]
+fn item_not_imported() {
+    test_transform_plain!(GotoDeclaration, r#"
+    fn unit() -> u32 {
+        1
+    }
+
+    fn main() {
+        let _ = un<caret>it();
+    }
+    "#, @r"
+    fn <sel>unit</sel>() -> u32 {
+        1
+    }
+
+    fn main() {
+        let _ = unit();
+    }
+    ");
+}
@@ -0,0 +1,24 @@
+use lsp_types::ClientCapabilities;
+use lsp_types::request::GotoDeclaration;
+
+use crate::support::MockClient;
+use crate::support::cursor::Cursors;
+use crate::support::goto::{render_goto, with_goto_capability};
+use crate::support::transform::Transformer;
+
+mod imports;
+mod trait_items;
+
+impl Transformer for GotoDeclaration {
+    fn capabilities(base: ClientCapabilities) -> ClientCapabilities {
+        with_goto_capability(base, |it| &mut it.declaration)
+    }
+
+    fn transform(
+        ls: MockClient,
+        cursors: Cursors,
+        _additional_data: Option<serde_json::Value>,
+    ) -> String {
+        render_goto::<Self>(ls, cursors)
+    }
+}
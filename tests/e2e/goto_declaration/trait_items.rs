@@ -0,0 +1,83 @@
+use lsp_types::request::GotoDeclaration;
+
+use crate::support::insta::test_transform_plain;
+
+#[test]
+fn method_call() {
+    test_transform_plain!(GotoDeclaration, r#"
+    trait Shape<T> {
+        fn area(self: @T) -> u32;
+    }
+
+    #[derive(Drop)]
+    struct Square {
+        side: u32,
+    }
+
+    impl SquareShape of Shape<Square> {
+        fn area(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+
+    fn main() {
+        let square = Square { side: 2 };
+        let _ = square.ar<caret>ea();
+    }
+    "#, @r"
+    trait Shape<T> {
+        fn <sel>area</sel>(self: @T) -> u32;
+    }
+
+    #[derive(Drop)]
+    struct Square {
+        side: u32,
+    }
+
+    impl SquareShape of Shape<Square> {
+        fn area(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+
+    fn main() {
+        let square = Square { side: 2 };
+        let _ = square.area();
+    }
+    ");
+}
+
+#[test]
+fn impl_function_name() {
+    test_transform_plain!(GotoDeclaration, r#"
+    trait Shape<T> {
+        fn area(self: @T) -> u32;
+    }
+
+    #[derive(Drop)]
+    struct Square {
+        side: u32,
+    }
+
+    impl SquareShape of Shape<Square> {
+        fn ar<caret>ea(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+    "#, @r"
+    trait Shape<T> {
+        fn <sel>area</sel>(self: @T) -> u32;
+    }
+
+    #[derive(Drop)]
+    struct Square {
+        side: u32,
+    }
+
+    impl SquareShape of Shape<Square> {
+        fn area(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+    ");
+}
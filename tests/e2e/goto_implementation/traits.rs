@@ -0,0 +1,204 @@
+use lsp_types::request::GotoImplementation;
+
+use crate::support::insta::test_transform_plain;
+
+#[test]
+fn trait_lists_impls() {
+    test_transform_plain!(GotoImplementation, r#"
+    trait Sha<caret>pe<T> {
+        fn area(self: @T) -> u32;
+    }
+
+    #[derive(Drop)]
+    struct Square {
+        side: u32,
+    }
+
+    #[derive(Drop)]
+    struct Circle {
+        radius: u32,
+    }
+
+    impl SquareShape of Shape<Square> {
+        fn area(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+
+    impl CircleShape of Shape<Circle> {
+        fn area(self: @Circle) -> u32 {
+            3 * *self.radius * *self.radius
+        }
+    }
+
+    fn main() {
+        let square = Square { side: 2 };
+        let _ = square.area();
+    }
+    "#, @r"
+    trait Shape<T> {
+        fn area(self: @T) -> u32;
+    }
+
+    #[derive(Drop)]
+    struct Square {
+        side: u32,
+    }
+
+    #[derive(Drop)]
+    struct Circle {
+        radius: u32,
+    }
+
+    impl <sel>SquareShape</sel> of Shape<Square> {
+        fn area(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+
+    impl <sel>CircleShape</sel> of Shape<Circle> {
+        fn area(self: @Circle) -> u32 {
+            3 * *self.radius * *self.radius
+        }
+    }
+
+    fn main() {
+        let square = Square { side: 2 };
+        let _ = square.area();
+    }
+    ");
+}
+
+#[test]
+fn trait_function_lists_impl_functions() {
+    test_transform_plain!(GotoImplementation, r#"
+    trait Shape<T> {
+        fn ar<caret>ea(self: @T) -> u32;
+    }
+
+    #[derive(Drop)]
+    struct Square {
+        side: u32,
+    }
+
+    #[derive(Drop)]
+    struct Circle {
+        radius: u32,
+    }
+
+    impl SquareShape of Shape<Square> {
+        fn area(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+
+    impl CircleShape of Shape<Circle> {
+        fn area(self: @Circle) -> u32 {
+            3 * *self.radius * *self.radius
+        }
+    }
+
+    fn main() {
+        let square = Square { side: 2 };
+        let _ = square.area();
+    }
+    "#, @r"
+    trait Shape<T> {
+        fn area(self: @T) -> u32;
+    }
+
+    #[derive(Drop)]
+    struct Square {
+        side: u32,
+    }
+
+    #[derive(Drop)]
+    struct Circle {
+        radius: u32,
+    }
+
+    impl SquareShape of Shape<Square> {
+        fn <sel>area</sel>(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+
+    impl CircleShape of Shape<Circle> {
+        fn <sel>area</sel>(self: @Circle) -> u32 {
+            3 * *self.radius * *self.radius
+        }
+    }
+
+    fn main() {
+        let square = Square { side: 2 };
+        let _ = square.area();
+    }
+    ");
+}
+
+#[test]
+fn method_call_goes_to_inferred_impl() {
+    test_transform_plain!(GotoImplementation, r#"
+    trait Shape<T> {
+        fn area(self: @T) -> u32;
+    }
+
+    #[derive(Drop)]
+    struct Square {
+        side: u32,
+    }
+
+    #[derive(Drop)]
+    struct Circle {
+        radius: u32,
+    }
+
+    impl SquareShape of Shape<Square> {
+        fn area(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+
+    impl CircleShape of Shape<Circle> {
+        fn area(self: @Circle) -> u32 {
+            3 * *self.radius * *self.radius
+        }
+    }
+
+    fn main() {
+        let square = Square { side: 2 };
+        let _ = square.ar<caret>ea();
+    }
+    "#, @r"
+    trait Shape<T> {
+        fn area(self: @T) -> u32;
+    }
+
+    #[derive(Drop)]
+    struct Square {
+        side: u32,
+    }
+
+    #[derive(Drop)]
+    struct Circle {
+        radius: u32,
+    }
+
+    impl SquareShape of Shape<Square> {
+        fn <sel>area</sel>(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+
+    impl CircleShape of Shape<Circle> {
+        fn area(self: @Circle) -> u32 {
+            3 * *self.radius * *self.radius
+        }
+    }
+
+    fn main() {
+        let square = Square { side: 2 };
+        let _ = square.area();
+    }
+    ");
+}
@@ -0,0 +1,204 @@
+use lsp_types::request::GotoTypeDefinition;
+
+use crate::support::insta::test_transform_plain;
+
+#[test]
+fn variable() {
+    test_transform_plain!(GotoTypeDefinition, r#"
+    #[derive(Drop)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    fn main() {
+        let point = Point { x: 1, y: 2 };
+        let _ = poi<caret>nt;
+    }
+    "#, @r"
+    #[derive(Drop)]
+    struct <sel>Point</sel> {
+        x: u32,
+        y: u32,
+    }
+
+    fn main() {
+        let point = Point { x: 1, y: 2 };
+        let _ = point;
+    }
+    ");
+}
+
+#[test]
+fn parameter() {
+    test_transform_plain!(GotoTypeDefinition, r#"
+    #[derive(Drop)]
+    enum Direction {
+        Up,
+        Down,
+    }
+
+    fn turn(direct<caret>ion: Direction) {}
+    "#, @r"
+    #[derive(Drop)]
+    enum <sel>Direction</sel> {
+        Up,
+        Down,
+    }
+
+    fn turn(direction: Direction) {}
+    ");
+}
+
+#[test]
+fn field_access() {
+    test_transform_plain!(GotoTypeDefinition, r#"
+    #[derive(Drop)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    #[derive(Drop)]
+    struct Line {
+        start: Point,
+        end: Point,
+    }
+
+    fn length(line: @Line) -> u32 {
+        let start = line.st<caret>art;
+        *start.x
+    }
+    "#, @r"
+    #[derive(Drop)]
+    struct <sel>Point</sel> {
+        x: u32,
+        y: u32,
+    }
+
+    #[derive(Drop)]
+    struct Line {
+        start: Point,
+        end: Point,
+    }
+
+    fn length(line: @Line) -> u32 {
+        let start = line.start;
+        *start.x
+    }
+    ");
+}
+
+#[test]
+fn call_expression() {
+    test_transform_plain!(GotoTypeDefinition, r#"
+    #[derive(Drop)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    fn origin() -> Point {
+        Point { x: 0, y: 0 }
+    }
+
+    fn main() {
+        let _ = origin(<caret>);
+    }
+    "#, @r"
+    #[derive(Drop)]
+    struct <sel>Point</sel> {
+        x: u32,
+        y: u32,
+    }
+
+    fn origin() -> Point {
+        Point { x: 0, y: 0 }
+    }
+
+    fn main() {
+        let _ = origin();
+    }
+    ");
+}
+
+#[test]
+fn wrappers_are_unwrapped() {
+    test_transform_plain!(GotoTypeDefinition, r#"
+    #[derive(Drop)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    fn main() {
+        let points: @Array<Box<Point>> = @array![BoxTrait::new(Point { x: 0, y: 0 })];
+        let _ = poi<caret>nts;
+    }
+    "#, @r"
+    #[derive(Drop)]
+    struct <sel>Point</sel> {
+        x: u32,
+        y: u32,
+    }
+
+    fn main() {
+        let points: @Array<Box<Point>> = @array![BoxTrait::new(Point { x: 0, y: 0 })];
+        let _ = points;
+    }
+    ");
+}
+
+#[test]
+fn several_types() {
+    test_transform_plain!(GotoTypeDefinition, r#"
+    #[derive(Drop)]
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    #[derive(Drop)]
+    enum Direction {
+        Up,
+        Down,
+    }
+
+    fn main() {
+        let pair: Option<(Point, Direction)> = Option::Some((Point { x: 0, y: 0 }, Direction::Up));
+        let _ = pa<caret>ir;
+    }
+    "#, @r"
+    #[derive(Drop)]
+    struct <sel>Point</sel> {
+        x: u32,
+        y: u32,
+    }
+
+    #[derive(Drop)]
+    enum <sel>Direction</sel> {
+        Up,
+        Down,
+    }
+
+    fn main() {
+        let pair: Option<(Point, Direction)> = Option::Some((Point { x: 0, y: 0 }, Direction::Up));
+        let _ = pair;
+    }
+    ");
+}
+
+#[test]
+fn primitive_type_outside_of_user_code() {
+    test_transform_plain!(GotoTypeDefinition, r#"
+    fn main() {
+        let number = 5_u32;
+        let _ = num<caret>ber;
+    }
+    "#, @r"
+    fn main() {
+        let number = 5_u32;
+        let _ = number;
+    }
+    ");
+}
@@ -0,0 +1,23 @@
+use lsp_types::ClientCapabilities;
+use lsp_types::request::GotoTypeDefinition;
+
+use crate::support::MockClient;
+use crate::support::cursor::Cursors;
+use crate::support::goto::{render_goto, with_goto_capability};
+use crate::support::transform::Transformer;
+
+mod expressions;
+
+impl Transformer for GotoTypeDefinition {
+    fn capabilities(base: ClientCapabilities) -> ClientCapabilities {
+        with_goto_capability(base, |it| &mut it.type_definition)
+    }
+
+    fn transform(
+        ls: MockClient,
+        cursors: Cursors,
+        _additional_data: Option<serde_json::Value>,
+    ) -> String {
+        render_goto::<Self>(ls, cursors)
+    }
+}
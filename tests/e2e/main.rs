@@ -1,18 +1,33 @@
 mod analysis;
+mod call_hierarchy;
+mod cancellation;
 mod code_actions;
 mod code_lens;
 mod completions;
 mod document_highlight;
+mod document_symbols;
 mod external_tools_config;
+mod file_operations;
 mod find_references;
+mod folding_ranges;
+mod formatting;
+mod goto_declaration;
 mod goto_definition;
+mod goto_implementation;
+mod goto_type_definition;
 mod hover;
 mod inlay_hints;
 mod linter;
 mod macros;
 mod no_config_reload;
+mod pull_diagnostics;
 mod rename;
 mod scarb;
+mod selection_range;
 mod semantic_tokens;
+mod signature_help;
 mod support;
+mod text_document_sync;
+mod type_hierarchy;
 mod workspace_configuration;
+mod workspace_symbols;
@@ -0,0 +1,161 @@
+use indoc::indoc;
+use lsp_types::{
+    ClientCapabilities, Diagnostic, DiagnosticClientCapabilities, DocumentDiagnosticParams,
+    DocumentDiagnosticReport, DocumentDiagnosticReportResult, PreviousResultId,
+    TextDocumentClientCapabilities, WorkspaceDiagnosticParams, WorkspaceDiagnosticReportResult,
+    WorkspaceDocumentDiagnosticReport, lsp_request,
+};
+
+use crate::support::cairo_project_toml::CAIRO_PROJECT_TOML_2025_12;
+use crate::support::{MockClient, sandbox};
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: base.text_document.or_else(Default::default).map(|it| {
+            TextDocumentClientCapabilities {
+                diagnostic: Some(DiagnosticClientCapabilities {
+                    dynamic_registration: Some(false),
+                    related_document_support: Some(true),
+                }),
+                ..it
+            }
+        }),
+        ..base
+    }
+}
+
+fn project() -> MockClient {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => indoc!(r#"
+                mod foo;
+
+                fn main() {
+                    undefined_function();
+                }
+            "#),
+            "src/foo.cairo" => indoc!(r#"
+                fn bar() {}
+            "#),
+        }
+        client_capabilities = caps;
+    };
+    ls.open_all_cairo_files_and_wait_for_project_update();
+    ls
+}
+
+fn document_diagnostic(
+    ls: &mut MockClient,
+    path: &str,
+    previous_result_id: Option<String>,
+) -> DocumentDiagnosticReport {
+    let DocumentDiagnosticReportResult::Report(report) = ls
+        .send_request::<lsp_request!("textDocument/diagnostic")>(DocumentDiagnosticParams {
+            text_document: ls.doc_id(path),
+            identifier: None,
+            previous_result_id,
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })
+    else {
+        panic!("expected a full diagnostic report");
+    };
+    report
+}
+
+fn render(diagnostics: &[Diagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(|diagnostic| format!("{}: {}", diagnostic.range.start.line, diagnostic.message))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn document_report() {
+    let mut ls = project();
+
+    let DocumentDiagnosticReport::Full(report) =
+        document_diagnostic(&mut ls, "src/lib.cairo", None)
+    else {
+        panic!("expected a full report");
+    };
+    let report = report.full_document_diagnostic_report;
+    insta::assert_snapshot!(render(&report.items), @"3: Function not found.");
+
+    let result_id = report.result_id.expect("full reports should have a result id");
+    let unchanged = document_diagnostic(&mut ls, "src/lib.cairo", Some(result_id.clone()));
+    let DocumentDiagnosticReport::Unchanged(unchanged) = unchanged else {
+        panic!("expected an unchanged report");
+    };
+    assert_eq!(unchanged.unchanged_document_diagnostic_report.result_id, result_id);
+}
+
+#[test]
+fn document_report_with_outdated_result_id() {
+    let mut ls = project();
+
+    let report = document_diagnostic(&mut ls, "src/foo.cairo", Some("outdated".to_string()));
+    let DocumentDiagnosticReport::Full(report) = report else {
+        panic!("expected a full report");
+    };
+    assert!(report.full_document_diagnostic_report.items.is_empty());
+}
+
+#[test]
+fn workspace_report() {
+    let mut ls = project();
+    let lib_url = ls.doc_id("src/lib.cairo").uri;
+    let foo_url = ls.doc_id("src/foo.cairo").uri;
+
+    let foo_result_id = {
+        let DocumentDiagnosticReport::Full(report) =
+            document_diagnostic(&mut ls, "src/foo.cairo", None)
+        else {
+            panic!("expected a full report");
+        };
+        report.full_document_diagnostic_report.result_id.unwrap()
+    };
+
+    let WorkspaceDiagnosticReportResult::Report(report) = ls
+        .send_request::<lsp_request!("workspace/diagnostic")>(WorkspaceDiagnosticParams {
+            identifier: None,
+            previous_result_ids: vec![PreviousResultId {
+                uri: foo_url.clone(),
+                value: foo_result_id,
+            }],
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        })
+    else {
+        panic!("expected a full workspace report");
+    };
+
+    let mut reports = report
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            WorkspaceDocumentDiagnosticReport::Full(report) if report.uri == lib_url => {
+                Some(format!(
+                    "lib.cairo full\n{}",
+                    render(&report.full_document_diagnostic_report.items)
+                ))
+            }
+            WorkspaceDocumentDiagnosticReport::Unchanged(report) if report.uri == foo_url => {
+                Some("foo.cairo unchanged".to_string())
+            }
+            WorkspaceDocumentDiagnosticReport::Full(report) if report.uri == foo_url => {
+                Some("foo.cairo full".to_string())
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    reports.sort();
+
+    insta::assert_snapshot!(reports.join("\n"), @r"
+    foo.cairo unchanged
+    lib.cairo full
+    3: Function not found.
+    ");
+}
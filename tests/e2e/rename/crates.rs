@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+
+use indoc::indoc;
+use lsp_types::request::Rename;
+use lsp_types::{
+    Position, PrepareRenameResponse, Range, RenameParams, TextDocumentPositionParams, lsp_request,
+};
+
+use super::extract_sorted_text_edits_and_file_renames;
+use crate::support::cursor::render_text_edits_and_file_renames;
+use crate::support::transform::Transformer;
+use crate::support::{MockClient, sandbox};
+
+fn workspace() -> MockClient {
+    let mut ls = sandbox! {
+        files {
+            "Scarb.toml" => indoc!(r#"
+                [workspace]
+                members = ["a", "b"]
+
+                [workspace.dependencies]
+                b = { path = "b" }
+            "#),
+            "a/Scarb.toml" => indoc!(r#"
+                [package]
+                name = "a"
+                version = "0.1.0"
+                edition = "2025_12"
+
+                [dependencies]
+                b = { workspace = true }
+            "#),
+            "a/src/lib.cairo" => indoc!(r#"
+                use b::Foo;
+
+                fn main() -> Foo {
+                    b::foo()
+                }
+            "#),
+            "b/Scarb.toml" => indoc!(r#"
+                [package]
+                name = "b"
+                version = "0.1.0"
+                edition = "2025_12"
+            "#),
+            "b/src/lib.cairo" => indoc!(r#"
+                pub struct Foo {}
+
+                pub fn foo() -> Foo {
+                    Foo {}
+                }
+            "#),
+        }
+        client_capabilities = Rename::capabilities;
+    };
+    ls.open_and_wait_for_project_update("a/src/lib.cairo");
+    ls
+}
+
+/// Renames the crate at the position in the given file and renders all changed files.
+fn rename_crate(ls: &mut MockClient, path: &str, position: Position) -> String {
+    let file_contents = ls
+        .fixture
+        .files()
+        .iter()
+        .map(|path| {
+            (
+                ls.fixture.file_url(path),
+                (path.to_string_lossy().to_string(), ls.fixture.read_file(path)),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    let params = RenameParams {
+        text_document_position: TextDocumentPositionParams {
+            text_document: ls.doc_id(path),
+            position,
+        },
+        new_name: "renamed".to_string(),
+        work_done_progress_params: Default::default(),
+    };
+    let edit = ls.send_request::<lsp_request!("textDocument/rename")>(params).unwrap();
+    let (text_edits, _) = extract_sorted_text_edits_and_file_renames(edit);
+
+    render_text_edits_and_file_renames(text_edits, HashMap::new(), &file_contents)
+}
+
+const RENAMED_WORKSPACE: &str = indoc!(
+    r#"
+    // → Scarb.toml
+    [workspace]
+    members = ["a", "b"]
+
+    [workspace.dependencies]
+    renamed = { path = "b" }
+
+    // → a/Scarb.toml
+    [package]
+    name = "a"
+    version = "0.1.0"
+    edition = "2025_12"
+
+    [dependencies]
+    renamed = { workspace = true }
+
+    // → a/src/lib.cairo
+    use renamed::Foo;
+
+    fn main() -> Foo {
+        renamed::foo()
+    }
+
+    // → b/Scarb.toml
+    [package]
+    name = "renamed"
+    version = "0.1.0"
+    edition = "2025_12"
+
+"#
+);
+
+#[test]
+fn from_path_segment() {
+    let mut ls = workspace();
+
+    let report = rename_crate(&mut ls, "a/src/lib.cairo", Position::new(0, 4));
+
+    assert_eq!(report, RENAMED_WORKSPACE);
+}
+
+#[test]
+fn from_manifest() {
+    let mut ls = workspace();
+
+    let report = rename_crate(&mut ls, "b/Scarb.toml", Position::new(1, 8));
+
+    assert_eq!(report, RENAMED_WORKSPACE);
+}
+
+#[test]
+fn prepare_in_manifest() {
+    let mut ls = workspace();
+
+    let response =
+        ls.send_request::<lsp_request!("textDocument/prepareRename")>(TextDocumentPositionParams {
+            text_document: ls.doc_id("b/Scarb.toml"),
+            position: Position::new(1, 8),
+        });
+
+    assert_eq!(
+        response,
+        Some(PrepareRenameResponse::RangeWithPlaceholder {
+            range: Range::new(Position::new(1, 8), Position::new(1, 9)),
+            placeholder: "b".to_string(),
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Only the package name can be renamed in Scarb.toml")]
+fn other_manifest_key() {
+    let mut ls = workspace();
+
+    rename_crate(&mut ls, "b/Scarb.toml", Position::new(2, 12));
+}
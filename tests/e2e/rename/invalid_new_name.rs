@@ -31,3 +31,17 @@ fn invalid_new_name_with_macros() {
     Some(additional_data)
     );
 }
+
+#[test]
+#[should_panic(expected = "`match` is not a valid identifier")]
+fn keyword_new_name() {
+    let additional_data = json!( {
+        "new_name" : r"match"
+    });
+
+    test_transform_plain!(Rename, r"
+    fn fu<caret>nc() {}
+    ", @"",
+    Some(additional_data)
+    );
+}
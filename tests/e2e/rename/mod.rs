@@ -16,11 +16,13 @@ use crate::support::cursor::{Cursors, render_text_edits_and_file_renames};
 use crate::support::transform::Transformer;
 
 mod consts;
+mod crates;
 mod enums;
 mod fns;
 mod invalid_new_name;
 mod macros;
 mod modules;
+mod prepare;
 mod structs;
 mod traits;
 mod types;
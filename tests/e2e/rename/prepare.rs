@@ -0,0 +1,119 @@
+use lsp_types::request::PrepareRenameRequest;
+use lsp_types::{
+    ClientCapabilities, PrepareRenameResponse, RenameClientCapabilities,
+    TextDocumentClientCapabilities, TextDocumentPositionParams, lsp_request,
+};
+
+use crate::support::MockClient;
+use crate::support::cursor::{Cursors, render_selections_with_attrs};
+use crate::support::insta::test_transform_plain;
+use crate::support::transform::Transformer;
+
+impl Transformer for PrepareRenameRequest {
+    fn capabilities(base: ClientCapabilities) -> ClientCapabilities {
+        ClientCapabilities {
+            text_document: base.text_document.or_else(Default::default).map(|it| {
+                TextDocumentClientCapabilities {
+                    rename: Some(RenameClientCapabilities {
+                        dynamic_registration: Some(false),
+                        prepare_support: Some(true),
+                        prepare_support_default_behavior: None,
+                        honors_change_annotations: None,
+                    }),
+                    ..it
+                }
+            }),
+            ..base
+        }
+    }
+
+    fn transform(
+        mut ls: MockClient,
+        cursors: Cursors,
+        _config: Option<serde_json::Value>,
+    ) -> String {
+        let cairo = ls.fixture.read_file("src/lib.cairo");
+        let params = TextDocumentPositionParams {
+            text_document: ls.doc_id("src/lib.cairo"),
+            position: cursors.assert_single_caret(),
+        };
+
+        match ls.send_request::<lsp_request!("textDocument/prepareRename")>(params) {
+            Some(PrepareRenameResponse::RangeWithPlaceholder { range, placeholder }) => {
+                render_selections_with_attrs(&cairo, &[(range, Some(placeholder))])
+            }
+            Some(response) => panic!("unexpected response: {response:?}"),
+            None => "none response".to_string(),
+        }
+    }
+}
+
+#[test]
+fn function() {
+    test_transform_plain!(PrepareRenameRequest, r"
+    fn fu<caret>nc() {}
+
+    fn main() {
+        func();
+    }
+    ", @r"
+    fn <sel=func>func</sel>() {}
+
+    fn main() {
+        func();
+    }
+    ")
+}
+
+#[test]
+fn variable_usage() {
+    test_transform_plain!(PrepareRenameRequest, r"
+    fn main() {
+        let abc = 1;
+        let _ = a<caret>bc;
+    }
+    ", @r"
+    fn main() {
+        let abc = 1;
+        let _ = <sel=abc>abc</sel>;
+    }
+    ")
+}
+
+#[test]
+fn comment() {
+    test_transform_plain!(PrepareRenameRequest, r"
+    // fu<caret>nc
+    fn func() {}
+    ", @"none response")
+}
+
+#[test]
+#[should_panic(expected = "Renaming via `Self` reference is not supported.")]
+fn self_reference() {
+    test_transform_plain!(PrepareRenameRequest, r"
+    trait Foo {
+        fn foo() -> Se<caret>lf;
+    }
+    ", @"")
+}
+
+#[test]
+#[should_panic(expected = "Cannot rename `new` because it is defined in the corelib")]
+fn corelib_item() {
+    test_transform_plain!(PrepareRenameRequest, r"
+    fn main() {
+        let _ = core::array::ArrayTrait::<felt252>::ne<caret>w();
+    }
+    ", @"")
+}
+
+#[test]
+#[should_panic(expected = "Renaming builtin inline macros is not supported")]
+fn builtin_inline_macro() {
+    test_transform_plain!(PrepareRenameRequest, r"
+    fn main() {
+        let _ = arr<caret>ay![1, 2, 3];
+    }
+    ", @"")
+}
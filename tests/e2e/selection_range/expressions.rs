@@ -0,0 +1,37 @@
+use lsp_types::SelectionRange;
+
+use crate::support::insta::test_transform_plain;
+
+#[test]
+fn call_argument() {
+    test_transform_plain!(SelectionRange, r#"
+    fn main() {
+        let x = add(1, value<caret>s.len());
+    }
+    "#, @r"
+        let x = add(1, <sel>values</sel>.len());
+    ---
+        let x = add(1, <sel>values.len()</sel>);
+    ---
+        let x = add(<sel>1, values.len()</sel>);
+    ---
+        let x = add<sel>(1, values.len())</sel>;
+    ---
+        let x = <sel>add(1, values.len())</sel>;
+    ---
+        <sel>let x = add(1, values.len());</sel>
+    ---
+    fn main() <sel>{
+        let x = add(1, values.len());
+    }</sel>
+    ---
+    <sel>fn main() {
+        let x = add(1, values.len());
+    }</sel>
+    ---
+    <sel>fn main() {
+        let x = add(1, values.len());
+    }
+    </sel>
+    ");
+}
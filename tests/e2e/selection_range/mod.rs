@@ -0,0 +1,54 @@
+use lsp_types::{
+    ClientCapabilities, SelectionRange, SelectionRangeClientCapabilities, SelectionRangeParams,
+    TextDocumentClientCapabilities, lsp_request,
+};
+
+use crate::support::cursor::peek_selection;
+use crate::support::transform::Transformer;
+
+mod expressions;
+
+impl Transformer for SelectionRange {
+    fn capabilities(base: ClientCapabilities) -> ClientCapabilities {
+        ClientCapabilities {
+            text_document: base.text_document.or_else(Default::default).map(|it| {
+                TextDocumentClientCapabilities {
+                    selection_range: Some(SelectionRangeClientCapabilities {
+                        dynamic_registration: Some(false),
+                    }),
+                    ..it
+                }
+            }),
+            ..base
+        }
+    }
+
+    fn transform(
+        mut ls: crate::support::MockClient,
+        cursors: crate::support::cursor::Cursors,
+        _config: Option<serde_json::Value>,
+    ) -> String {
+        let cairo = ls.fixture.read_file("src/lib.cairo");
+        let position = cursors.assert_single_caret();
+
+        let params = SelectionRangeParams {
+            text_document: ls.doc_id("src/lib.cairo"),
+            positions: vec![position],
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+
+        let ranges =
+            ls.send_request::<lsp_request!("textDocument/selectionRange")>(params).unwrap();
+        let [selection_range] = ranges.unwrap_or_default().try_into().unwrap();
+
+        let mut steps = vec![];
+        let mut current = Some(&selection_range);
+        while let Some(selection_range) = current {
+            steps.push(peek_selection(&cairo, &selection_range.range));
+            current = selection_range.parent.as_deref();
+        }
+
+        steps.join("---\n")
+    }
+}
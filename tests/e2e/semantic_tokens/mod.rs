@@ -1,17 +1,21 @@
-use cairo_language_server::testing::SemanticTokenKind;
+use cairo_language_server::testing::{SemanticTokenKind, SemanticTokenModifiers};
 use lsp_types::{
-    ClientCapabilities, Position, Range, SemanticTokens, SemanticTokensClientCapabilities,
-    SemanticTokensClientCapabilitiesRequests, SemanticTokensFullOptions, SemanticTokensParams,
-    SemanticTokensResult, TextDocumentClientCapabilities, lsp_request,
+    ClientCapabilities, Position, Range, SemanticToken, SemanticTokens,
+    SemanticTokensClientCapabilities, SemanticTokensClientCapabilitiesRequests,
+    SemanticTokensFullOptions, SemanticTokensParams, SemanticTokensResult,
+    TextDocumentClientCapabilities, lsp_request,
 };
 
-use crate::support::MockClient;
+use crate::support::cairo_project_toml::CAIRO_PROJECT_TOML_2025_12;
 use crate::support::cursor::{Cursors, render_text_with_annotations};
 use crate::support::transform::Transformer;
+use crate::support::{MockClient, sandbox};
 
 mod complex;
 mod declarative_macros;
+mod modifiers;
 mod proc_macros;
+mod requests;
 
 impl Transformer for SemanticTokens {
     fn capabilities(base: ClientCapabilities) -> ClientCapabilities {
@@ -40,43 +44,82 @@ impl Transformer for SemanticTokens {
     ) -> String {
         let code = ls.fixture.read_file("src/lib.cairo");
 
-        let res = ls
-            .send_request::<lsp_request!("textDocument/semanticTokens/full")>(
-                SemanticTokensParams {
-                    work_done_progress_params: Default::default(),
-                    partial_result_params: Default::default(),
-                    text_document: ls.doc_id("src/lib.cairo"),
-                },
-            )
-            .unwrap();
-        let SemanticTokensResult::Tokens(tokens) = res else { panic!("expected full tokens") };
+        render_tokens(&code, full_tokens(&mut ls).data)
+    }
+}
 
-        let mut line = 0;
-        let mut character = 0;
+/// Annotates the code with kinds of the tokens.
+fn render_tokens(code: &str, tokens: Vec<SemanticToken>) -> String {
+    let legend = SemanticTokenKind::legend();
+    render_tokens_by(code, tokens, |token| legend[token.token_type as usize].as_str().to_string())
+}
 
-        let legend = SemanticTokenKind::legend();
+/// Annotates the code with kinds of the tokens followed by their modifiers.
+fn render_tokens_with_modifiers(code: &str, tokens: Vec<SemanticToken>) -> String {
+    let legend = SemanticTokenKind::legend();
+    let modifiers_legend = SemanticTokenModifiers::legend();
+    render_tokens_by(code, tokens, |token| {
+        let mut label = legend[token.token_type as usize].as_str().to_string();
+        for (index, modifier) in modifiers_legend.iter().enumerate() {
+            if token.token_modifiers_bitset & (1 << index) != 0 {
+                label.push('.');
+                label.push_str(modifier.as_str());
+            }
+        }
+        label
+    })
+}
 
-        let tokens: Vec<_> = tokens
-            .data
-            .into_iter()
-            .map(|token| {
-                // Reset on new line.
-                if token.delta_line != 0 {
-                    character = 0;
-                }
+fn render_tokens_by(
+    code: &str,
+    tokens: Vec<SemanticToken>,
+    label: impl Fn(&SemanticToken) -> String,
+) -> String {
+    let mut line = 0;
+    let mut character = 0;
 
-                line += token.delta_line;
-                character += token.delta_start;
+    let tokens: Vec<_> = tokens
+        .into_iter()
+        .map(|token| {
+            // Reset on new line.
+            if token.delta_line != 0 {
+                character = 0;
+            }
 
-                let start = Position { character, line };
-                let end = Position { character: start.character + token.length, ..start };
+            line += token.delta_line;
+            character += token.delta_start;
 
-                let token_type = legend[token.token_type as usize].as_str().to_string();
+            let start = Position { character, line };
+            let end = Position { character: start.character + token.length, ..start };
 
-                (Range { start, end }, Some(token_type))
-            })
-            .collect();
+            (Range { start, end }, Some(label(&token)))
+        })
+        .collect();
 
-        render_text_with_annotations(&code, "token", &tokens)
-    }
+    render_text_with_annotations(code, "token", &tokens)
+}
+
+/// Opens `src/lib.cairo` with the given code in a project.
+fn open(code: &str) -> MockClient {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => code,
+        }
+        client_capabilities = SemanticTokens::capabilities;
+    };
+    ls.open_all_cairo_files_and_wait_for_project_update();
+    ls
+}
+
+fn full_tokens(ls: &mut MockClient) -> SemanticTokens {
+    let res = ls
+        .send_request::<lsp_request!("textDocument/semanticTokens/full")>(SemanticTokensParams {
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+            text_document: ls.doc_id("src/lib.cairo"),
+        })
+        .unwrap();
+    let SemanticTokensResult::Tokens(tokens) = res else { panic!("expected full tokens") };
+    tokens
 }
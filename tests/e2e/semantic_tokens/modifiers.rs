@@ -0,0 +1,63 @@
+use indoc::indoc;
+
+use super::{full_tokens, open, render_tokens_with_modifiers};
+
+#[test]
+fn modifiers() {
+    let code = indoc!(
+        r#"
+        #[deprecated(feature: "old")]
+        fn old() {}
+
+        const LIMIT: u32 = 10;
+
+        fn main(ref a: u32) {
+            let mut x = LIMIT;
+            let y: Option<u32> = Option::Some(x);
+            old();
+        }
+    "#
+    );
+    let mut ls = open(code);
+
+    insta::assert_snapshot!(render_tokens_with_modifiers(code, full_tokens(&mut ls).data), @r#"
+    #[<token=decorator>deprecated</token>(<token=decorator>feature</token>: <token=string>"old"</token>)]
+    <token=keyword>fn</token> <token=function.declaration.deprecated>old</token>() {}
+
+    <token=keyword>const</token> <token=enumMember.declaration.static>LIMIT</token>: <token=type.defaultLibrary>u32</token> = <token=number>10</token>;
+
+    <token=keyword>fn</token> <token=function.declaration>main</token>(<token=keyword>ref</token> <token=parameter.declaration.mutable>a</token>: <token=type.defaultLibrary>u32</token>) {
+        <token=keyword>let</token> <token=keyword>mut</token> <token=variable.declaration.mutable>x</token> = <token=enumMember.static>LIMIT</token>;
+        <token=keyword>let</token> <token=variable.declaration>y</token>: <token=enum.defaultLibrary>Option</token><token=operator><</token><token=type.defaultLibrary>u32</token><token=operator>></token> = <token=enum.defaultLibrary>Option</token>::<token=enumMember.defaultLibrary>Some</token>(<token=variable.mutable>x</token>);
+        <token=function.deprecated>old</token>();
+    }
+    "#);
+}
+
+#[test]
+fn corelib_variants() {
+    let code = indoc!(
+        r#"
+        enum Local {
+            Some: u32,
+        }
+
+        fn main() {
+            let _x: Option<u32> = Option::Some(1);
+            let _y = Local::Some(2);
+        }
+    "#
+    );
+    let mut ls = open(code);
+
+    insta::assert_snapshot!(render_tokens_with_modifiers(code, full_tokens(&mut ls).data), @r#"
+    <token=keyword>enum</token> <token=enum.declaration>Local</token> {
+        <token=enumMember.declaration>Some</token>: <token=type.defaultLibrary>u32</token>,
+    }
+
+    <token=keyword>fn</token> <token=function.declaration>main</token>() {
+        <token=keyword>let</token> <token=variable.declaration>_x</token>: <token=enum.defaultLibrary>Option</token><token=operator><</token><token=type.defaultLibrary>u32</token><token=operator>></token> = <token=enum.defaultLibrary>Option</token>::<token=enumMember.defaultLibrary>Some</token>(<token=number>1</token>);
+        <token=keyword>let</token> <token=variable.declaration>_y</token> = <token=enum>Local</token>::<token=enumMember>Some</token>(<token=number>2</token>);
+    }
+    "#);
+}
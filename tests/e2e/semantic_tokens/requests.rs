@@ -0,0 +1,144 @@
+use indoc::indoc;
+use lsp_types::{
+    DidChangeTextDocumentParams, Position, Range, SemanticToken, SemanticTokensDeltaParams,
+    SemanticTokensFullDeltaResult, SemanticTokensRangeParams, SemanticTokensRangeResult,
+    TextDocumentContentChangeEvent, VersionedTextDocumentIdentifier, lsp_notification, lsp_request,
+};
+
+use super::{full_tokens, open, render_tokens};
+use crate::support::MockClient;
+
+fn delta(ls: &mut MockClient, previous_result_id: &str) -> SemanticTokensFullDeltaResult {
+    ls.send_request::<lsp_request!("textDocument/semanticTokens/full/delta")>(
+        SemanticTokensDeltaParams {
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+            text_document: ls.doc_id("src/lib.cairo"),
+            previous_result_id: previous_result_id.to_string(),
+        },
+    )
+    .unwrap()
+}
+
+fn change(ls: &mut MockClient, code: &str) {
+    ls.send_notification::<lsp_notification!("textDocument/didChange")>(
+        DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: ls.doc_id("src/lib.cairo").uri,
+                version: 1,
+            },
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: code.to_string(),
+            }],
+        },
+    );
+}
+
+#[test]
+fn delta_after_change() {
+    let mut ls = open(indoc!(
+        r#"
+        fn foo() {}
+    "#
+    ));
+    let tokens = full_tokens(&mut ls);
+    let result_id = tokens.result_id.expect("full tokens should have a result id");
+
+    let code = indoc!(
+        r#"
+        fn foo() {}
+        fn bar() {}
+    "#
+    );
+    change(&mut ls, code);
+
+    let SemanticTokensFullDeltaResult::TokensDelta(delta) = delta(&mut ls, &result_id) else {
+        panic!("expected tokens delta");
+    };
+    assert_ne!(delta.result_id, Some(result_id));
+
+    // Edits index the flattened array of integers.
+    let mut data: Vec<u32> = tokens.data.iter().flat_map(flatten).collect();
+    for edit in delta.edits.into_iter().rev() {
+        let start = edit.start as usize;
+        let end = start + edit.delete_count as usize;
+        data.splice(start..end, edit.data.unwrap_or_default().iter().flat_map(flatten));
+    }
+    let data = data
+        .chunks(5)
+        .map(|token| SemanticToken {
+            delta_line: token[0],
+            delta_start: token[1],
+            length: token[2],
+            token_type: token[3],
+            token_modifiers_bitset: token[4],
+        })
+        .collect();
+
+    insta::assert_snapshot!(render_tokens(code, data), @r"
+    <token=keyword>fn</token> <token=function>foo</token>() {}
+    <token=keyword>fn</token> <token=function>bar</token>() {}
+    ");
+}
+
+#[test]
+fn delta_of_unknown_result_falls_back_to_full_tokens() {
+    let code = indoc!(
+        r#"
+        fn foo() {}
+    "#
+    );
+    let mut ls = open(code);
+
+    let SemanticTokensFullDeltaResult::Tokens(tokens) = delta(&mut ls, "unknown") else {
+        panic!("expected full tokens");
+    };
+    assert!(tokens.result_id.is_some());
+
+    insta::assert_snapshot!(render_tokens(code, tokens.data), @"<token=keyword>fn</token> <token=function>foo</token>() {}");
+}
+
+#[test]
+fn range() {
+    let code = indoc!(
+        r#"
+        fn foo() {}
+        fn bar() {}
+        fn baz() {}
+    "#
+    );
+    let mut ls = open(code);
+
+    let res = ls
+        .send_request::<lsp_request!("textDocument/semanticTokens/range")>(
+            SemanticTokensRangeParams {
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+                text_document: ls.doc_id("src/lib.cairo"),
+                range: Range {
+                    start: Position { line: 1, character: 0 },
+                    end: Position { line: 2, character: 0 },
+                },
+            },
+        )
+        .unwrap();
+    let SemanticTokensRangeResult::Tokens(tokens) = res else { panic!("expected tokens") };
+
+    insta::assert_snapshot!(render_tokens(code, tokens.data), @r"
+    fn foo() {}
+    <token=keyword>fn</token> <token=function>bar</token>() {}
+    fn baz() {}
+    ");
+}
+
+fn flatten(token: &SemanticToken) -> [u32; 5] {
+    [
+        token.delta_line,
+        token.delta_start,
+        token.length,
+        token.token_type,
+        token.token_modifiers_bitset,
+    ]
+}
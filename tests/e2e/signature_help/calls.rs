@@ -0,0 +1,129 @@
+use lsp_types::SignatureHelp;
+
+use crate::support::insta::test_transform_plain;
+
+#[test]
+fn free_function_first_argument() {
+    test_transform_plain!(SignatureHelp, r#"
+    fn add(a: felt252, b: felt252) -> felt252 {
+        a + b
+    }
+
+    fn main() {
+        add(<caret>1, 2);
+    }
+    "#, @"fn add(<sel>a: felt252</sel>, b: felt252) -> felt252")
+}
+
+#[test]
+fn free_function_second_argument() {
+    test_transform_plain!(SignatureHelp, r#"
+    fn add(a: felt252, b: felt252) -> felt252 {
+        a + b
+    }
+
+    fn main() {
+        add(1, 2<caret>);
+    }
+    "#, @"fn add(a: felt252, <sel>b: felt252</sel>) -> felt252")
+}
+
+#[test]
+fn named_argument() {
+    test_transform_plain!(SignatureHelp, r#"
+    fn add(a: felt252, b: felt252) -> felt252 {
+        a + b
+    }
+
+    fn main() {
+        add(a: 1, b: <caret>2);
+    }
+    "#, @"fn add(a: felt252, <sel>b: felt252</sel>) -> felt252")
+}
+
+#[test]
+fn unknown_named_argument() {
+    test_transform_plain!(SignatureHelp, r#"
+    fn add(a: felt252, b: felt252) -> felt252 {
+        a + b
+    }
+
+    fn main() {
+        add(a: 1, c: <caret>2);
+    }
+    "#, @"fn add(a: felt252, b: felt252) -> felt252")
+}
+
+#[test]
+fn generic_function() {
+    test_transform_plain!(SignatureHelp, r#"
+    fn first<T, +Drop<T>>(a: T, b: T) -> T {
+        a
+    }
+
+    fn main() {
+        first(1_u8, <caret>2);
+    }
+    "#, @"fn first<T, +Drop<T>>(a: T, <sel>b: T</sel>) -> T")
+}
+
+#[test]
+fn nested_call() {
+    test_transform_plain!(SignatureHelp, r#"
+    fn add(a: felt252, b: felt252) -> felt252 {
+        a + b
+    }
+
+    fn neg(x: felt252) -> felt252 {
+        -x
+    }
+
+    fn main() {
+        add(neg(<caret>1), 2);
+    }
+    "#, @"fn neg(<sel>x: felt252</sel>) -> felt252")
+}
+
+#[test]
+fn method_call() {
+    test_transform_plain!(SignatureHelp, r#"
+    #[derive(Drop)]
+    struct Counter {
+        value: u32,
+    }
+
+    #[generate_trait]
+    impl CounterImpl of CounterTrait {
+        fn add(ref self: Counter, amount: u32, times: u32) {
+            self.value += amount * times;
+        }
+    }
+
+    fn main() {
+        let mut counter = Counter { value: 0 };
+        counter.add(1, <caret>2);
+    }
+    "#, @"fn add(ref self: Counter, amount: u32, <sel>times: u32</sel>)")
+}
+
+#[test]
+fn inline_macro() {
+    test_transform_plain!(SignatureHelp, r#"
+    fn main() {
+        let _x = array![1, <caret>2];
+    }
+    "#, @"array!")
+}
+
+#[test]
+fn outside_of_call() {
+    test_transform_plain!(SignatureHelp, r#"
+    fn add(a: felt252, b: felt252) -> felt252 {
+        a + b
+    }
+
+    fn main() {
+        let _x = <caret>add(1, 2);
+    }
+    "#, @"none response")
+}
@@ -0,0 +1,79 @@
+use lsp_types::{
+    ClientCapabilities, ParameterLabel, SignatureHelp, SignatureHelpClientCapabilities,
+    SignatureHelpParams, TextDocumentClientCapabilities, TextDocumentPositionParams, lsp_request,
+};
+
+use crate::support::transform::Transformer;
+
+mod calls;
+
+impl Transformer for SignatureHelp {
+    fn capabilities(base: ClientCapabilities) -> ClientCapabilities {
+        ClientCapabilities {
+            text_document: base.text_document.or_else(Default::default).map(|it| {
+                TextDocumentClientCapabilities {
+                    signature_help: Some(SignatureHelpClientCapabilities {
+                        dynamic_registration: Some(false),
+                        ..Default::default()
+                    }),
+                    ..it
+                }
+            }),
+            ..base
+        }
+    }
+
+    fn transform(
+        mut ls: crate::support::MockClient,
+        cursors: crate::support::cursor::Cursors,
+        _config: Option<serde_json::Value>,
+    ) -> String {
+        let position = cursors.assert_single_caret();
+
+        let params = SignatureHelpParams {
+            context: None,
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: ls.doc_id("src/lib.cairo"),
+                position,
+            },
+            work_done_progress_params: Default::default(),
+        };
+
+        let Some(help) =
+            ls.send_request::<lsp_request!("textDocument/signatureHelp")>(params).unwrap()
+        else {
+            return "none response".to_string();
+        };
+
+        let active_parameter = help.active_parameter.map(|index| index as usize);
+        help.signatures
+            .into_iter()
+            .map(|signature| {
+                render_signature(&signature.label, signature.parameters, active_parameter)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Renders the signature label with the active parameter wrapped in selection markers.
+fn render_signature(
+    label: &str,
+    parameters: Option<Vec<lsp_types::ParameterInformation>>,
+    active_parameter: Option<usize>,
+) -> String {
+    let Some(ParameterLabel::LabelOffsets([start, end])) = parameters
+        .zip(active_parameter)
+        .and_then(|(parameters, active_parameter)| parameters.into_iter().nth(active_parameter))
+        .map(|parameter| parameter.label)
+    else {
+        return label.to_string();
+    };
+
+    let utf16 = label.encode_utf16().collect::<Vec<_>>();
+    let [before, selected, after] =
+        [&utf16[..start as usize], &utf16[start as usize..end as usize], &utf16[end as usize..]]
+            .map(String::from_utf16_lossy);
+
+    format!("{before}<sel>{selected}</sel>{after}")
+}
@@ -0,0 +1,59 @@
+use lsp_types::request::Request;
+use lsp_types::{
+    ClientCapabilities, GotoCapability, GotoDefinitionParams, GotoDefinitionResponse,
+    TextDocumentClientCapabilities, TextDocumentPositionParams,
+};
+
+use crate::support::MockClient;
+use crate::support::cursor::{Cursors, render_selections};
+
+/// Enables the goto capability selected by `capability` in the client capabilities.
+pub fn with_goto_capability(
+    base: ClientCapabilities,
+    capability: impl FnOnce(&mut TextDocumentClientCapabilities) -> &mut Option<GotoCapability>,
+) -> ClientCapabilities {
+    ClientCapabilities {
+        text_document: base.text_document.or_else(Default::default).map(|mut it| {
+            *capability(&mut it) =
+                Some(GotoCapability { dynamic_registration: Some(false), link_support: None });
+            it
+        }),
+        ..base
+    }
+}
+
+/// Sends the goto request `R` at the caret in `src/lib.cairo` and renders the locations
+/// found in this file as selections.
+pub fn render_goto<R>(mut ls: MockClient, cursors: Cursors) -> String
+where
+    R: Request<Params = GotoDefinitionParams, Result = Option<GotoDefinitionResponse>>,
+{
+    let cairo = ls.fixture.read_file("src/lib.cairo");
+    let position = cursors.assert_single_caret();
+
+    let params = GotoDefinitionParams {
+        text_document_position_params: TextDocumentPositionParams {
+            text_document: ls.doc_id("src/lib.cairo"),
+            position,
+        },
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+    let locations = match ls.send_request::<R>(params) {
+        Some(GotoDefinitionResponse::Scalar(location)) => vec![location],
+        Some(GotoDefinitionResponse::Array(locations)) => locations,
+        Some(GotoDefinitionResponse::Link(_)) => {
+            panic!("unexpected GotoDefinitionResponse::Link")
+        }
+        None => return "none response".to_string(),
+    };
+
+    let lib_uri = ls.doc_id("src/lib.cairo").uri;
+    let ranges = locations
+        .into_iter()
+        .filter(|location| location.uri == lib_uri)
+        .map(|location| location.range)
+        .collect::<Vec<_>>();
+
+    render_selections(&cairo, &ranges)
+}
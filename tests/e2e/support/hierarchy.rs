@@ -0,0 +1,81 @@
+use std::fmt::Debug;
+
+use lsp_types::request::Request;
+use lsp_types::{
+    CallHierarchyItem, Position, Range, TextDocumentPositionParams, TypeHierarchyItem,
+};
+
+use crate::support::MockClient;
+
+/// An item of a call or type hierarchy.
+pub trait HierarchyItem: Clone + Debug {
+    fn name(&self) -> &str;
+    fn detail(&self) -> Option<&str>;
+    fn selection_range(&self) -> Range;
+}
+
+impl HierarchyItem for CallHierarchyItem {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn detail(&self) -> Option<&str> {
+        self.detail.as_deref()
+    }
+
+    fn selection_range(&self) -> Range {
+        self.selection_range
+    }
+}
+
+impl HierarchyItem for TypeHierarchyItem {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn detail(&self) -> Option<&str> {
+        self.detail.as_deref()
+    }
+
+    fn selection_range(&self) -> Range {
+        self.selection_range
+    }
+}
+
+/// Sends the request `R` preparing a hierarchy at the position in `src/lib.cairo`.
+///
+/// Panics if more than a single item is returned.
+pub fn prepare<R, I>(
+    ls: &mut MockClient,
+    position: Position,
+    params: impl FnOnce(TextDocumentPositionParams) -> R::Params,
+) -> Option<I>
+where
+    R: Request<Result = Option<Vec<I>>>,
+    I: HierarchyItem,
+{
+    let text_document_position_params =
+        TextDocumentPositionParams { text_document: ls.doc_id("src/lib.cairo"), position };
+
+    ls.send_request::<R>(params(text_document_position_params)).map(|items| {
+        let [item] = <[_; 1]>::try_from(items).unwrap();
+        item
+    })
+}
+
+/// Renders the item as its name, detail and the line of its selection range.
+pub fn render_item(item: &impl HierarchyItem) -> String {
+    format!(
+        "{} ({}) @ {}",
+        item.name(),
+        item.detail().unwrap_or_default(),
+        item.selection_range().start.line
+    )
+}
+
+/// Renders a section of related items with the given header, one sorted and indented line each.
+pub fn render_section(header: &str, lines: impl IntoIterator<Item = String>) -> String {
+    let mut lines = lines.into_iter().map(|line| format!("    {line}\n")).collect::<Vec<_>>();
+    lines.sort();
+    format!("{header}:\n{}", lines.concat())
+}
@@ -10,7 +10,7 @@ use cairo_language_server::lsp::ext::ServerStatusParams;
 use cairo_language_server::lsp::ext::ViewAnalyzedCrates;
 use cairo_language_server::lsp::ext::testing::ProjectUpdatingFinished;
 use cairo_language_server::testing::BackendForTesting;
-use lsp_server::{Message, Notification, Request, Response};
+use lsp_server::{Message, Notification, Request, RequestId, Response};
 use lsp_types::request::{RegisterCapability, Request as LspRequest};
 use lsp_types::{Diagnostic, PublishDiagnosticsParams, Url, lsp_notification, lsp_request};
 use serde_json::Value;
@@ -132,28 +132,33 @@ impl MockClient {
 
     /// Sends an arbitrary request to the server.
     pub fn send_request_untyped(&mut self, method: &'static str, params: Value) -> Value {
-        let id = self.req_id.next();
-        let message = Message::Request(Request::new(id.clone(), method.to_owned(), params));
+        let id = self.start_request_untyped(method, params);
 
-        self.client.sender.send(message.clone()).expect("failed to send request");
+        match self.wait_for_response(&id).response_result {
+            Ok(result) => result,
+            Err(err) => panic!("error response: {err:#?}"),
+        }
+    }
 
-        while let Some(response_message) =
-            self.recv().unwrap_or_else(|err| panic!("{err:?}: {message:?}"))
-        {
-            if let Message::Response(res) = response_message {
-                let res_id = res.id;
-                let result = res.response_result;
+    /// Sends a typed request to the server without waiting for its response.
+    ///
+    /// The response has to be received with [`MockClient::wait_for_response`].
+    pub fn start_request<R: lsp_types::request::Request>(
+        &mut self,
+        params: R::Params,
+    ) -> RequestId {
+        let params = serde_json::to_value(params).expect("failed to serialize request params");
+        self.start_request_untyped(R::METHOD, params)
+    }
 
-                assert_eq!(res_id, id);
+    /// Sends an arbitrary request to the server without waiting for its response.
+    fn start_request_untyped(&mut self, method: &'static str, params: Value) -> RequestId {
+        let id = self.req_id.next();
+        let message = Message::Request(Request::new(id.clone(), method.to_owned(), params));
 
-                match result {
-                    Ok(result) => return result,
-                    Err(err) => panic!("error response: {err:#?}"),
-                }
-            }
-        }
+        self.client.sender.send(message).expect("failed to send request");
 
-        panic!("no response for request: {message:?}")
+        id
     }
 
     /// Sends a typed notification to the server.
@@ -233,6 +238,22 @@ impl MockClient {
         Ok(message)
     }
 
+    /// Waits for the response to the request with the given ID.
+    ///
+    /// Responses are expected in the order the requests were sent in.
+    pub fn wait_for_response(&mut self, id: &RequestId) -> Response {
+        while let Some(message) =
+            self.recv().unwrap_or_else(|err| panic!("{err:?}: no response for request {id}"))
+        {
+            if let Message::Response(response) = message {
+                assert_eq!(&response.id, id);
+                return response;
+            }
+        }
+
+        panic!("no response for request {id}")
+    }
+
     /// Looks for a message that satisfies the given predicate in message trace and removes it from
     /// it or waits for a new one.
     fn wait_for_message<T>(
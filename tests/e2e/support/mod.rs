@@ -3,6 +3,8 @@ pub mod client_capabilities;
 pub mod cursor;
 pub mod diagnostics;
 pub mod fixture;
+pub mod goto;
+pub mod hierarchy;
 pub mod insta;
 pub mod itertools;
 pub mod jsonrpc;
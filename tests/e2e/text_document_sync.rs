@@ -0,0 +1,147 @@
+use indoc::indoc;
+use lsp_types::notification::ShowMessage;
+use lsp_types::{
+    DidChangeTextDocumentParams, DidSaveTextDocumentParams, DocumentSymbolParams,
+    DocumentSymbolResponse, MessageType, Position, Range, TextDocumentContentChangeEvent,
+    VersionedTextDocumentIdentifier, lsp_notification, lsp_request,
+};
+
+use crate::support::cairo_project_toml::CAIRO_PROJECT_TOML_2025_12;
+use crate::support::{MockClient, sandbox};
+
+/// Renders names and lines of items in `src/lib.cairo` as seen by the server.
+fn outline(ls: &mut MockClient) -> String {
+    let params = DocumentSymbolParams {
+        text_document: ls.doc_id("src/lib.cairo"),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+
+    let Some(DocumentSymbolResponse::Nested(symbols)) =
+        ls.send_request::<lsp_request!("textDocument/documentSymbol")>(params)
+    else {
+        panic!("expected a nested document symbol response");
+    };
+
+    symbols
+        .into_iter()
+        .map(|symbol| format!("{} @ {}", symbol.name, symbol.range.start.line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn change(ls: &mut MockClient, version: i32, content_changes: Vec<TextDocumentContentChangeEvent>) {
+    ls.send_notification::<lsp_notification!("textDocument/didChange")>(
+        DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: ls.doc_id("src/lib.cairo").uri,
+                version,
+            },
+            content_changes,
+        },
+    );
+}
+
+fn edit(start: (u32, u32), end: (u32, u32), text: &str) -> TextDocumentContentChangeEvent {
+    TextDocumentContentChangeEvent {
+        range: Some(Range {
+            start: Position { line: start.0, character: start.1 },
+            end: Position { line: end.0, character: end.1 },
+        }),
+        range_length: None,
+        text: text.to_string(),
+    }
+}
+
+fn full(text: &str) -> TextDocumentContentChangeEvent {
+    TextDocumentContentChangeEvent { range: None, range_length: None, text: text.to_string() }
+}
+
+#[test]
+fn applies_range_edits() {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => indoc!(r#"
+                // ą🦀 x
+                fn foo() {}
+            "#),
+        }
+    };
+    ls.open_all_cairo_files_and_wait_for_project_update();
+
+    // Columns are counted in UTF-16 code units, `🦀` takes two of them.
+    change(
+        &mut ls,
+        1,
+        vec![edit((0, 7), (1, 2), "\nfn bar() {}\nfn"), edit((2, 3), (2, 6), "baz")],
+    );
+
+    insta::assert_snapshot!(outline(&mut ls), @r"
+    bar @ 1
+    baz @ 2
+    ");
+}
+
+#[test]
+fn ignores_range_edits_while_out_of_sync() {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => "fn foo() {}\n",
+        }
+    };
+    ls.open_all_cairo_files_and_wait_for_project_update();
+
+    change(&mut ls, 2, vec![edit((0, 3), (0, 6), "bar")]);
+    insta::assert_snapshot!(outline(&mut ls), @"bar @ 0");
+
+    // An edit with an older version cannot be applied, the content on disk is used instead.
+    change(&mut ls, 1, vec![edit((0, 3), (0, 6), "baz")]);
+    ls.wait_for_notification::<ShowMessage>(|params| params.typ == MessageType::WARNING);
+    insta::assert_snapshot!(outline(&mut ls), @"foo @ 0");
+
+    // The client has applied this edit on top of content the server does not know,
+    // so it must not be applied on top of the content on disk.
+    change(&mut ls, 3, vec![edit((0, 0), (0, 0), "fn qux() {}\n")]);
+    insta::assert_snapshot!(outline(&mut ls), @"foo @ 0");
+
+    // Saving brings the document back in sync, and range edits apply on top of the saved content.
+    ls.edit_file("src/lib.cairo", "fn qux() {}\nfn baz() {}\n");
+    ls.send_notification::<lsp_notification!("textDocument/didSave")>(DidSaveTextDocumentParams {
+        text_document: ls.doc_id("src/lib.cairo"),
+        text: None,
+    });
+    insta::assert_snapshot!(outline(&mut ls), @r"
+    qux @ 0
+    baz @ 1
+    ");
+
+    change(&mut ls, 4, vec![edit((2, 0), (2, 0), "fn quux() {}\n")]);
+    insta::assert_snapshot!(outline(&mut ls), @r"
+    qux @ 0
+    baz @ 1
+    quux @ 2
+    ");
+}
+
+#[test]
+fn full_content_replaces_reloaded_content() {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => "fn foo() {}\n",
+        }
+    };
+    ls.open_all_cairo_files_and_wait_for_project_update();
+
+    change(&mut ls, 2, vec![edit((0, 3), (0, 6), "bar")]);
+    change(&mut ls, 1, vec![edit((0, 3), (0, 6), "baz")]);
+    ls.wait_for_notification::<ShowMessage>(|params| params.typ == MessageType::WARNING);
+
+    change(&mut ls, 4, vec![full("fn foo() {}\n\nfn qux() {}\n")]);
+    insta::assert_snapshot!(outline(&mut ls), @r"
+    foo @ 0
+    qux @ 2
+    ");
+}
@@ -0,0 +1,100 @@
+use lsp_types::TypeHierarchyItem;
+
+use crate::support::insta::test_transform_plain;
+
+#[test]
+fn trait_lists_impls_with_constraints() {
+    test_transform_plain!(TypeHierarchyItem, r#"
+    trait Sha<caret>pe<T> {
+        fn area(self: @T) -> u32;
+    }
+
+    struct Square {
+        side: u32,
+    }
+
+    impl SquareShape of Shape<Square> {
+        fn area(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+
+    impl ArrayShape<T, +Drop<T>> of Shape<Array<T>> {
+        fn area(self: @Array<T>) -> u32 {
+            self.len()
+        }
+    }
+
+    impl SquareShapeAlias = SquareShape;
+    "#, @r"
+    Shape (hello) @ 0
+    supertypes:
+    subtypes:
+        ArrayShape (impl ArrayShape<T, +Drop<T>> of Shape<Array<T>>) @ 15
+        SquareShape (impl SquareShape of Shape<Square>) @ 9
+    ");
+}
+
+#[test]
+fn impl_lists_trait_types_and_aliases() {
+    test_transform_plain!(TypeHierarchyItem, r#"
+    trait Shape<T> {
+        fn area(self: @T) -> u32;
+    }
+
+    struct Square {
+        side: u32,
+    }
+
+    impl SquareSh<caret>ape of Shape<Square> {
+        fn area(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+
+    impl ArrayShape<T, +Drop<T>> of Shape<Array<T>> {
+        fn area(self: @Array<T>) -> u32 {
+            self.len()
+        }
+    }
+
+    impl SquareShapeAlias = SquareShape;
+    "#, @r"
+    SquareShape (impl SquareShape of Shape<Square>) @ 9
+    supertypes:
+        Shape (hello) @ 0
+    subtypes:
+        Square (hello) @ 5
+        SquareShapeAlias (impl SquareShapeAlias = SquareShape) @ 21
+    ");
+}
+
+#[test]
+fn type_lists_impls_mentioning_it() {
+    test_transform_plain!(TypeHierarchyItem, r#"
+    trait Shape<T> {
+        fn area(self: @T) -> u32;
+    }
+
+    struct Squ<caret>are {
+        side: u32,
+    }
+
+    impl SquareShape of Shape<Square> {
+        fn area(self: @Square) -> u32 {
+            *self.side * *self.side
+        }
+    }
+
+    impl ArrayShape<T, +Drop<T>> of Shape<Array<T>> {
+        fn area(self: @Array<T>) -> u32 {
+            self.len()
+        }
+    }
+    "#, @r"
+    Square (hello) @ 5
+    supertypes:
+        SquareShape (impl SquareShape of Shape<Square>) @ 9
+    subtypes:
+    ");
+}
@@ -0,0 +1,71 @@
+use lsp_types::{
+    ClientCapabilities, TextDocumentClientCapabilities, TypeHierarchyClientCapabilities,
+    TypeHierarchyItem, TypeHierarchyPrepareParams, TypeHierarchySubtypesParams,
+    TypeHierarchySupertypesParams, lsp_request,
+};
+
+use crate::support::hierarchy::{prepare, render_item, render_section};
+use crate::support::transform::Transformer;
+
+mod impls;
+
+impl Transformer for TypeHierarchyItem {
+    fn capabilities(base: ClientCapabilities) -> ClientCapabilities {
+        ClientCapabilities {
+            text_document: base.text_document.or_else(Default::default).map(|it| {
+                TextDocumentClientCapabilities {
+                    // Type hierarchy is provided only through dynamic registration.
+                    type_hierarchy: Some(TypeHierarchyClientCapabilities {
+                        dynamic_registration: Some(true),
+                    }),
+                    ..it
+                }
+            }),
+            ..base
+        }
+    }
+
+    fn transform(
+        mut ls: crate::support::MockClient,
+        cursors: crate::support::cursor::Cursors,
+        _config: Option<serde_json::Value>,
+    ) -> String {
+        let position = cursors.assert_single_caret();
+
+        let Some(item) = prepare::<lsp_request!("textDocument/prepareTypeHierarchy"), _>(
+            &mut ls,
+            position,
+            |text_document_position_params| TypeHierarchyPrepareParams {
+                text_document_position_params,
+                work_done_progress_params: Default::default(),
+            },
+        ) else {
+            return "none response".to_string();
+        };
+
+        let supertypes = ls
+            .send_request::<lsp_request!("typeHierarchy/supertypes")>(
+                TypeHierarchySupertypesParams {
+                    item: item.clone(),
+                    work_done_progress_params: Default::default(),
+                    partial_result_params: Default::default(),
+                },
+            )
+            .unwrap_or_default();
+
+        let subtypes = ls
+            .send_request::<lsp_request!("typeHierarchy/subtypes")>(TypeHierarchySubtypesParams {
+                item: item.clone(),
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+            })
+            .unwrap_or_default();
+
+        format!(
+            "{}\n{}{}",
+            render_item(&item),
+            render_section("supertypes", supertypes.iter().map(render_item)),
+            render_section("subtypes", subtypes.iter().map(render_item))
+        )
+    }
+}
@@ -0,0 +1,157 @@
+use indoc::indoc;
+use lsp_types::{
+    ClientCapabilities, DidChangeTextDocumentParams, DidChangeWatchedFilesParams, FileChangeType,
+    FileEvent, TextDocumentContentChangeEvent, VersionedTextDocumentIdentifier,
+    WorkspaceClientCapabilities, WorkspaceSymbolClientCapabilities, WorkspaceSymbolParams,
+    WorkspaceSymbolResponse, lsp_notification, lsp_request,
+};
+
+use crate::support::cairo_project_toml::CAIRO_PROJECT_TOML_2025_12;
+use crate::support::{MockClient, sandbox};
+
+fn caps(base: ClientCapabilities) -> ClientCapabilities {
+    ClientCapabilities {
+        workspace: base.workspace.or_else(Default::default).map(|it| WorkspaceClientCapabilities {
+            symbol: Some(WorkspaceSymbolClientCapabilities {
+                dynamic_registration: Some(false),
+                ..Default::default()
+            }),
+            ..it
+        }),
+        ..base
+    }
+}
+
+/// Searches for symbols and renders those defined in `src/lib.cairo`.
+/// Symbols from corelib are skipped to keep snapshots stable.
+fn search(ls: &mut MockClient, query: &str) -> String {
+    search_in(ls, "src/lib.cairo", query)
+}
+
+/// Searches for symbols and renders those defined in the given file.
+fn search_in(ls: &mut MockClient, path: &str, query: &str) -> String {
+    let params = WorkspaceSymbolParams {
+        query: query.to_string(),
+        work_done_progress_params: Default::default(),
+        partial_result_params: Default::default(),
+    };
+
+    let Some(WorkspaceSymbolResponse::Flat(symbols)) =
+        ls.send_request::<lsp_request!("workspace/symbol")>(params)
+    else {
+        panic!("expected a flat workspace symbol response");
+    };
+
+    let uri = ls.doc_id(path).uri;
+    symbols
+        .into_iter()
+        .filter(|symbol| symbol.location.uri == uri)
+        .map(|symbol| {
+            format!(
+                "{name} in {container} @ {line}",
+                name = symbol.name,
+                container = symbol.container_name.unwrap_or_default(),
+                line = symbol.location.range.start.line,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn finds_symbols_in_workspace() {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => indoc!(r#"
+                struct Counter {
+                    value: u32,
+                }
+
+                trait CounterTrait {
+                    fn get(self: @Counter) -> u32;
+                }
+
+                impl CounterImpl of CounterTrait {
+                    fn get(self: @Counter) -> u32 {
+                        *self.value
+                    }
+                }
+
+                fn main() {}
+            "#),
+        }
+        client_capabilities = caps;
+    };
+    ls.open_all_cairo_files_and_wait_for_project_update();
+
+    insta::assert_snapshot!(search(&mut ls, "Counter"), @r"
+    Counter in hello @ 0
+    CounterTrait in hello @ 4
+    CounterImpl in hello @ 8
+    ");
+
+    insta::assert_snapshot!(search(&mut ls, "get"), @r"
+    get in hello::CounterTrait @ 5
+    get in hello::CounterImpl @ 9
+    ");
+}
+
+#[test]
+fn reindexes_edited_files() {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => "fn foo() {}\n",
+        }
+        client_capabilities = caps;
+    };
+    ls.open_all_cairo_files_and_wait_for_project_update();
+
+    insta::assert_snapshot!(search(&mut ls, "foo"), @"foo in hello @ 0");
+
+    ls.send_notification::<lsp_notification!("textDocument/didChange")>(
+        DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: ls.doc_id("src/lib.cairo").uri,
+                version: 1,
+            },
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: "fn bar() {}\n\nfn foo() {}\n".to_string(),
+            }],
+        },
+    );
+
+    insta::assert_snapshot!(search(&mut ls, "foo"), @"foo in hello @ 2");
+}
+
+#[test]
+fn reindexes_files_changed_on_disk() {
+    let mut ls = sandbox! {
+        files {
+            "cairo_project.toml" => CAIRO_PROJECT_TOML_2025_12,
+            "src/lib.cairo" => "mod other;\n",
+            "src/other.cairo" => "fn foo() {}\n",
+        }
+        client_capabilities = caps;
+    };
+    ls.open_and_wait_for_project_update("src/lib.cairo");
+
+    let symbols = search_in(&mut ls, "src/other.cairo", "foo");
+    insta::assert_snapshot!(symbols, @"foo in hello::other @ 0");
+
+    ls.edit_file("src/other.cairo", "fn bar() {}\n\nfn foo() {}\n");
+    ls.send_notification::<lsp_notification!("workspace/didChangeWatchedFiles")>(
+        DidChangeWatchedFilesParams {
+            changes: vec![FileEvent {
+                uri: ls.doc_id("src/other.cairo").uri,
+                typ: FileChangeType::CHANGED,
+            }],
+        },
+    );
+
+    let symbols = search_in(&mut ls, "src/other.cairo", "foo");
+    insta::assert_snapshot!(symbols, @"foo in hello::other @ 2");
+}